@@ -0,0 +1,94 @@
+use crate::impls::inner_types::*;
+use crate::*;
+
+/// A BLS public key with the affine form precomputed.
+///
+/// Use when the same public key verifies many signatures
+/// to avoid repeating the affine conversion on every verification.
+pub struct AffinePublicKey<C: BlsSignatureImpl>(
+    /// The BLS public key raw value in affine form
+    pub <C as Pairing>::PublicKeyAffine,
+);
+
+impl<C: BlsSignatureImpl> fmt::Debug for AffinePublicKey<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl<C: BlsSignatureImpl> Copy for AffinePublicKey<C> {}
+
+impl<C: BlsSignatureImpl> Clone for AffinePublicKey<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: BlsSignatureImpl> PartialEq for AffinePublicKey<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_projective() == other.to_projective()
+    }
+}
+
+impl<C: BlsSignatureImpl> Eq for AffinePublicKey<C> {}
+
+impl<C: BlsSignatureImpl> From<PublicKey<C>> for AffinePublicKey<C> {
+    fn from(pk: PublicKey<C>) -> Self {
+        Self::from(&pk)
+    }
+}
+
+impl<C: BlsSignatureImpl> From<&PublicKey<C>> for AffinePublicKey<C> {
+    fn from(pk: &PublicKey<C>) -> Self {
+        Self(<C as Pairing>::PublicKeyAffine::from(pk.0))
+    }
+}
+
+impl<C: BlsSignatureImpl> From<AffinePublicKey<C>> for PublicKey<C> {
+    fn from(pk: AffinePublicKey<C>) -> Self {
+        pk.to_projective()
+    }
+}
+
+impl<C: BlsSignatureImpl> From<&AffinePublicKey<C>> for PublicKey<C> {
+    fn from(pk: &AffinePublicKey<C>) -> Self {
+        pk.to_projective()
+    }
+}
+
+impl<C: BlsSignatureImpl> AffinePublicKey<C> {
+    /// Convert back to the projective public key
+    pub fn to_projective(&self) -> PublicKey<C> {
+        PublicKey(self.0.into())
+    }
+
+    /// Verify a signature using this public key
+    pub fn verify<B: AsRef<[u8]>>(&self, sig: &Signature<C>, msg: B) -> BlsResult<()> {
+        match sig {
+            Signature::Basic(sig) => <C as BlsSignatureCore>::core_verify_affine(
+                self.0,
+                *sig,
+                msg,
+                <C as BlsSignatureBasic>::DST,
+            ),
+            Signature::MessageAugmentation(sig) => {
+                let pk_bytes = self.0.to_bytes();
+                let mut overhead = Vec::with_capacity(pk_bytes.as_ref().len() + msg.as_ref().len());
+                overhead.extend_from_slice(pk_bytes.as_ref());
+                overhead.extend_from_slice(msg.as_ref());
+                <C as BlsSignatureCore>::core_verify_affine(
+                    self.0,
+                    *sig,
+                    overhead.as_slice(),
+                    <C as BlsSignatureMessageAugmentation>::DST,
+                )
+            }
+            Signature::ProofOfPossession(sig) => <C as BlsSignatureCore>::core_verify_affine(
+                self.0,
+                *sig,
+                msg,
+                <C as BlsSignaturePop>::SIG_DST,
+            ),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::impls::inner_types::*;
 use crate::*;
+use rand_core::{CryptoRng, RngCore};
 
 /// Represents a BLS signature for multiple signatures that signed different messages
 #[derive(PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -81,21 +82,17 @@ impl<C: BlsSignatureImpl> TryFrom<&[Signature<C>]> for AggregateSignature<C> {
     type Error = BlsError;
 
     fn try_from(sigs: &[Signature<C>]) -> Result<Self, Self::Error> {
-        if sigs.len() < 2 {
-            return Err(BlsError::InvalidSignature);
+        if sigs.is_empty() {
+            return Err(BlsError::InvalidInputs(
+                "no signatures provided".to_string(),
+            ));
         }
-        let mut g = <C as Pairing>::Signature::identity();
         for s in &sigs[1..] {
             if !s.same_scheme(&sigs[0]) {
                 return Err(BlsError::InvalidSignatureScheme);
             }
-            let ss = match s {
-                Signature::Basic(sig) => sig,
-                Signature::MessageAugmentation(sig) => sig,
-                Signature::ProofOfPossession(sig) => sig,
-            };
-            g += ss;
         }
+        let g = sum_signatures(&sigs[1..]);
         match sigs[0] {
             Signature::Basic(s) => Ok(Self::Basic(g + s)),
             Signature::MessageAugmentation(s) => Ok(Self::MessageAugmentation(g + s)),
@@ -115,19 +112,64 @@ impl<C: BlsSignatureImpl> From<&AggregateSignature<C>> for Vec<u8> {
 impl<C: BlsSignatureImpl> TryFrom<&[u8]> for AggregateSignature<C> {
     type Error = BlsError;
 
+    /// Deserialize an aggregate signature.
+    ///
+    /// Points that are not on the curve, not in the prime order subgroup,
+    /// or are the identity element are rejected.
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        serde_bare::from_slice(value).map_err(|e| BlsError::InvalidInputs(e.to_string()))
+        let sig: Self =
+            serde_bare::from_slice(value).map_err(|e| BlsError::InvalidInputs(e.to_string()))?;
+        sig.validate()?;
+        Ok(sig)
     }
 }
 
 impl<C: BlsSignatureImpl> AggregateSignature<C> {
+    /// The signature scheme used by this value
+    pub fn scheme(&self) -> SignatureSchemes {
+        match self {
+            Self::Basic(_) => SignatureSchemes::Basic,
+            Self::MessageAugmentation(_) => SignatureSchemes::MessageAugmentation,
+            Self::ProofOfPossession(_) => SignatureSchemes::ProofOfPossession,
+        }
+    }
+
+    /// Create an aggregate of a single signature
+    pub fn from_single(sig: Signature<C>) -> Self {
+        match sig {
+            Signature::Basic(s) => Self::Basic(s),
+            Signature::MessageAugmentation(s) => Self::MessageAugmentation(s),
+            Signature::ProofOfPossession(s) => Self::ProofOfPossession(s),
+        }
+    }
+
     /// Accumulate multiple signatures into a single signature
     /// Verify fails if any signed message is a duplicate
     pub fn from_signatures<B: AsRef<[Signature<C>]>>(signatures: B) -> BlsResult<Self> {
         Self::try_from(signatures.as_ref())
     }
 
+    /// Accumulate signed messages into a single signature, returning it
+    /// with the public key and message pairs to pass to [`Self::verify`]
+    ///
+    /// The signed messages are not verified here. The aggregate can't be
+    /// verified without the returned pairs so callers must keep them
+    /// alongside it.
+    pub fn from_signed_messages(
+        messages: &[SignedMessage<C>],
+    ) -> BlsResult<AggregatedSignedMessages<C>> {
+        let sigs = messages.iter().map(|m| m.sig).collect::<Vec<_>>();
+        let signature = Self::try_from(sigs.as_slice())?;
+        let data = messages.iter().map(|m| (m.pk, m.msg.clone())).collect();
+        Ok(AggregatedSignedMessages { signature, data })
+    }
+
     /// Verify the aggregated signature using the public keys
+    ///
+    /// The basic scheme rejects any duplicate message. The message
+    /// augmentation and proof of possession schemes allow the same message
+    /// under distinct keys, though when every signer signed the same message
+    /// [`Self::verify_same_message`] is faster.
     pub fn verify<B: AsRef<[u8]>>(&self, data: &[(PublicKey<C>, B)]) -> BlsResult<()> {
         let ii = data.iter().map(|(pk, m)| (pk.0, m));
         match self {
@@ -138,4 +180,161 @@ impl<C: BlsSignatureImpl> AggregateSignature<C> {
             Self::ProofOfPossession(sig) => <C as BlsSignaturePop>::aggregate_verify(ii, *sig),
         }
     }
+
+    /// Verify the aggregated signature using the public keys, messages and
+    /// the individual signatures that were aggregated.
+    ///
+    /// An aggregate alone can't tell a valid set of signatures from one where
+    /// two signatures were altered to cancel out, which matters when the same
+    /// message is signed more than once. This checks the signatures sum to
+    /// this aggregate then verifies them with random scalars drawn from `rng`
+    /// in a single multi-pairing, so cancelling signatures are rejected.
+    pub fn verify_randomized<B: AsRef<[u8]>>(
+        &self,
+        data: &[(PublicKey<C>, B, Signature<C>)],
+        rng: impl RngCore + CryptoRng,
+    ) -> BlsResult<()> {
+        if data.is_empty() {
+            return Err(BlsError::InvalidInputs(
+                "no signatures to verify".to_string(),
+            ));
+        }
+        let mut sum = <C as Pairing>::Signature::identity();
+        for (_, _, sig) in data {
+            if sig.scheme() != self.scheme() {
+                return Err(BlsError::InvalidSignatureScheme);
+            }
+            sum += sig.as_raw_value();
+        }
+        if sum != *self.as_raw_value() {
+            return Err(BlsError::InvalidSignature);
+        }
+        let items = data
+            .iter()
+            .map(|(pk, msg, sig)| (*pk, msg.as_ref(), *sig))
+            .collect::<Vec<_>>();
+        Signature::batch_verify_mixed_with_rng(&items, rng)
+    }
+
+    /// Verify like [`Self::verify_randomized`] and on failure report the
+    /// position of every entry in `data` whose signature does not verify
+    /// against its public key and message.
+    ///
+    /// An aggregate can't be split back into signatures so the individual
+    /// signatures must be supplied. If each signature is valid but they do
+    /// not add up to this aggregate the returned list is empty.
+    pub fn verify_identify<B: AsRef<[u8]>>(
+        &self,
+        data: &[(PublicKey<C>, B, Signature<C>)],
+    ) -> Result<(), Vec<usize>> {
+        if self.verify_randomized(data, get_crypto_rng()).is_ok() {
+            return Ok(());
+        }
+        Err(data
+            .iter()
+            .enumerate()
+            .filter(|(_, (pk, msg, sig))| {
+                sig.scheme() != self.scheme() || sig.verify(pk, msg).is_err()
+            })
+            .map(|(i, _)| i)
+            .collect())
+    }
+
+    /// Verify an aggregate of signatures on the same message against a
+    /// commitment to the signing key set.
+    ///
+    /// The commitment is the sum of the signers' public keys, so a verifier
+    /// only needs to store a single [`MultiPublicKey`] instead of every key.
+    /// Only the proof of possession scheme is accepted as summing keys is
+    /// only safe when every key's proof of possession was checked when the
+    /// commitment was formed.
+    pub fn verify_against_key_commitment<B: AsRef<[u8]>>(
+        &self,
+        key_set_commitment: &MultiPublicKey<C>,
+        msg: B,
+    ) -> BlsResult<()> {
+        match self {
+            Self::ProofOfPossession(sig) => {
+                <C as BlsSignaturePop>::verify(key_set_commitment.0, *sig, msg)
+            }
+            _ => Err(BlsError::InvalidSignatureScheme),
+        }
+    }
+
+    /// Verify an aggregate of signatures that all signed `msg`.
+    ///
+    /// The public keys are summed so only a single pairing check is needed,
+    /// i.e. `e(H(m), sum pk) == e(sig, g)`. This is the common case of
+    /// committee votes where many keys sign the same message.
+    /// Like [`Self::verify_against_key_commitment`] only the proof of
+    /// possession scheme is accepted.
+    pub fn verify_same_message<B: AsRef<[u8]>>(
+        &self,
+        pks: &[PublicKey<C>],
+        msg: B,
+    ) -> BlsResult<()> {
+        self.verify_same_message_returning_key(pks, msg).1
+    }
+
+    /// Same as [`Self::verify_same_message`] but also returns the aggregated
+    /// public key that was checked, e.g. for audit logs or to cache it
+    /// for later checks against the same signer set.
+    ///
+    /// The key is returned whether or not verification succeeds and is
+    /// always [`MultiPublicKey::from_public_keys`] over `pks`.
+    pub fn verify_same_message_returning_key<B: AsRef<[u8]>>(
+        &self,
+        pks: &[PublicKey<C>],
+        msg: B,
+    ) -> (MultiPublicKey<C>, BlsResult<()>) {
+        let apk = MultiPublicKey::from_public_keys(pks);
+        if pks.is_empty() {
+            return (
+                apk,
+                Err(BlsError::InvalidInputs(
+                    "no public keys to aggregate".to_string(),
+                )),
+            );
+        }
+        let res = self.verify_against_key_commitment(&apk, msg);
+        (apk, res)
+    }
+
+    /// Remove a signature that was previously accumulated into this aggregate.
+    ///
+    /// Fails if `sig` uses a different scheme than this aggregate
+    /// or if nothing would remain in the aggregate.
+    pub fn checked_sub(&self, sig: &Signature<C>) -> BlsResult<Self> {
+        if self.scheme() != sig.scheme() {
+            return Err(BlsError::InvalidSignatureScheme);
+        }
+        let g = *self.as_raw_value() - *sig.as_raw_value();
+        if g.is_identity().into() {
+            return Err(BlsError::InvalidSignature);
+        }
+        match self {
+            Self::Basic(_) => Ok(Self::Basic(g)),
+            Self::MessageAugmentation(_) => Ok(Self::MessageAugmentation(g)),
+            Self::ProofOfPossession(_) => Ok(Self::ProofOfPossession(g)),
+        }
+    }
+
+    /// Check that the signature is a valid point in the prime order subgroup
+    /// and is not the identity element
+    pub fn validate(&self) -> BlsResult<()> {
+        let sig = self.as_raw_value();
+        if (sig.is_identity() | !<C as Pairing>::signature_is_torsion_free(sig)).into() {
+            return Err(BlsError::InvalidSignature);
+        }
+        Ok(())
+    }
+
+    /// Extract the inner raw representation
+    pub fn as_raw_value(&self) -> &<C as Pairing>::Signature {
+        match self {
+            Self::Basic(s) => s,
+            Self::MessageAugmentation(s) => s,
+            Self::ProofOfPossession(s) => s,
+        }
+    }
 }
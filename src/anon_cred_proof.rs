@@ -0,0 +1,480 @@
+use crate::impls::inner_types::*;
+use crate::*;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+const CREDENTIAL_DST: &[u8] = b"BLS_ANON_CRED_BLS12381_XMD:SHA-256_SSWU_RO_";
+const GENERATOR_DST: &[u8] = b"BLS_ANON_CRED_GENERATOR_BLS12381_XMD:SHA-256_SSWU_RO_";
+const ATTRIBUTE_DST: &[u8] = b"BLS_ANON_CRED_ATTRIBUTE_BLS12381_XMD:SHA-256_";
+const EXPONENT_DST: &[u8] = b"BLS_ANON_CRED_EXPONENT_BLS12381_XMD:SHA-256_";
+const CHALLENGE_DST: &[u8] = b"BLS_ANON_CRED_CHALLENGE_BLS12381_XMD:SHA-256_";
+
+/// A credential issued by signing a commitment to all of its attributes.
+///
+/// The attributes are committed to together with a random blinding
+/// factor, each bound to its position, and the issuer signs the
+/// commitment `B` with a BBS style signature `A = B / (sk + e)` where
+/// `e` is derived from the commitment. Attributes from different
+/// credentials can't be combined since they open different
+/// commitments. The holder keeps the credential private and only
+/// ever shows an [`AnonCredProof`].
+pub struct AnonCredential<C: BlsSignatureImpl> {
+    /// The issuer's public key
+    pub issuer: PublicKey<C>,
+    /// The signed attributes in order
+    pub attributes: Vec<Vec<u8>>,
+    /// The blinding factor of the attribute commitment
+    pub blinding: <<C as Pairing>::PublicKey as Group>::Scalar,
+    /// The issuer's signature on the attribute commitment
+    pub signature: <C as Pairing>::Signature,
+}
+
+impl<C: BlsSignatureImpl> Clone for AnonCredential<C> {
+    fn clone(&self) -> Self {
+        Self {
+            issuer: self.issuer,
+            attributes: self.attributes.clone(),
+            blinding: self.blinding,
+            signature: self.signature,
+        }
+    }
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for AnonCredential<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "AnonCredential {{ issuer: {:?}, attributes: {:?}, blinding: <redacted>, signature: {:?} }}",
+            self.issuer, self.attributes, self.signature
+        )
+    }
+}
+
+impl<C: BlsSignatureImpl> Zeroize for AnonCredential<C> {
+    fn zeroize(&mut self) {
+        zeroize_scalar(&mut self.blinding);
+    }
+}
+
+impl<C: BlsSignatureImpl> Drop for AnonCredential<C> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C: BlsSignatureImpl> ZeroizeOnDrop for AnonCredential<C> {}
+
+impl<C: BlsSignatureImpl> AnonCredential<C> {
+    /// Issue a credential over `attributes` using the issuer's secret key
+    pub fn issue<B: AsRef<[u8]>>(sk: &SecretKey<C>, attributes: &[B]) -> BlsResult<Self> {
+        if attributes.is_empty() {
+            return Err(BlsError::InvalidInputs("no attributes to sign".to_string()));
+        }
+        if sk.0.is_zero().into() {
+            return Err(BlsError::InvalidInputs("secret key is zero".to_string()));
+        }
+        let attributes = attributes
+            .iter()
+            .map(|a| a.as_ref().to_vec())
+            .collect::<Vec<_>>();
+        let mut blinding = <<C as Pairing>::PublicKey as Group>::Scalar::random(get_crypto_rng());
+        // Should only happen with negligible probability but just in case
+        while blinding.is_zero().into() {
+            blinding = <<C as Pairing>::PublicKey as Group>::Scalar::random(get_crypto_rng());
+        }
+        let commitment = commit_attributes::<C>(&attributes, blinding);
+        let e = signature_exponent::<C>(commitment);
+        let inverse: Option<<<C as Pairing>::PublicKey as Group>::Scalar> =
+            (sk.0 + e).invert().into();
+        let inverse = inverse.ok_or(BlsError::InvalidSignature)?;
+        Ok(Self {
+            issuer: sk.public_key(),
+            attributes,
+            blinding,
+            signature: commitment * inverse,
+        })
+    }
+
+    /// Check the issuer's signature on the attribute commitment
+    pub fn verify(&self) -> BlsResult<()> {
+        if self.attributes.is_empty() {
+            return Err(BlsError::InvalidInputs("no attributes".to_string()));
+        }
+        if self.signature.is_identity().into() {
+            return Err(BlsError::InvalidSignature);
+        }
+        let commitment = commit_attributes::<C>(&self.attributes, self.blinding);
+        let e = signature_exponent::<C>(commitment);
+        let g = <C as Pairing>::PublicKey::generator();
+        // e(A, pk + g * e) == e(B, g)
+        if <C as Pairing>::pairing(&[(self.signature, self.issuer.0 + g * e), (commitment, -g)])
+            .is_identity()
+            .into()
+        {
+            Ok(())
+        } else {
+            Err(BlsError::InvalidSignature)
+        }
+    }
+
+    /// Prove possession of this credential while revealing only
+    /// the attributes at the `disclosed` positions.
+    ///
+    /// The proof is bound to `context`, e.g. a verifier chosen nonce,
+    /// so it can't be replayed elsewhere.
+    pub fn prove(&self, disclosed: &[usize], context: &[u8]) -> BlsResult<AnonCredProof<C>> {
+        AnonCredProof::generate(self, disclosed, context)
+    }
+}
+
+/// A non-interactive proof of possessing an [`AnonCredential`]
+/// that reveals a chosen subset of its attributes.
+///
+/// The credential signature `A` and its commitment `B` are randomized
+/// by a fresh `r` for every proof as `Abar = A * r` and
+/// `Bbar = B * r - Abar * e`, so `e(Abar, pk) == e(Bbar, g)` holds
+/// without revealing either. Knowledge of `1 / r`, `e / r`, the
+/// blinding factor and every undisclosed attribute opening `Bbar` is
+/// proven without revealing them, so two proofs over the same
+/// credential can't be linked.
+#[derive(PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AnonCredProof<C: BlsSignatureImpl> {
+    /// The randomized credential signature `Abar`
+    #[serde(serialize_with = "traits::signature::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::signature::deserialize::<C, _>")]
+    pub randomized_signature: <C as Pairing>::Signature,
+    /// The randomized attribute commitment `Bbar`
+    #[serde(serialize_with = "traits::signature::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::signature::deserialize::<C, _>")]
+    pub randomized_commitment: <C as Pairing>::Signature,
+    /// The fiat-shamir heuristic challenge
+    #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::scalar::deserialize::<C, _>")]
+    pub challenge: <<C as Pairing>::PublicKey as Group>::Scalar,
+    /// The response for `1 / r`
+    #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::scalar::deserialize::<C, _>")]
+    pub randomizer_response: <<C as Pairing>::PublicKey as Group>::Scalar,
+    /// The response for `e / r`
+    #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::scalar::deserialize::<C, _>")]
+    pub exponent_response: <<C as Pairing>::PublicKey as Group>::Scalar,
+    /// The response for the blinding factor
+    #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::scalar::deserialize::<C, _>")]
+    pub blinding_response: <<C as Pairing>::PublicKey as Group>::Scalar,
+    /// The responses for the undisclosed attributes in position order
+    #[serde(serialize_with = "traits::scalars::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::scalars::deserialize::<C, _>")]
+    pub hidden_responses: Vec<<<C as Pairing>::PublicKey as Group>::Scalar>,
+}
+
+impl<C: BlsSignatureImpl> Default for AnonCredProof<C> {
+    fn default() -> Self {
+        Self {
+            randomized_signature: <C as Pairing>::Signature::default(),
+            randomized_commitment: <C as Pairing>::Signature::default(),
+            challenge: <<C as Pairing>::PublicKey as Group>::Scalar::ZERO,
+            randomizer_response: <<C as Pairing>::PublicKey as Group>::Scalar::ZERO,
+            exponent_response: <<C as Pairing>::PublicKey as Group>::Scalar::ZERO,
+            blinding_response: <<C as Pairing>::PublicKey as Group>::Scalar::ZERO,
+            hidden_responses: Vec::new(),
+        }
+    }
+}
+
+impl<C: BlsSignatureImpl> Display for AnonCredProof<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{{ randomized_signature: {}, randomized_commitment: {}, hidden: {} }}",
+            self.randomized_signature,
+            self.randomized_commitment,
+            self.hidden_responses.len()
+        )
+    }
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for AnonCredProof<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{{ randomized_signature: {:?}, randomized_commitment: {:?}, challenge: {:?}, randomizer_response: {:?}, exponent_response: {:?}, blinding_response: {:?}, hidden_responses: {:?} }}",
+            self.randomized_signature,
+            self.randomized_commitment,
+            self.challenge,
+            self.randomizer_response,
+            self.exponent_response,
+            self.blinding_response,
+            self.hidden_responses
+        )
+    }
+}
+
+impl<C: BlsSignatureImpl> Clone for AnonCredProof<C> {
+    fn clone(&self) -> Self {
+        Self {
+            randomized_signature: self.randomized_signature,
+            randomized_commitment: self.randomized_commitment,
+            challenge: self.challenge,
+            randomizer_response: self.randomizer_response,
+            exponent_response: self.exponent_response,
+            blinding_response: self.blinding_response,
+            hidden_responses: self.hidden_responses.clone(),
+        }
+    }
+}
+
+impl<C: BlsSignatureImpl> From<&AnonCredProof<C>> for Vec<u8> {
+    fn from(value: &AnonCredProof<C>) -> Self {
+        serde_bare::to_vec(value).expect("Failed to serialize AnonCredProof")
+    }
+}
+
+impl<C: BlsSignatureImpl> TryFrom<&[u8]> for AnonCredProof<C> {
+    type Error = BlsError;
+
+    fn try_from(value: &[u8]) -> BlsResult<Self> {
+        let output = serde_bare::from_slice(value)?;
+        Ok(output)
+    }
+}
+
+impl_from_derivatives_generic!(AnonCredProof);
+
+impl<C: BlsSignatureImpl> AnonCredProof<C> {
+    /// Create a proof for `credential` disclosing the attributes at `disclosed`
+    pub fn generate(
+        credential: &AnonCredential<C>,
+        disclosed: &[usize],
+        context: &[u8],
+    ) -> BlsResult<Self> {
+        if credential.attributes.is_empty() {
+            return Err(BlsError::InvalidInputs("no attributes".to_string()));
+        }
+        let mut seen = vec![false; credential.attributes.len()];
+        for &i in disclosed {
+            match seen.get_mut(i) {
+                Some(s) if !*s => *s = true,
+                Some(_) => {
+                    return Err(BlsError::InvalidInputs(format!(
+                        "attribute {} is disclosed more than once",
+                        i
+                    )))
+                }
+                None => {
+                    return Err(BlsError::InvalidInputs(format!(
+                        "attribute {} does not exist",
+                        i
+                    )))
+                }
+            }
+        }
+        let hidden = (0..seen.len()).filter(|i| !seen[*i]).collect::<Vec<_>>();
+
+        let commitment = commit_attributes::<C>(&credential.attributes, credential.blinding);
+        let e = signature_exponent::<C>(commitment);
+        let mut rng = get_crypto_rng();
+        let mut r = <<C as Pairing>::Signature as Group>::Scalar::random(&mut rng);
+        // Should only happen with negligible probability but just in case
+        while r.is_zero().into() {
+            r = <<C as Pairing>::Signature as Group>::Scalar::random(&mut rng);
+        }
+        let randomized_signature = credential.signature * r;
+        let randomized_commitment = commitment * r - randomized_signature * e;
+        let r_inv = r.invert().unwrap();
+
+        let randomizer_nonce = <<C as Pairing>::Signature as Group>::Scalar::random(&mut rng);
+        let exponent_nonce = <<C as Pairing>::Signature as Group>::Scalar::random(&mut rng);
+        let blinding_nonce = <<C as Pairing>::Signature as Group>::Scalar::random(&mut rng);
+        let hidden_nonces = hidden
+            .iter()
+            .map(|_| <<C as Pairing>::Signature as Group>::Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+        let t = hidden.iter().zip(&hidden_nonces).fold(
+            randomized_commitment * randomizer_nonce + randomized_signature * exponent_nonce
+                - blinding_generator::<C>() * blinding_nonce,
+            |acc, (i, n)| acc - attribute_generator::<C>(*i) * n,
+        );
+
+        let disclosed = disclosed
+            .iter()
+            .map(|&i| (i, credential.attributes[i].as_slice()))
+            .collect::<Vec<_>>();
+        let y = challenge::<C>(
+            credential.issuer,
+            randomized_signature,
+            randomized_commitment,
+            t,
+            seen.len(),
+            disclosed,
+            context,
+        );
+        let hidden_responses = hidden
+            .iter()
+            .zip(hidden_nonces)
+            .map(|(i, n)| n + y * attribute_scalar::<C>(&credential.attributes[*i]))
+            .collect();
+        Ok(Self {
+            randomized_signature,
+            randomized_commitment,
+            challenge: y,
+            randomizer_response: randomizer_nonce + y * r_inv,
+            exponent_response: exponent_nonce + y * e * r_inv,
+            blinding_response: blinding_nonce + y * credential.blinding,
+            hidden_responses,
+        })
+    }
+
+    /// Verify the proof against the issuer's public key, the disclosed
+    /// attributes paired with their positions, and the proof `context`
+    pub fn verify<B: AsRef<[u8]>>(
+        &self,
+        issuer_pk: PublicKey<C>,
+        disclosed: &[(usize, B)],
+        context: &[u8],
+    ) -> BlsResult<()> {
+        let count = disclosed.len() + self.hidden_responses.len();
+        if count == 0 {
+            return Err(BlsError::InvalidInputs("no attributes".to_string()));
+        }
+        if self.randomized_signature.is_identity().into() {
+            return Err(BlsError::InvalidInputs(
+                "randomized signature is the identity point".to_string(),
+            ));
+        }
+        // e(Abar, pk) == e(Bbar, g) holds exactly when Abar = A * r and
+        // Bbar = B * r - Abar * e for a signature A on commitment B
+        let g = <C as Pairing>::PublicKey::generator();
+        if (!<C as Pairing>::pairing(&[
+            (self.randomized_signature, issuer_pk.0),
+            (self.randomized_commitment, -g),
+        ])
+        .is_identity())
+        .into()
+        {
+            return Err(BlsError::InvalidProof);
+        }
+        let mut seen = vec![false; count];
+        for (i, _) in disclosed {
+            match seen.get_mut(*i) {
+                Some(s) if !*s => *s = true,
+                Some(_) => {
+                    return Err(BlsError::InvalidInputs(format!(
+                        "attribute {} is disclosed more than once",
+                        i
+                    )))
+                }
+                None => {
+                    return Err(BlsError::InvalidInputs(format!(
+                        "attribute {} does not exist",
+                        i
+                    )))
+                }
+            }
+        }
+        let hidden = (0..count).filter(|i| !seen[*i]);
+
+        // Bbar / r + Abar * e / r - blinding - undisclosed attributes
+        // leaves the base and the disclosed attributes
+        let disclosed_commitment = disclosed
+            .iter()
+            .fold(credential_base::<C>(), |acc, (i, a)| {
+                acc + attribute_generator::<C>(*i) * attribute_scalar::<C>(a.as_ref())
+            });
+        let t = hidden.zip(&self.hidden_responses).fold(
+            self.randomized_commitment * self.randomizer_response
+                + self.randomized_signature * self.exponent_response
+                - blinding_generator::<C>() * self.blinding_response
+                - disclosed_commitment * self.challenge,
+            |acc, (i, s)| acc - attribute_generator::<C>(i) * s,
+        );
+        let y = challenge::<C>(
+            issuer_pk,
+            self.randomized_signature,
+            self.randomized_commitment,
+            t,
+            count,
+            disclosed.iter().map(|(i, a)| (*i, a.as_ref())).collect(),
+            context,
+        );
+        if y != self.challenge {
+            return Err(BlsError::InvalidProof);
+        }
+        Ok(())
+    }
+}
+
+/// The base of every attribute commitment
+fn credential_base<C: BlsSignatureImpl>() -> <C as Pairing>::Signature {
+    <C as HashToPoint>::hash_to_point(b"credential base", CREDENTIAL_DST)
+}
+
+/// The generator for the blinding factor of the attribute commitment
+fn blinding_generator<C: BlsSignatureImpl>() -> <C as Pairing>::Signature {
+    <C as HashToPoint>::hash_to_point(b"blinding factor", GENERATOR_DST)
+}
+
+/// The generator binding an attribute to its position in the credential
+fn attribute_generator<C: BlsSignatureImpl>(index: usize) -> <C as Pairing>::Signature {
+    <C as HashToPoint>::hash_to_point((index as u64).to_be_bytes(), GENERATOR_DST)
+}
+
+/// Hash an attribute to the scalar that is committed to
+fn attribute_scalar<C: BlsSignatureImpl>(
+    attribute: &[u8],
+) -> <<C as Pairing>::Signature as Group>::Scalar {
+    <C as HashToScalar>::hash_to_scalar(attribute, ATTRIBUTE_DST)
+}
+
+/// Commit to every attribute at its position and the blinding factor
+fn commit_attributes<C: BlsSignatureImpl>(
+    attributes: &[Vec<u8>],
+    blinding: <<C as Pairing>::Signature as Group>::Scalar,
+) -> <C as Pairing>::Signature {
+    attributes.iter().enumerate().fold(
+        credential_base::<C>() + blinding_generator::<C>() * blinding,
+        |acc, (i, a)| acc + attribute_generator::<C>(i) * attribute_scalar::<C>(a),
+    )
+}
+
+/// Derive the signature exponent `e` from the attribute commitment
+fn signature_exponent<C: BlsSignatureImpl>(
+    commitment: <C as Pairing>::Signature,
+) -> <<C as Pairing>::Signature as Group>::Scalar {
+    <C as HashToScalar>::hash_to_scalar(commitment.to_bytes().as_ref(), EXPONENT_DST)
+}
+
+/// Compute the Fiat-Shamir challenge binding the proof to its context
+fn challenge<C: BlsSignatureImpl>(
+    issuer_pk: PublicKey<C>,
+    randomized_signature: <C as Pairing>::Signature,
+    randomized_commitment: <C as Pairing>::Signature,
+    t: <C as Pairing>::Signature,
+    count: usize,
+    mut disclosed: Vec<(usize, &[u8])>,
+    context: &[u8],
+) -> <<C as Pairing>::Signature as Group>::Scalar {
+    // The verifier may list the disclosed attributes in any order
+    disclosed.sort_unstable_by_key(|(i, _)| *i);
+
+    let mut transcript = merlin::Transcript::new(b"AnonCredProof");
+    transcript.append_message(b"issuer", issuer_pk.0.to_bytes().as_ref());
+    transcript.append_message(
+        b"randomized signature",
+        randomized_signature.to_bytes().as_ref(),
+    );
+    transcript.append_message(
+        b"randomized commitment",
+        randomized_commitment.to_bytes().as_ref(),
+    );
+    transcript.append_message(b"t", t.to_bytes().as_ref());
+    transcript.append_u64(b"count", count as u64);
+    for (i, a) in disclosed {
+        transcript.append_u64(b"index", i as u64);
+        transcript.append_message(b"attribute", a);
+    }
+    transcript.append_message(b"context", context);
+    let mut challenge = [0u8; 64];
+    transcript.challenge_bytes(b"challenge", &mut challenge);
+    <C as HashToScalar>::hash_to_scalar(challenge, CHALLENGE_DST)
+}
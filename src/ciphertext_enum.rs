@@ -0,0 +1,215 @@
+use crate::*;
+use serde::de::{SeqAccess, Visitor};
+
+const SIGN_CRYPT: u8 = 1;
+const TIME_CRYPT: u8 = 2;
+
+/// A sign crypt or time lock ciphertext that carries which curve
+/// and which encryption it uses.
+///
+/// Lets ciphertexts for both implementations be stored together and
+/// decrypted at runtime without knowing the curve at compile time.
+/// The serialized form is tagged with [`Bls12381`] followed by the
+/// ciphertext type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CiphertextEnum {
+    /// A sign crypt ciphertext for keys with signatures in G1
+    SignCryptG1(SignCryptCiphertext<Bls12381G1Impl>),
+    /// A sign crypt ciphertext for keys with signatures in G2
+    SignCryptG2(SignCryptCiphertext<Bls12381G2Impl>),
+    /// A time lock ciphertext for keys with signatures in G1
+    TimeCryptG1(TimeCryptCiphertext<Bls12381G1Impl>),
+    /// A time lock ciphertext for keys with signatures in G2
+    TimeCryptG2(TimeCryptCiphertext<Bls12381G2Impl>),
+}
+
+impl Serialize for CiphertextEnum {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            CiphertextEnum::SignCryptG1(v) => (Bls12381::G1, SIGN_CRYPT, v).serialize(s),
+            CiphertextEnum::SignCryptG2(v) => (Bls12381::G2, SIGN_CRYPT, v).serialize(s),
+            CiphertextEnum::TimeCryptG1(v) => (Bls12381::G1, TIME_CRYPT, v).serialize(s),
+            CiphertextEnum::TimeCryptG2(v) => (Bls12381::G2, TIME_CRYPT, v).serialize(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CiphertextEnum {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct CiphertextEnumVisitor;
+
+        impl<'de> Visitor<'de> for CiphertextEnumVisitor {
+            type Value = CiphertextEnum;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "a tuple of the curve, ciphertext type and ciphertext")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ee = seq
+                    .next_element::<Bls12381>()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                let tt = seq
+                    .next_element::<u8>()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                macro_rules! next {
+                    ($ty:ty, $variant:ident) => {
+                        seq.next_element::<$ty>()?
+                            .map(CiphertextEnum::$variant)
+                            .ok_or_else(|| serde::de::Error::invalid_length(2, &self))
+                    };
+                }
+                match (ee, tt) {
+                    (Bls12381::G1, SIGN_CRYPT) => {
+                        next!(SignCryptCiphertext<Bls12381G1Impl>, SignCryptG1)
+                    }
+                    (Bls12381::G2, SIGN_CRYPT) => {
+                        next!(SignCryptCiphertext<Bls12381G2Impl>, SignCryptG2)
+                    }
+                    (Bls12381::G1, TIME_CRYPT) => {
+                        next!(TimeCryptCiphertext<Bls12381G1Impl>, TimeCryptG1)
+                    }
+                    (Bls12381::G2, TIME_CRYPT) => {
+                        next!(TimeCryptCiphertext<Bls12381G2Impl>, TimeCryptG2)
+                    }
+                    (_, t) => Err(serde::de::Error::custom(format!(
+                        "invalid ciphertext type {}",
+                        t
+                    ))),
+                }
+            }
+        }
+        d.deserialize_tuple(3, CiphertextEnumVisitor)
+    }
+}
+
+impl From<SignCryptCiphertext<Bls12381G1Impl>> for CiphertextEnum {
+    fn from(value: SignCryptCiphertext<Bls12381G1Impl>) -> Self {
+        Self::SignCryptG1(value)
+    }
+}
+
+impl From<SignCryptCiphertext<Bls12381G2Impl>> for CiphertextEnum {
+    fn from(value: SignCryptCiphertext<Bls12381G2Impl>) -> Self {
+        Self::SignCryptG2(value)
+    }
+}
+
+impl From<TimeCryptCiphertext<Bls12381G1Impl>> for CiphertextEnum {
+    fn from(value: TimeCryptCiphertext<Bls12381G1Impl>) -> Self {
+        Self::TimeCryptG1(value)
+    }
+}
+
+impl From<TimeCryptCiphertext<Bls12381G2Impl>> for CiphertextEnum {
+    fn from(value: TimeCryptCiphertext<Bls12381G2Impl>) -> Self {
+        Self::TimeCryptG2(value)
+    }
+}
+
+impl From<&CiphertextEnum> for Vec<u8> {
+    fn from(value: &CiphertextEnum) -> Self {
+        let (tt, mut output) = match value {
+            CiphertextEnum::SignCryptG1(v) => (SIGN_CRYPT, Vec::from(v)),
+            CiphertextEnum::SignCryptG2(v) => (SIGN_CRYPT, Vec::from(v)),
+            CiphertextEnum::TimeCryptG1(v) => (TIME_CRYPT, Vec::from(v)),
+            CiphertextEnum::TimeCryptG2(v) => (TIME_CRYPT, Vec::from(v)),
+        };
+        output.insert(0, tt);
+        output.insert(0, u8::from(value.curve()));
+        output
+    }
+}
+
+impl TryFrom<&[u8]> for CiphertextEnum {
+    type Error = BlsError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() < 2 {
+            return Err(BlsError::DeserializationError(
+                "input is too short".to_string(),
+            ));
+        }
+        let ee = Bls12381::try_from(value[0])?;
+        let bytes = &value[2..];
+        let err = |e: BlsError| {
+            BlsError::DeserializationError(format!("invalid {} ciphertext: {}", ee, e))
+        };
+        match (ee, value[1]) {
+            (Bls12381::G1, SIGN_CRYPT) => SignCryptCiphertext::try_from(bytes)
+                .map(Self::SignCryptG1)
+                .map_err(err),
+            (Bls12381::G2, SIGN_CRYPT) => SignCryptCiphertext::try_from(bytes)
+                .map(Self::SignCryptG2)
+                .map_err(err),
+            (Bls12381::G1, TIME_CRYPT) => TimeCryptCiphertext::try_from(bytes)
+                .map(Self::TimeCryptG1)
+                .map_err(err),
+            (Bls12381::G2, TIME_CRYPT) => TimeCryptCiphertext::try_from(bytes)
+                .map(Self::TimeCryptG2)
+                .map_err(err),
+            (_, t) => Err(BlsError::DeserializationError(format!(
+                "invalid ciphertext type {}",
+                t
+            ))),
+        }
+    }
+}
+
+impl_from_derivatives!(CiphertextEnum);
+
+impl CiphertextEnum {
+    /// The curve implementation of this ciphertext
+    pub fn curve(&self) -> Bls12381 {
+        match self {
+            Self::SignCryptG1(_) | Self::TimeCryptG1(_) => Bls12381::G1,
+            Self::SignCryptG2(_) | Self::TimeCryptG2(_) => Bls12381::G2,
+        }
+    }
+
+    /// Is this a time lock ciphertext
+    pub fn is_time_lock(&self) -> bool {
+        matches!(self, Self::TimeCryptG1(_) | Self::TimeCryptG2(_))
+    }
+
+    /// Decrypt a sign crypt ciphertext with the recipient's secret key
+    pub fn decrypt_with_secret_key(&self, sk: &SecretKeyEnum) -> BlsResult<Vec<u8>> {
+        let res = match (self, sk) {
+            (Self::SignCryptG1(c), SecretKeyEnum::G1(sk)) => c.decrypt(sk),
+            (Self::SignCryptG2(c), SecretKeyEnum::G2(sk)) => c.decrypt(sk),
+            (Self::TimeCryptG1(_) | Self::TimeCryptG2(_), _) => {
+                return Err(BlsError::InvalidInputs(
+                    "time lock ciphertexts are decrypted with a signature".to_string(),
+                ))
+            }
+            _ => {
+                return Err(BlsError::InvalidInputs(
+                    "secret key is for a different curve".to_string(),
+                ))
+            }
+        };
+        Option::<Vec<u8>>::from(res).ok_or(BlsError::DecryptionFailed)
+    }
+
+    /// Decrypt a time lock ciphertext with the signature over its identifier
+    pub fn decrypt_with_signature(&self, sig: &SignatureEnum) -> BlsResult<Vec<u8>> {
+        let res = match (self, sig) {
+            (Self::TimeCryptG1(c), SignatureEnum::G1(sig)) => c.decrypt(sig),
+            (Self::TimeCryptG2(c), SignatureEnum::G2(sig)) => c.decrypt(sig),
+            (Self::SignCryptG1(_) | Self::SignCryptG2(_), _) => {
+                return Err(BlsError::InvalidInputs(
+                    "sign crypt ciphertexts are decrypted with a secret key".to_string(),
+                ))
+            }
+            _ => {
+                return Err(BlsError::InvalidInputs(
+                    "signature is for a different curve".to_string(),
+                ))
+            }
+        };
+        Option::<Vec<u8>>::from(res).ok_or(BlsError::DecryptionFailed)
+    }
+}
@@ -0,0 +1,118 @@
+use crate::impls::inner_types::*;
+use crate::*;
+
+/// The public transcript of a dealer splitting a secret key.
+///
+/// Contains the Feldman commitments to each coefficient of the
+/// sharing polynomial. The first commitment is the public key
+/// and the remaining commitments bind the dealer's randomness,
+/// so anyone can later check the published public key shares
+/// were all derived from the same polynomial.
+#[derive(PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealerTranscript<C: BlsSignatureImpl> {
+    /// The number of shares required to reconstruct the secret
+    pub threshold: usize,
+    /// The commitments to the polynomial coefficients
+    #[serde(bound(
+        serialize = "PublicKey<C>: Serialize",
+        deserialize = "PublicKey<C>: Deserialize<'de>"
+    ))]
+    pub commitments: Vec<PublicKey<C>>,
+}
+
+/// The Feldman commitments produced by [`SecretKey::split_with_transcript`]
+/// for checking shares with [`SecretKeyShare::verify_feldman`]
+pub type FeldmanCommitment<C> = DealerTranscript<C>;
+
+impl<C: BlsSignatureImpl> Clone for DealerTranscript<C> {
+    fn clone(&self) -> Self {
+        Self {
+            threshold: self.threshold,
+            commitments: self.commitments.clone(),
+        }
+    }
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for DealerTranscript<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "DealerTranscript {{ threshold: {}, commitments: {:?} }}",
+            self.threshold, self.commitments
+        )
+    }
+}
+
+impl_from_derivatives_generic!(DealerTranscript);
+
+impl<C: BlsSignatureImpl> From<&DealerTranscript<C>> for Vec<u8> {
+    fn from(value: &DealerTranscript<C>) -> Self {
+        serde_bare::to_vec(value).expect("failed to serialize dealer transcript")
+    }
+}
+
+impl<C: BlsSignatureImpl> TryFrom<&[u8]> for DealerTranscript<C> {
+    type Error = BlsError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let output = serde_bare::from_slice(value)?;
+        Ok(output)
+    }
+}
+
+impl<C: BlsSignatureImpl> DealerTranscript<C> {
+    /// The public key of the secret that was split
+    pub fn public_key(&self) -> Option<PublicKey<C>> {
+        self.commitments.first().copied()
+    }
+
+    /// Verify the public key shares were all created by the dealer
+    /// that produced this transcript
+    pub fn verify(&self, public_key_shares: &[PublicKeyShare<C>]) -> BlsResult<()> {
+        self.check_commitments()?;
+        if public_key_shares.len() < self.threshold {
+            return Err(BlsError::InsufficientShares(
+                self.threshold,
+                public_key_shares.len(),
+            ));
+        }
+        let commitments = self.commitments.iter().map(|c| c.0).collect::<Vec<_>>();
+        for (i, share) in public_key_shares.iter().enumerate() {
+            let id = share.0.identifier();
+            if id.0.is_zero().into() {
+                return Err(BlsError::InvalidInputs(format!(
+                    "public key share at {} has a zero identifier",
+                    i + 1
+                )));
+            }
+            if public_key_shares[..i]
+                .iter()
+                .any(|s| s.0.identifier() == id)
+            {
+                return Err(BlsError::DuplicateShareIdentifier);
+            }
+            if evaluate_commitments(&commitments, id.0) != share.0.value().0 {
+                return Err(BlsError::InvalidInputs(format!(
+                    "public key share at {} does not match the transcript",
+                    i + 1
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the commitments are well formed for the threshold
+    pub(crate) fn check_commitments(&self) -> BlsResult<()> {
+        if self.threshold < 2 || self.commitments.len() != self.threshold {
+            return Err(BlsError::InvalidInputs(
+                "transcript threshold does not match the commitments".to_string(),
+            ));
+        }
+        if self.commitments.iter().any(|c| c.0.is_identity().into()) {
+            return Err(BlsError::InvalidInputs(
+                "transcript commitment is the identity point".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
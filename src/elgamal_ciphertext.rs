@@ -1,5 +1,7 @@
+use crate::impls::inner_types::*;
 use crate::*;
-use core::ops::{Add, AddAssign};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use rand_core::{CryptoRng, RngCore};
 
 /// An ElGamal ciphertext
 #[derive(Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -96,6 +98,83 @@ impl<'a, C: BlsSignatureImpl> AddAssign<&'a ElGamalCiphertext<C>> for ElGamalCip
     }
 }
 
+impl<'a, 'b, C: BlsSignatureImpl> Sub<&'b ElGamalCiphertext<C>> for &'a ElGamalCiphertext<C> {
+    type Output = ElGamalCiphertext<C>;
+
+    fn sub(self, rhs: &'b ElGamalCiphertext<C>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<'a, C: BlsSignatureImpl> Sub<&'a ElGamalCiphertext<C>> for ElGamalCiphertext<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: &'a ElGamalCiphertext<C>) -> Self::Output {
+        self - *rhs
+    }
+}
+
+impl<'a, C: BlsSignatureImpl> Sub<ElGamalCiphertext<C>> for &'a ElGamalCiphertext<C> {
+    type Output = ElGamalCiphertext<C>;
+
+    fn sub(self, rhs: ElGamalCiphertext<C>) -> Self::Output {
+        *self - rhs
+    }
+}
+
+impl<C: BlsSignatureImpl> Sub<ElGamalCiphertext<C>> for ElGamalCiphertext<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            c1: self.c1 - rhs.c1,
+            c2: self.c2 - rhs.c2,
+        }
+    }
+}
+
+impl<C: BlsSignatureImpl> SubAssign<ElGamalCiphertext<C>> for ElGamalCiphertext<C> {
+    fn sub_assign(&mut self, rhs: ElGamalCiphertext<C>) {
+        self.c1 -= rhs.c1;
+        self.c2 -= rhs.c2;
+    }
+}
+
+impl<'a, C: BlsSignatureImpl> SubAssign<&'a ElGamalCiphertext<C>> for ElGamalCiphertext<C> {
+    fn sub_assign(&mut self, rhs: &'a ElGamalCiphertext<C>) {
+        self.c1 -= rhs.c1;
+        self.c2 -= rhs.c2;
+    }
+}
+
+impl<C: BlsSignatureImpl> Mul<<<C as Pairing>::PublicKey as Group>::Scalar>
+    for ElGamalCiphertext<C>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: <<C as Pairing>::PublicKey as Group>::Scalar) -> Self::Output {
+        self.scale(rhs)
+    }
+}
+
+impl<'a, C: BlsSignatureImpl> Mul<<<C as Pairing>::PublicKey as Group>::Scalar>
+    for &'a ElGamalCiphertext<C>
+{
+    type Output = ElGamalCiphertext<C>;
+
+    fn mul(self, rhs: <<C as Pairing>::PublicKey as Group>::Scalar) -> Self::Output {
+        self.scale(rhs)
+    }
+}
+
+impl<C: BlsSignatureImpl> MulAssign<<<C as Pairing>::PublicKey as Group>::Scalar>
+    for ElGamalCiphertext<C>
+{
+    fn mul_assign(&mut self, rhs: <<C as Pairing>::PublicKey as Group>::Scalar) {
+        *self = self.scale(rhs);
+    }
+}
+
 impl<C: BlsSignatureImpl> From<&ElGamalCiphertext<C>> for Vec<u8> {
     fn from(value: &ElGamalCiphertext<C>) -> Self {
         serde_bare::to_vec(value).expect("failed to serialize ElGamalCiphertext")
@@ -114,8 +193,115 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for ElGamalCiphertext<C> {
 impl_from_derivatives_generic!(ElGamalCiphertext);
 
 impl<C: BlsSignatureImpl> ElGamalCiphertext<C> {
+    /// The number of bytes in the compact encoding
+    pub const BYTES: usize = 2 * <C as Pairing>::PUBLIC_KEY_BYTES;
+
+    /// Get the compact encoding `c1 || c2` of compressed points
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut output = Vec::with_capacity(Self::BYTES);
+        output.extend_from_slice(self.c1.to_bytes().as_ref());
+        output.extend_from_slice(self.c2.to_bytes().as_ref());
+        output
+    }
+
+    /// Read the compact encoding produced by [`ElGamalCiphertext::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> BlsResult<Self> {
+        if bytes.len() != Self::BYTES {
+            return Err(BlsError::InvalidInputs(format!(
+                "Invalid length, expected {}, got {}",
+                Self::BYTES,
+                bytes.len()
+            )));
+        }
+        let (c1, c2) = bytes.split_at(<C as Pairing>::PUBLIC_KEY_BYTES);
+        Ok(Self {
+            c1: PublicKey::<C>::try_from_unchecked(c1)?.0,
+            c2: PublicKey::<C>::try_from_unchecked(c2)?.0,
+        })
+    }
+
+    /// Multiply both components by a public scalar so the encrypted
+    /// message is multiplied by `scalar`
+    pub fn scale(&self, scalar: <<C as Pairing>::PublicKey as Group>::Scalar) -> Self {
+        Self {
+            c1: self.c1 * scalar,
+            c2: self.c2 * scalar,
+        }
+    }
+
+    /// Add a fresh encryption of zero under `pk` so the result is unlinkable
+    /// to this ciphertext but decrypts to the same value
+    pub fn rerandomize(&self, pk: &PublicKey<C>, mut rng: impl RngCore + CryptoRng) -> Self {
+        let blinder = <<C as Pairing>::PublicKey as Group>::Scalar::random(&mut rng);
+        self.rerandomize_inner(pk, blinder)
+    }
+
+    /// Rerandomize this ciphertext with a caller supplied blinder.
+    ///
+    /// Prefer [`ElGamalCiphertext::rerandomize`] unless deterministic output
+    /// is required.
+    pub fn rerandomize_with_blinder(
+        &self,
+        pk: &PublicKey<C>,
+        blinder: &SecretKey<C>,
+    ) -> BlsResult<Self> {
+        if blinder.0.is_zero().into() {
+            return Err(BlsError::InvalidInputs("Blinder is zero".to_string()));
+        }
+        Ok(self.rerandomize_inner(pk, blinder.0))
+    }
+
+    fn rerandomize_inner(
+        &self,
+        pk: &PublicKey<C>,
+        blinder: <<C as Pairing>::PublicKey as Group>::Scalar,
+    ) -> Self {
+        Self {
+            c1: self.c1 + <C as Pairing>::PublicKey::generator() * blinder,
+            c2: self.c2 + pk.0 * blinder,
+        }
+    }
+
     /// Decrypt this ciphertext
     pub fn decrypt(&self, sk: &SecretKey<C>) -> <C as Pairing>::PublicKey {
         <C as BlsElGamal>::decrypt(sk.0, self.c1, self.c2)
     }
+
+    /// Decrypt this ciphertext and recover a small scalar message `m <= max`
+    ///
+    /// [`ElGamalCiphertext::decrypt`] returns the message in the exponent so
+    /// recovering `m` requires a discrete log. This uses baby-step giant-step
+    /// which takes `O(sqrt(max))` time and memory, so only use it for bounded
+    /// message spaces. Returns `None` if the message is larger than `max`
+    /// and an error if `max` is larger than [`MAX_DISCRETE_LOG`].
+    pub fn decrypt_scalar(&self, sk: &SecretKey<C>, max: u64) -> BlsResult<Option<u64>> {
+        discrete_log(
+            <C as BlsElGamal>::message_generator(),
+            self.decrypt(sk),
+            max,
+        )
+    }
+
+    /// Create a decryption share from a secret key share
+    ///
+    /// The share carries no proof, use
+    /// [`SecretKeyShare::elgamal_decryption_share`] for a verifiable share.
+    pub fn create_decryption_share(
+        &self,
+        sks: &SecretKeyShare<C>,
+    ) -> BlsResult<ElGamalDecryptionShare<C>> {
+        Ok(ElGamalDecryptionShare(
+            <C as BlsSignatureCore>::public_key_share_with_generator(&sks.0, self.c1)?,
+            None,
+        ))
+    }
+
+    /// Decrypt this ciphertext by combining the decryption shares
+    pub fn decrypt_with_shares(
+        &self,
+        shares: &[ElGamalDecryptionShare<C>],
+    ) -> BlsResult<<C as Pairing>::PublicKey> {
+        let key = ElGamalDecryptionKey::from_shares(shares)?;
+        Ok(key.decrypt(self))
+    }
 }
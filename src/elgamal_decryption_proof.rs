@@ -0,0 +1,125 @@
+use crate::impls::inner_types::*;
+use crate::*;
+use rand_core::{CryptoRng, RngCore};
+
+const SALT: &[u8] = b"BLS_ELGAMAL_DECRYPTION_SHARE_PROOF_";
+
+/// A Chaum-Pedersen proof that an ElGamal decryption share was created
+/// with the same secret key share as its public key share.
+///
+/// Proves the discrete log of the public key share with respect to the
+/// public key generator equals the discrete log of the decryption share
+/// with respect to the ciphertext `c1`, so a corrupted share is caught
+/// before it is combined.
+#[derive(PartialEq, Eq, Serialize, Deserialize)]
+pub struct ElGamalDecryptionProof<C: BlsSignatureImpl> {
+    /// The fiat-shamir heuristic challenge
+    #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::scalar::deserialize::<C, _>")]
+    pub challenge: <<C as Pairing>::PublicKey as Group>::Scalar,
+    /// The response to the challenge
+    #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::scalar::deserialize::<C, _>")]
+    pub response: <<C as Pairing>::PublicKey as Group>::Scalar,
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for ElGamalDecryptionProof<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ElGamalDecryptionProof {{ challenge: {:?}, response: {:?} }}",
+            self.challenge, self.response
+        )
+    }
+}
+
+impl<C: BlsSignatureImpl> Copy for ElGamalDecryptionProof<C> {}
+
+impl<C: BlsSignatureImpl> Clone for ElGamalDecryptionProof<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: BlsSignatureImpl> From<&ElGamalDecryptionProof<C>> for Vec<u8> {
+    fn from(value: &ElGamalDecryptionProof<C>) -> Self {
+        serde_bare::to_vec(value).expect("failed to serialize ElGamalDecryptionProof")
+    }
+}
+
+impl<C: BlsSignatureImpl> TryFrom<&[u8]> for ElGamalDecryptionProof<C> {
+    type Error = BlsError;
+
+    fn try_from(value: &[u8]) -> BlsResult<Self> {
+        let proof = serde_bare::from_slice(value)?;
+        Ok(proof)
+    }
+}
+
+impl_from_derivatives_generic!(ElGamalDecryptionProof);
+
+impl<C: BlsSignatureImpl> ElGamalDecryptionProof<C> {
+    /// Prove `pk = g^x` and `share = c1^x` for the secret key share `x`
+    pub(crate) fn new(
+        sk: <<C as Pairing>::PublicKey as Group>::Scalar,
+        pk: <C as Pairing>::PublicKey,
+        c1: <C as Pairing>::PublicKey,
+        share: <C as Pairing>::PublicKey,
+        mut rng: impl RngCore + CryptoRng,
+    ) -> Self {
+        let k = <<C as Pairing>::PublicKey as Group>::Scalar::random(&mut rng);
+        let t1 = <C as Pairing>::PublicKey::generator() * k;
+        let t2 = c1 * k;
+        let challenge = Self::compute_challenge(pk, c1, share, t1, t2);
+        Self {
+            challenge,
+            response: k + challenge * sk,
+        }
+    }
+
+    /// Check the proof for `pk = g^x` and `share = c1^x`
+    pub(crate) fn verify(
+        &self,
+        pk: <C as Pairing>::PublicKey,
+        c1: <C as Pairing>::PublicKey,
+        share: <C as Pairing>::PublicKey,
+    ) -> BlsResult<()> {
+        if (pk.is_identity() | c1.is_identity() | share.is_identity()).into() {
+            return Err(BlsError::InvalidInputs(
+                "public key, ciphertext or decryption share is the identity point".to_string(),
+            ));
+        }
+        if (self.challenge.is_zero() | self.response.is_zero()).into() {
+            return Err(BlsError::InvalidInputs("Proof values are zero".to_string()));
+        }
+        let t1 = <C as Pairing>::PublicKey::generator() * self.response - pk * self.challenge;
+        let t2 = c1 * self.response - share * self.challenge;
+        if Self::compute_challenge(pk, c1, share, t1, t2) != self.challenge {
+            return Err(BlsError::InvalidProof);
+        }
+        Ok(())
+    }
+
+    fn compute_challenge(
+        pk: <C as Pairing>::PublicKey,
+        c1: <C as Pairing>::PublicKey,
+        share: <C as Pairing>::PublicKey,
+        t1: <C as Pairing>::PublicKey,
+        t2: <C as Pairing>::PublicKey,
+    ) -> <<C as Pairing>::PublicKey as Group>::Scalar {
+        let mut transcript = merlin::Transcript::new(b"ElGamalDecryptionProof");
+        transcript.append_message(b"dst", SALT);
+        transcript.append_message(
+            b"base point",
+            <C as Pairing>::PublicKey::generator().to_bytes().as_ref(),
+        );
+        transcript.append_message(b"pk", pk.to_bytes().as_ref());
+        transcript.append_message(b"c1", c1.to_bytes().as_ref());
+        transcript.append_message(b"share", share.to_bytes().as_ref());
+        transcript.append_message(b"t1", t1.to_bytes().as_ref());
+        transcript.append_message(b"t2", t2.to_bytes().as_ref());
+        let mut challenge = [0u8; 64];
+        transcript.challenge_bytes(b"challenge", &mut challenge);
+        <C as BlsElGamal>::scalar_from_bytes_wide(&challenge)
+    }
+}
@@ -3,26 +3,53 @@ use crate::*;
 /// A public key share is a point on the curve
 /// Must be combined with other public key shares
 /// in order to decrypt a ciphertext
+///
+/// Shares created with [`SecretKeyShare::elgamal_decryption_share`] carry a
+/// proof that can be checked with [`ElGamalDecryptionShare::verify`] before
+/// combining.
 #[derive(PartialEq, Eq, Serialize, Deserialize)]
 pub struct ElGamalDecryptionShare<C: BlsSignatureImpl>(
     #[serde(serialize_with = "traits::public_key_share::serialize::<C, _>")]
     #[serde(deserialize_with = "traits::public_key_share::deserialize::<C, _>")]
     pub <C as Pairing>::PublicKeyShare,
+    #[serde(bound(
+        serialize = "ElGamalDecryptionProof<C>: Serialize",
+        deserialize = "ElGamalDecryptionProof<C>: Deserialize<'de>"
+    ))]
+    pub Option<ElGamalDecryptionProof<C>>,
 );
 
 impl<C: BlsSignatureImpl> Clone for ElGamalDecryptionShare<C> {
     fn clone(&self) -> Self {
-        Self(self.0)
+        Self(self.0, self.1)
     }
 }
 
 impl<C: BlsSignatureImpl> fmt::Debug for ElGamalDecryptionShare<C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self.0)
+        write!(f, "{:?} {:?}", self.0, self.1)
     }
 }
 
-impl<C: BlsSignatureImpl> ElGamalDecryptionShare<C> {}
+impl<C: BlsSignatureImpl> ElGamalDecryptionShare<C> {
+    /// Check this decryption share was computed from `ciphertext` with the
+    /// secret key share belonging to `pks`
+    ///
+    /// Fails for shares without a proof.
+    pub fn verify(
+        &self,
+        pks: &PublicKeyShare<C>,
+        ciphertext: &ElGamalCiphertext<C>,
+    ) -> BlsResult<()> {
+        if self.0.identifier() != pks.0.identifier() {
+            return Err(BlsError::InvalidInputs(
+                "decryption and public shares do not correspond".to_string(),
+            ));
+        }
+        let proof = self.1.as_ref().ok_or(BlsError::InvalidProof)?;
+        proof.verify(pks.0.value().0, ciphertext.c1, self.0.value().0)
+    }
+}
 
 impl<C: BlsSignatureImpl> From<&ElGamalDecryptionShare<C>> for Vec<u8> {
     fn from(value: &ElGamalDecryptionShare<C>) -> Self {
@@ -50,6 +77,12 @@ pub struct ElGamalDecryptionKey<C: BlsSignatureImpl>(
     pub <C as Pairing>::PublicKey,
 );
 
+impl<C: BlsSignatureImpl> fmt::Debug for ElGamalDecryptionKey<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
 impl<C: BlsSignatureImpl> Clone for ElGamalDecryptionKey<C> {
     fn clone(&self) -> Self {
         Self(self.0)
@@ -74,12 +107,12 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for ElGamalDecryptionKey<C> {
 impl_from_derivatives_generic!(ElGamalDecryptionKey);
 
 impl<C: BlsSignatureImpl> ElGamalDecryptionKey<C> {
-    /// Decrypt signcrypt ciphertext
+    /// Decrypt ElGamal ciphertext
     pub fn decrypt(&self, ciphertext: &ElGamalCiphertext<C>) -> <C as Pairing>::PublicKey {
         ciphertext.c2 - self.0
     }
 
-    /// Combine decryption shares into a signcrypt decryption key
+    /// Combine decryption shares into an ElGamal decryption key
     pub fn from_shares(shares: &[ElGamalDecryptionShare<C>]) -> BlsResult<Self> {
         let points = shares
             .iter()
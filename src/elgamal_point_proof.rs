@@ -0,0 +1,91 @@
+use crate::impls::inner_types::*;
+use crate::*;
+
+/// An ElGamal encrypted point with a proof of knowledge of the blinder
+///
+/// Shows the ciphertext was honestly formed by the sender without
+/// revealing the encrypted point.
+#[derive(Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ElGamalPointProof<C: BlsSignatureImpl> {
+    /// The el-gamal ciphertext
+    #[serde(bound(
+        serialize = "ElGamalCiphertext<C>: Serialize",
+        deserialize = "ElGamalCiphertext<C>: Deserialize<'de>"
+    ))]
+    pub ciphertext: ElGamalCiphertext<C>,
+    /// The proof of the blinder
+    #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::scalar::deserialize::<C, _>")]
+    pub blinder_proof: <<C as Pairing>::PublicKey as Group>::Scalar,
+    /// The fiat-shamir heuristic challenge
+    #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::scalar::deserialize::<C, _>")]
+    pub challenge: <<C as Pairing>::PublicKey as Group>::Scalar,
+}
+
+impl<C: BlsSignatureImpl> Display for ElGamalPointProof<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{{ciphertext: {}, blinder_proof: {:?}, challenge: {:?}}}",
+            self.ciphertext, self.blinder_proof, self.challenge
+        )
+    }
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for ElGamalPointProof<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{{ciphertext: {:?}, blinder_proof: {:?}, challenge: {:?}}}",
+            self.ciphertext, self.blinder_proof, self.challenge
+        )
+    }
+}
+
+impl<C: BlsSignatureImpl> Copy for ElGamalPointProof<C> {}
+
+impl<C: BlsSignatureImpl> Clone for ElGamalPointProof<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: BlsSignatureImpl> From<&ElGamalPointProof<C>> for Vec<u8> {
+    fn from(value: &ElGamalPointProof<C>) -> Self {
+        serde_bare::to_vec(value).expect("Failed to serialize ElGamalPointProof")
+    }
+}
+
+impl<C: BlsSignatureImpl> TryFrom<&[u8]> for ElGamalPointProof<C> {
+    type Error = BlsError;
+
+    fn try_from(value: &[u8]) -> BlsResult<Self> {
+        let proof = serde_bare::from_slice(value)?;
+        Ok(proof)
+    }
+}
+
+impl_from_derivatives_generic!(ElGamalPointProof);
+
+impl<C: BlsSignatureImpl> ElGamalPointProof<C> {
+    /// Verify the proof and ciphertext are valid
+    pub fn verify(&self, pk: PublicKey<C>) -> BlsResult<()> {
+        <C as BlsElGamal>::verify_point_proof(
+            pk.0,
+            self.ciphertext.c1,
+            self.ciphertext.c2,
+            self.blinder_proof,
+            self.challenge,
+        )
+    }
+
+    /// Verify the proof and ciphertext then decrypt the point
+    pub fn verify_and_decrypt(&self, sk: &SecretKey<C>) -> BlsResult<<C as Pairing>::PublicKey> {
+        if sk.0.is_zero().into() {
+            return Err(BlsError::InvalidInputs("secret key is zero".to_string()));
+        }
+        self.verify(sk.public_key())?;
+        Ok(self.ciphertext.decrypt(sk))
+    }
+}
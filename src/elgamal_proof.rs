@@ -70,6 +70,49 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for ElGamalProof<C> {
 impl_from_derivatives_generic!(ElGamalProof);
 
 impl<C: BlsSignatureImpl> ElGamalProof<C> {
+    /// The number of bytes in the compact encoding
+    pub const BYTES: usize = ElGamalCiphertext::<C>::BYTES + 3 * SECRET_KEY_BYTES;
+
+    /// Get the compact encoding of the ciphertext followed by the
+    /// message proof, blinder proof and challenge as big-endian scalars
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut output = self.ciphertext.to_bytes();
+        output.reserve(3 * SECRET_KEY_BYTES);
+        for s in [self.message_proof, self.blinder_proof, self.challenge] {
+            output.extend_from_slice(&scalar_to_be_bytes::<C, SECRET_KEY_BYTES>(s));
+        }
+        output
+    }
+
+    /// Read the compact encoding produced by [`ElGamalProof::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> BlsResult<Self> {
+        if bytes.len() != Self::BYTES {
+            return Err(BlsError::InvalidInputs(format!(
+                "Invalid length, expected {}, got {}",
+                Self::BYTES,
+                bytes.len()
+            )));
+        }
+        let (ciphertext, scalars) = bytes.split_at(ElGamalCiphertext::<C>::BYTES);
+        let ciphertext = ElGamalCiphertext::from_bytes(ciphertext)?;
+        let mut values = [<<C as Pairing>::PublicKey as Group>::Scalar::ZERO; 3];
+        for (value, chunk) in values
+            .iter_mut()
+            .zip(scalars.chunks_exact(SECRET_KEY_BYTES))
+        {
+            let repr =
+                <[u8; SECRET_KEY_BYTES]>::try_from(chunk).expect("chunk is the correct size");
+            *value = Option::from(scalar_from_be_bytes::<C, SECRET_KEY_BYTES>(&repr))
+                .ok_or_else(|| BlsError::InvalidInputs("Invalid scalar".to_string()))?;
+        }
+        Ok(Self {
+            ciphertext,
+            message_proof: values[0],
+            blinder_proof: values[1],
+            challenge: values[2],
+        })
+    }
+
     /// Verify the proof and ciphertext are valid
     pub fn verify(&self, pk: PublicKey<C>) -> BlsResult<()> {
         <C as BlsElGamal>::verify_proof(
@@ -95,4 +138,17 @@ impl<C: BlsSignatureImpl> ElGamalProof<C> {
             self.challenge,
         )
     }
+
+    /// Verify the proof against `pk` then decrypt with a decryption key
+    /// for this ciphertext, for example one combined from decryption shares
+    ///
+    /// The caller is responsible for `dk` belonging to the secret key of `pk`.
+    pub fn verify_with_decryption_key(
+        &self,
+        pk: &PublicKey<C>,
+        dk: &ElGamalDecryptionKey<C>,
+    ) -> BlsResult<<C as Pairing>::PublicKey> {
+        self.verify(*pk)?;
+        Ok(dk.decrypt(&self.ciphertext))
+    }
 }
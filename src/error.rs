@@ -1,3 +1,4 @@
+use alloc::string::{String, ToString};
 use thiserror::Error;
 
 /// The error types generated by this library
@@ -27,10 +28,22 @@ pub enum BlsError {
     /// An error occurred during serialization
     #[error("serialization error: {0}")]
     DeserializationError(String),
+    /// A signature was requested for a slot that is not greater than the last signed slot
+    #[error("refusing to sign a slot that is not greater than the last signed slot")]
+    DoubleSign,
+    /// Fewer shares were supplied than the threshold requires
+    #[error("insufficient shares: expected at least {0}, got {1}")]
+    InsufficientShares(usize, usize),
+    /// More than one share has the same identifier
+    #[error("duplicate share identifier")]
+    DuplicateShareIdentifier,
+    /// The ciphertext could not be decrypted with the supplied key
+    #[error("decryption failed")]
+    DecryptionFailed,
 }
 
 /// The result type generated by this library
-pub type BlsResult<T> = anyhow::Result<T, BlsError>;
+pub type BlsResult<T> = Result<T, BlsError>;
 
 impl From<vsss_rs::Error> for BlsError {
     fn from(_: vsss_rs::Error) -> Self {
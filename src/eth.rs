@@ -0,0 +1,67 @@
+//! Helpers for the BLS signatures used by the Ethereum consensus layer.
+//!
+//! Ethereum uses the proof of possession scheme with public keys in G1
+//! and signatures in G2 (`min-pk`) which is [`Bls12381G2Impl`]. Points
+//! are exchanged as raw compressed bytes.
+
+use crate::*;
+
+/// The implementation matching the Ethereum consensus layer
+pub type Eth2G2 = Bls12381G2Impl;
+
+/// The domain separation tag Ethereum uses for signatures
+pub const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Read a 48 byte compressed public key, rejecting the identity
+pub fn public_key_from_bytes(bytes: &[u8]) -> BlsResult<PublicKey<Eth2G2>> {
+    PublicKey::try_from(bytes)
+}
+
+/// Read a 96 byte compressed signature.
+///
+/// The identity is accepted since [`eth_fast_aggregate_verify`] allows it
+/// for an empty set of public keys.
+pub fn signature_from_bytes(bytes: &[u8]) -> BlsResult<Signature<Eth2G2>> {
+    let mut repr = <Eth2G2 as Pairing>::Signature::default().to_bytes();
+    if repr.as_ref().len() != bytes.len() {
+        return Err(BlsError::InvalidInputs(format!(
+            "Invalid length, expected {}, got {}",
+            repr.as_ref().len(),
+            bytes.len()
+        )));
+    }
+    repr.as_mut().copy_from_slice(bytes);
+    Option::<<Eth2G2 as Pairing>::Signature>::from(<Eth2G2 as Pairing>::Signature::from_bytes(
+        &repr,
+    ))
+    .map(Signature::ProofOfPossession)
+    .ok_or_else(|| BlsError::InvalidInputs("Invalid byte sequence".to_string()))
+}
+
+/// The `eth_fast_aggregate_verify` function from the Altair specification.
+///
+/// Same as fast aggregate verify except no public keys with the
+/// identity signature is valid, as happens for an empty sync committee.
+pub fn eth_fast_aggregate_verify<B: AsRef<[u8]>>(
+    pks: &[PublicKey<Eth2G2>],
+    msg: B,
+    sig: &Signature<Eth2G2>,
+) -> BlsResult<()> {
+    let sig = match sig {
+        Signature::ProofOfPossession(sig) => *sig,
+        _ => return Err(BlsError::InvalidSignatureScheme),
+    };
+    if pks.is_empty() {
+        return if sig.is_identity().into() {
+            Ok(())
+        } else {
+            Err(BlsError::InvalidInputs("no public keys".to_string()))
+        };
+    }
+    if pks.iter().any(|pk| pk.0.is_identity().into()) {
+        return Err(BlsError::InvalidInputs(
+            "public key is the identity point".to_string(),
+        ));
+    }
+    <Eth2G2 as BlsSignaturePop>::multi_sig_verify(pks.iter().map(|pk| pk.0), sig, msg)
+}
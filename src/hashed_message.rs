@@ -0,0 +1,67 @@
+use crate::*;
+
+/// A message hashed to a curve point for one signature scheme.
+///
+/// Created by [`PublicKey::hash_message`] so the expensive hash to curve
+/// runs once when the same message is checked against many keys or
+/// signature shares. The scheme is kept with the point and verifying a
+/// signature from another scheme fails.
+///
+/// Message augmentation prefixes the public key to the message, so those
+/// hashes only verify against the key they were created with.
+pub struct HashedMessage<C: BlsSignatureImpl> {
+    scheme: SignatureSchemes,
+    point: <C as Pairing>::Signature,
+}
+
+impl<C: BlsSignatureImpl> Copy for HashedMessage<C> {}
+
+impl<C: BlsSignatureImpl> Clone for HashedMessage<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for HashedMessage<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "HashedMessage {{ scheme: {:?}, point: {:?} }}",
+            self.scheme, self.point
+        )
+    }
+}
+
+impl<C: BlsSignatureImpl> PartialEq for HashedMessage<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scheme == other.scheme && self.point == other.point
+    }
+}
+
+impl<C: BlsSignatureImpl> Eq for HashedMessage<C> {}
+
+impl<C: BlsSignatureImpl> HashedMessage<C> {
+    /// Hash `msg` for `scheme`, prefixing `pk` for message augmentation
+    pub(crate) fn new<B: AsRef<[u8]>>(scheme: SignatureSchemes, pk: &PublicKey<C>, msg: B) -> Self {
+        let msg = msg.as_ref();
+        let dst = scheme_dst::<C>(scheme);
+        let point = if scheme == SignatureSchemes::MessageAugmentation {
+            let mut overhead = <C as BlsSignatureMessageAugmentation>::pk_bytes(pk.0, msg.len());
+            overhead.extend_from_slice(msg);
+            <C as HashToPoint>::hash_to_point(&overhead, dst)
+        } else {
+            <C as HashToPoint>::hash_to_point(msg, dst)
+        };
+        Self { scheme, point }
+    }
+
+    /// The signature scheme this message was hashed for
+    pub fn scheme(&self) -> SignatureSchemes {
+        self.scheme
+    }
+
+    /// Extract the inner raw representation
+    pub fn as_raw_value(&self) -> &<C as Pairing>::Signature {
+        &self.point
+    }
+}
@@ -1,10 +1,26 @@
 use crate::impls::inner_types::*;
-use crate::{BlsSignatureImpl, Pairing};
+use crate::{
+    BlsError, BlsResult, BlsSignatureBasic, BlsSignatureCore, BlsSignatureImpl,
+    BlsSignatureMessageAugmentation, BlsSignaturePop, Pairing, Signature, SignatureSchemes,
+};
+use alloc::{collections::BTreeMap, format, string::ToString, vec, vec::Vec};
 use rand_chacha::ChaCha20Rng;
 use rand_core::SeedableRng;
-use subtle::{Choice, CtOption};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use vsss_rs::{IdentifierPrimeField, Share};
 
 pub const KEYGEN_SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-";
+pub const SUBKEY_SALT: &[u8] = b"BLS-SIG-SUBKEY-SALT-";
+pub const KEY_TWEAK_DST: &[u8] = b"BLS-SIG-KEY-TWEAK-";
+
+/// The domain separation tag messages are hashed with under `scheme`
+pub fn scheme_dst<C: BlsSignatureImpl>(scheme: SignatureSchemes) -> &'static [u8] {
+    match scheme {
+        SignatureSchemes::Basic => <C as BlsSignatureBasic>::DST,
+        SignatureSchemes::MessageAugmentation => <C as BlsSignatureMessageAugmentation>::DST,
+        SignatureSchemes::ProofOfPossession => <C as BlsSignaturePop>::SIG_DST,
+    }
+}
 
 pub fn scalar_from_hkdf_bytes(salt: Option<&[u8]>, ikm: &[u8]) -> Scalar {
     const INFO: [u8; 2] = [0u8, 48u8];
@@ -25,6 +41,70 @@ pub fn scalar_from_hkdf_bytes(salt: Option<&[u8]>, ikm: &[u8]) -> Scalar {
     s
 }
 
+/// HKDF extract `ikm` with `salt` then expand 48 bytes of output
+/// keying material bound to `info`
+pub fn hkdf_expand(salt: &[u8], ikm: &[u8], info: &[u8]) -> [u8; 48] {
+    let mut extractor = hkdf::HkdfExtract::<sha2::Sha256>::new(Some(salt));
+    extractor.input_ikm(ikm);
+    extractor.input_ikm(&[0u8]);
+    let (_, h) = extractor.finalize();
+
+    let mut output = [0u8; 48];
+    // Unwrap allowed since 48 is a valid length
+    h.expand_multi_info(&[info, &[0u8, 48u8][..]], &mut output)
+        .unwrap();
+    output
+}
+
+/// The HKDF_mod_r function from the IETF `KeyGen` as used by EIP-2333.
+///
+/// Unlike [`hkdf_expand`] the salt is hashed before the first use.
+pub fn hkdf_mod_r<C: BlsSignatureImpl>(ikm: &[u8]) -> <<C as Pairing>::PublicKey as Group>::Scalar {
+    use sha2::{Digest, Sha256};
+
+    let mut salt = KEYGEN_SALT.to_vec();
+    loop {
+        salt = Sha256::digest(&salt).to_vec();
+        let okm = hkdf_expand(&salt, ikm, &[]);
+        let sk = <C as BlsSignatureCore>::scalar_from_okm(&okm);
+        if !bool::from(sk.is_zero()) {
+            return sk;
+        }
+    }
+}
+
+/// The compressed Lamport public key from EIP-2333 `parent_SK_to_lamport_PK`
+/// for the big-endian parent secret key and child index
+pub fn eip2333_lamport_pk(parent_sk: &[u8; 32], index: u32) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let salt = index.to_be_bytes();
+    let not_ikm = parent_sk.map(|b| !b);
+    let mut lamport_pk = Sha256::new();
+    let mut okm = vec![0u8; 255 * 32];
+    for ikm in [&parent_sk[..], &not_ikm[..]] {
+        let h = hkdf::Hkdf::<Sha256>::new(Some(&salt), ikm);
+        // Unwrap allowed since 255 * 32 is the maximum valid length
+        h.expand(&[], &mut okm).unwrap();
+        for chunk in okm.chunks(32) {
+            lamport_pk.update(Sha256::digest(chunk));
+        }
+    }
+    okm.iter_mut().for_each(|b| *b = 0);
+    lamport_pk.finalize().into()
+}
+
+/// Overwrite a secret scalar with zero.
+///
+/// The scalar types don't all implement `Zeroize` so this is best effort:
+/// the write is kept from being optimized away but earlier copies made
+/// by moves are not cleared.
+pub fn zeroize_scalar<F: Field>(f: &mut F) {
+    *f = F::ZERO;
+    core::hint::black_box(f);
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
 pub fn byte_xor(arr1: &[u8], arr2: &[u8]) -> Vec<u8> {
     debug_assert_eq!(arr1.len(), arr2.len());
     let mut o = Vec::with_capacity(arr1.len());
@@ -34,10 +114,59 @@ pub fn byte_xor(arr1: &[u8], arr2: &[u8]) -> Vec<u8> {
     o
 }
 
+/// The signing domain for slot signatures, see [`domain_dst`]
+pub const SLOT_DOMAIN: &[u8] = b"slot";
+
+/// Prefix the message with the fixed width big-endian slot so
+/// that no two (slot, message) pairs produce the same bytes
+pub fn slot_message(slot: u64, msg: &[u8]) -> Vec<u8> {
+    let mut o = Vec::with_capacity(8 + msg.len());
+    o.extend_from_slice(&slot.to_be_bytes());
+    o.extend_from_slice(msg);
+    o
+}
+
+/// Derive the domain separation tag for a signing domain by appending
+/// the SHA-256 hash of the domain label to the scheme's tag, so the
+/// ciphersuite prefix stays identifiable
+pub fn domain_dst(base_dst: &[u8], domain: &[u8]) -> Vec<u8> {
+    use sha2::{Digest, Sha256};
+
+    let mut dst = Vec::with_capacity(base_dst.len() + 32);
+    dst.extend_from_slice(base_dst);
+    dst.extend_from_slice(&Sha256::digest(domain));
+    dst
+}
+
+/// The current system time in milliseconds since the unix epoch
+#[cfg(feature = "std")]
+pub fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system time is before the unix epoch")
+        .as_millis() as u64
+}
+
 pub fn get_crypto_rng() -> ChaCha20Rng {
     ChaCha20Rng::from_entropy()
 }
 
+/// Sum the raw signature points regardless of scheme
+#[cfg(not(feature = "rayon"))]
+pub fn sum_signatures<C: BlsSignatureImpl>(sigs: &[Signature<C>]) -> <C as Pairing>::Signature {
+    sigs.iter().map(|s| *s.as_raw_value()).sum()
+}
+
+/// Sum the raw signature points regardless of scheme in parallel
+#[cfg(feature = "rayon")]
+pub fn sum_signatures<C: BlsSignatureImpl>(sigs: &[Signature<C>]) -> <C as Pairing>::Signature {
+    use rayon::prelude::*;
+
+    sigs.par_iter()
+        .map(|s| *s.as_raw_value())
+        .reduce(<C as Pairing>::Signature::identity, |a, b| a + b)
+}
+
 pub fn pairing_g1_g2(points: &[(G1Projective, G2Projective)]) -> Gt {
     let t = points
         .iter()
@@ -62,6 +191,54 @@ pub fn pairing_g2_g1(points: &[(G2Projective, G1Projective)]) -> Gt {
     multi_miller_loop(ref_t.as_slice()).final_exponentiation()
 }
 
+pub fn pairing_g1_g2_affine(points: &[(G1Projective, G2Affine)]) -> Gt {
+    let t = points
+        .iter()
+        .map(|(p1, p2)| (p1.to_affine(), G2Prepared::from(*p2)))
+        .collect::<Vec<(G1Affine, G2Prepared)>>();
+    let ref_t = t
+        .iter()
+        .map(|(p1, p2)| (p1, p2))
+        .collect::<Vec<(&G1Affine, &G2Prepared)>>();
+    multi_miller_loop(ref_t.as_slice()).final_exponentiation()
+}
+
+pub fn pairing_g2_g1_affine(points: &[(G2Projective, G1Affine)]) -> Gt {
+    let t = points
+        .iter()
+        .map(|(p1, p2)| (*p2, G2Prepared::from(p1.to_affine())))
+        .collect::<Vec<(G1Affine, G2Prepared)>>();
+    let ref_t = t
+        .iter()
+        .map(|(p1, p2)| (p1, p2))
+        .collect::<Vec<(&G1Affine, &G2Prepared)>>();
+    multi_miller_loop(ref_t.as_slice()).final_exponentiation()
+}
+
+pub fn pairing_g1_g2_prepared(points: &[(G1Projective, &G2Prepared)]) -> Gt {
+    let t = points
+        .iter()
+        .map(|(p1, p2)| (p1.to_affine(), *p2))
+        .collect::<Vec<(G1Affine, &G2Prepared)>>();
+    let ref_t = t
+        .iter()
+        .map(|(p1, p2)| (p1, *p2))
+        .collect::<Vec<(&G1Affine, &G2Prepared)>>();
+    multi_miller_loop(ref_t.as_slice()).final_exponentiation()
+}
+
+pub fn pairing_g2_g1_prepared(points: &[(G2Projective, &G1Affine)]) -> Gt {
+    let t = points
+        .iter()
+        .map(|(p1, p2)| (**p2, G2Prepared::from(p1.to_affine())))
+        .collect::<Vec<(G1Affine, G2Prepared)>>();
+    let ref_t = t
+        .iter()
+        .map(|(p1, p2)| (p1, p2))
+        .collect::<Vec<(&G1Affine, &G2Prepared)>>();
+    multi_miller_loop(ref_t.as_slice()).final_exponentiation()
+}
+
 pub fn scalar_to_be_bytes<C: BlsSignatureImpl, const N: usize>(
     s: <<C as Pairing>::PublicKey as Group>::Scalar,
 ) -> [u8; N] {
@@ -111,6 +288,128 @@ pub fn scalar_from_le_bytes<C: BlsSignatureImpl, const N: usize>(
     <<C as Pairing>::PublicKey as Group>::Scalar::from_repr(repr)
 }
 
+/// Compare two scalars by their numeric value
+pub fn scalar_cmp<F: PrimeField>(a: &F, b: &F) -> core::cmp::Ordering {
+    let a = a.to_repr();
+    let b = b.to_repr();
+    // the repr is little-endian so compare from the most significant byte
+    a.as_ref().iter().rev().cmp(b.as_ref().iter().rev())
+}
+
+/// Decode a little-endian share identifier stored in `bytes.len()` bytes
+pub fn identifier_from_le_bytes<F: PrimeField>(bytes: &[u8]) -> BlsResult<F> {
+    let mut repr = F::Repr::default();
+    if bytes.is_empty() || bytes.len() > repr.as_ref().len() {
+        return Err(BlsError::InvalidInputs(format!(
+            "identifier width must be between 1 and {} bytes, found {}",
+            repr.as_ref().len(),
+            bytes.len()
+        )));
+    }
+    repr.as_mut()[..bytes.len()].copy_from_slice(bytes);
+    Option::from(F::from_repr(repr))
+        .ok_or_else(|| BlsError::InvalidInputs("Invalid identifier".to_string()))
+}
+
+/// Evaluate the polynomial committed to by `commitments` in the exponent at `x`
+pub fn evaluate_commitments<G: Group>(commitments: &[G], x: G::Scalar) -> G {
+    commitments
+        .iter()
+        .rev()
+        .fold(G::identity(), |acc, c| acc * x + c)
+}
+
+/// The largest bound [`discrete_log`] accepts, any more takes
+/// too much time and memory to be useful
+pub const MAX_DISCRETE_LOG: u64 = 1 << 40;
+
+/// Find `x <= max` with `base * x == target` using baby-step giant-step.
+///
+/// Takes `O(sqrt(max))` group operations and memory and fails if
+/// `max` is larger than [`MAX_DISCRETE_LOG`].
+pub fn discrete_log<G: Group + GroupEncoding>(
+    base: G,
+    target: G,
+    max: u64,
+) -> BlsResult<Option<u64>> {
+    if max > MAX_DISCRETE_LOG {
+        return Err(BlsError::InvalidInputs(format!(
+            "discrete log bound {} is larger than {}",
+            max, MAX_DISCRETE_LOG
+        )));
+    }
+    let overflow = || BlsError::InvalidInputs("discrete log bound overflow".to_string());
+    // m = ceil(sqrt(max + 1)) so m * m covers every candidate
+    let count = max.checked_add(1).ok_or_else(overflow)?;
+    let mut m = count.isqrt();
+    if m.checked_mul(m).ok_or_else(overflow)? < count {
+        m += 1;
+    }
+    let mut baby_steps = BTreeMap::new();
+    let mut point = G::identity();
+    for j in 0..m {
+        baby_steps
+            .entry(point.to_bytes().as_ref().to_vec())
+            .or_insert(j);
+        point += base;
+    }
+    let giant_step = -(base * G::Scalar::from(m));
+    let mut gamma = target;
+    for i in 0..m {
+        if let Some(j) = baby_steps.get(gamma.to_bytes().as_ref()) {
+            let x = i
+                .checked_mul(m)
+                .and_then(|x| x.checked_add(*j))
+                .ok_or_else(overflow)?;
+            return Ok((x <= max).then_some(x));
+        }
+        gamma += giant_step;
+    }
+    Ok(None)
+}
+
+/// A table of multiples of a fixed base point for fast repeated
+/// scalar multiplication with 4-bit windows.
+///
+/// Lookups touch every entry of a window so the table access
+/// pattern doesn't depend on the scalar.
+pub struct FixedBaseTable<G: Group + ConditionallySelectable> {
+    windows: Vec<[G; 16]>,
+}
+
+impl<G: Group + ConditionallySelectable> FixedBaseTable<G> {
+    pub fn new(base: G) -> Self {
+        let count = (G::Scalar::NUM_BITS as usize).div_ceil(4);
+        let mut windows = Vec::with_capacity(count);
+        let mut b = base;
+        for _ in 0..count {
+            let mut window = [G::identity(); 16];
+            let mut acc = G::identity();
+            for entry in window.iter_mut() {
+                *entry = acc;
+                acc += b;
+            }
+            b = acc;
+            windows.push(window);
+        }
+        Self { windows }
+    }
+
+    pub fn mul(&self, scalar: &G::Scalar) -> G {
+        let repr = scalar.to_repr();
+        let mut result = G::identity();
+        for (i, window) in self.windows.iter().enumerate() {
+            let nibble = (repr.as_ref()[i / 2] >> ((i & 1) * 4)) & 0x0F;
+            let mut point = G::identity();
+            for (j, p) in window.iter().enumerate().skip(1) {
+                point.conditional_assign(p, (j as u8).ct_eq(&nibble));
+            }
+            result += point;
+        }
+        result
+    }
+}
+
 pub mod fixed_arr {
     use core::fmt::{self, Formatter};
     use serde::{
@@ -178,3 +477,80 @@ impl IsZero for [u8] {
         Choice::from((((t | -t) >> 7) + 1) as u8)
     }
 }
+
+/// Make sure the shares can be interpolated before combining them.
+///
+/// A threshold is at least two so fewer shares can never be enough,
+/// and a zero or repeated identifier would make a Lagrange
+/// denominator zero.
+pub fn check_share_identifiers<S, F>(shares: &[S]) -> BlsResult<()>
+where
+    S: Share<Identifier = IdentifierPrimeField<F>>,
+    F: PrimeField,
+{
+    check_identifiers(shares.iter().map(|s| s.identifier()))
+}
+
+/// Make sure the identifiers can be interpolated, see [`check_share_identifiers`].
+pub fn check_identifiers<'a, F, I>(identifiers: I) -> BlsResult<()>
+where
+    F: PrimeField,
+    I: ExactSizeIterator<Item = &'a IdentifierPrimeField<F>> + Clone,
+{
+    if identifiers.len() < 2 {
+        return Err(BlsError::InsufficientShares(2, identifiers.len()));
+    }
+    for (i, id) in identifiers.clone().enumerate() {
+        if id.0.is_zero().into() {
+            return Err(BlsError::InvalidInputs(format!(
+                "share at {} has a zero identifier",
+                i + 1
+            )));
+        }
+        if identifiers.clone().take(i).any(|prev| prev == id) {
+            return Err(BlsError::DuplicateShareIdentifier);
+        }
+    }
+    Ok(())
+}
+
+/// Compute the Lagrange coefficients for interpolating at zero.
+///
+/// The identifiers must already be checked with [`check_share_identifiers`].
+pub fn lagrange_coefficients<F: PrimeField>(ids: &[F]) -> BlsResult<Vec<F>> {
+    let mut coefficients = Vec::with_capacity(ids.len());
+    for (i, xi) in ids.iter().enumerate() {
+        let mut num = F::ONE;
+        let mut den = F::ONE;
+        for (j, xj) in ids.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            num *= xj;
+            den *= *xj - xi;
+        }
+        let den = Option::<F>::from(den.invert()).ok_or(BlsError::DuplicateShareIdentifier)?;
+        coefficients.push(num * den);
+    }
+    Ok(coefficients)
+}
+
+#[cfg(feature = "blst")]
+pub fn g1_sum_of_products(points: &[G1Projective], scalars: &[Scalar]) -> G1Projective {
+    G1Projective::multi_exp(points, scalars)
+}
+
+#[cfg(not(feature = "blst"))]
+pub fn g1_sum_of_products(points: &[G1Projective], scalars: &[Scalar]) -> G1Projective {
+    G1Projective::sum_of_products(points, scalars)
+}
+
+#[cfg(feature = "blst")]
+pub fn g2_sum_of_products(points: &[G2Projective], scalars: &[Scalar]) -> G2Projective {
+    G2Projective::multi_exp(points, scalars)
+}
+
+#[cfg(not(feature = "blst"))]
+pub fn g2_sum_of_products(points: &[G2Projective], scalars: &[Scalar]) -> G2Projective {
+    G2Projective::sum_of_products(points, scalars)
+}
@@ -3,9 +3,11 @@
 
 mod g1;
 mod g2;
+mod xof;
 
 pub use g1::*;
 pub use g2::*;
+pub use xof::*;
 
 use crate::*;
 use core::{
@@ -20,6 +22,8 @@ use rand_core::{CryptoRng, RngCore};
 pub trait BlsSignatureImpl:
     BlsSignatureBasic + BlsSignatureMessageAugmentation + BlsSignaturePop
 {
+    /// The curve tag naming the group signatures are in
+    const CURVE: Bls12381;
 }
 
 /// A BLS signature implementation
@@ -86,6 +90,14 @@ pub type Bls12381G1 = BlsSignature<Bls12381G1Impl>;
 /// A BLS signature implementation using G2 for signatures and G1 for public keys
 pub type Bls12381G2 = BlsSignature<Bls12381G2Impl>;
 
+/// A BLS signature implementation using G1 for signatures and G2 for public keys
+/// that hashes to the curve with SHAKE-128
+pub type Bls12381G1Xof = BlsSignature<Bls12381G1XofImpl>;
+
+/// A BLS signature implementation using G2 for signatures and G1 for public keys
+/// that hashes to the curve with SHAKE-128
+pub type Bls12381G2Xof = BlsSignature<Bls12381G2XofImpl>;
+
 /// A convenience wrapper for the two BLS signature implementations
 /// that doesn't require specifying the generics and can be used in
 /// trait object like situations.
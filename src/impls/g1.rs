@@ -30,14 +30,57 @@ impl HashToScalar for Bls12381G1Impl {
 impl Pairing for Bls12381G1Impl {
     type SecretKeyShare = DefaultShare<IdentifierPrimeField<Scalar>, IdentifierPrimeField<Scalar>>;
     type PublicKey = G2Projective;
+    type PublicKeyAffine = G2Affine;
+    type PublicKeyPrepared = G2Prepared;
     type PublicKeyShare = InnerPointShareG2;
     type Signature = G1Projective;
     type SignatureShare = InnerPointShareG1;
     type PairingResult = Gt;
 
+    const PUBLIC_KEY_BYTES: usize = 96;
+    const SIGNATURE_BYTES: usize = 48;
+
     fn pairing(points: &[(Self::Signature, Self::PublicKey)]) -> Self::PairingResult {
         pairing_g1_g2(points)
     }
+
+    fn pairing_with_affine_public_keys(
+        points: &[(Self::Signature, Self::PublicKeyAffine)],
+    ) -> Self::PairingResult {
+        pairing_g1_g2_affine(points)
+    }
+
+    fn prepare_public_key(public_key: &Self::PublicKey) -> Self::PublicKeyPrepared {
+        G2Prepared::from(public_key.to_affine())
+    }
+
+    fn pairing_with_prepared_public_keys(
+        points: &[(Self::Signature, &Self::PublicKeyPrepared)],
+    ) -> Self::PairingResult {
+        pairing_g1_g2_prepared(points)
+    }
+
+    fn signature_sum_of_products(
+        points: &[Self::Signature],
+        scalars: &[Scalar],
+    ) -> Self::Signature {
+        g1_sum_of_products(points, scalars)
+    }
+
+    fn public_key_sum_of_products(
+        points: &[Self::PublicKey],
+        scalars: &[Scalar],
+    ) -> Self::PublicKey {
+        g2_sum_of_products(points, scalars)
+    }
+
+    fn signature_is_torsion_free(signature: &Self::Signature) -> Choice {
+        signature.to_affine().is_torsion_free()
+    }
+
+    fn public_key_is_torsion_free(public_key: &Self::PublicKey) -> Choice {
+        public_key.to_affine().is_torsion_free()
+    }
 }
 
 impl BlsSerde for Bls12381G1Impl {
@@ -104,7 +147,11 @@ impl BlsSerde for Bls12381G1Impl {
     }
 }
 
-impl BlsSignatureCore for Bls12381G1Impl {}
+impl BlsSignatureCore for Bls12381G1Impl {
+    fn scalar_from_okm(okm: &[u8; 48]) -> <Self::PublicKey as Group>::Scalar {
+        Scalar::from_okm(okm)
+    }
+}
 
 impl BlsSignatureBasic for Bls12381G1Impl {
     const DST: &'static [u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
@@ -138,7 +185,9 @@ impl BlsMultiKey for Bls12381G1Impl {}
 
 impl BlsMultiSignature for Bls12381G1Impl {}
 
-impl BlsSignatureImpl for Bls12381G1Impl {}
+impl BlsSignatureImpl for Bls12381G1Impl {
+    const CURVE: Bls12381 = Bls12381::G1;
+}
 
 /// The BLS12381 G1 hash to public key group
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
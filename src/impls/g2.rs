@@ -28,14 +28,57 @@ impl HashToScalar for Bls12381G2Impl {
 impl Pairing for Bls12381G2Impl {
     type SecretKeyShare = DefaultShare<IdentifierPrimeField<Scalar>, IdentifierPrimeField<Scalar>>;
     type PublicKey = G1Projective;
+    type PublicKeyAffine = G1Affine;
+    type PublicKeyPrepared = G1Affine;
     type PublicKeyShare = InnerPointShareG1;
     type Signature = G2Projective;
     type SignatureShare = InnerPointShareG2;
     type PairingResult = Gt;
 
+    const PUBLIC_KEY_BYTES: usize = 48;
+    const SIGNATURE_BYTES: usize = 96;
+
     fn pairing(points: &[(Self::Signature, Self::PublicKey)]) -> Self::PairingResult {
         pairing_g2_g1(points)
     }
+
+    fn pairing_with_affine_public_keys(
+        points: &[(Self::Signature, Self::PublicKeyAffine)],
+    ) -> Self::PairingResult {
+        pairing_g2_g1_affine(points)
+    }
+
+    fn prepare_public_key(public_key: &Self::PublicKey) -> Self::PublicKeyPrepared {
+        public_key.to_affine()
+    }
+
+    fn pairing_with_prepared_public_keys(
+        points: &[(Self::Signature, &Self::PublicKeyPrepared)],
+    ) -> Self::PairingResult {
+        pairing_g2_g1_prepared(points)
+    }
+
+    fn signature_sum_of_products(
+        points: &[Self::Signature],
+        scalars: &[Scalar],
+    ) -> Self::Signature {
+        g2_sum_of_products(points, scalars)
+    }
+
+    fn public_key_sum_of_products(
+        points: &[Self::PublicKey],
+        scalars: &[Scalar],
+    ) -> Self::PublicKey {
+        g1_sum_of_products(points, scalars)
+    }
+
+    fn signature_is_torsion_free(signature: &Self::Signature) -> Choice {
+        signature.to_affine().is_torsion_free()
+    }
+
+    fn public_key_is_torsion_free(public_key: &Self::PublicKey) -> Choice {
+        public_key.to_affine().is_torsion_free()
+    }
 }
 
 impl BlsSerde for Bls12381G2Impl {
@@ -102,7 +145,11 @@ impl BlsSerde for Bls12381G2Impl {
     }
 }
 
-impl BlsSignatureCore for Bls12381G2Impl {}
+impl BlsSignatureCore for Bls12381G2Impl {
+    fn scalar_from_okm(okm: &[u8; 48]) -> <Self::PublicKey as Group>::Scalar {
+        Scalar::from_okm(okm)
+    }
+}
 
 impl BlsSignatureBasic for Bls12381G2Impl {
     const DST: &'static [u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
@@ -136,7 +183,9 @@ impl BlsMultiKey for Bls12381G2Impl {}
 
 impl BlsMultiSignature for Bls12381G2Impl {}
 
-impl BlsSignatureImpl for Bls12381G2Impl {}
+impl BlsSignatureImpl for Bls12381G2Impl {
+    const CURVE: Bls12381 = Bls12381::G2;
+}
 
 /// The BLS12381 G1 hash to public key group
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
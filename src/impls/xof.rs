@@ -0,0 +1,235 @@
+use crate::impls::inner_types::*;
+use crate::*;
+
+/// Represents BLS signatures on the BLS12-381 curve where
+/// Signatures are in G1 and Public Keys are in G2 like [`Bls12381G1Impl`]
+/// but messages are hashed to the curve with `expand_message_xof`
+/// using SHAKE-128 instead of `expand_message_xmd` using SHA-256
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub struct Bls12381G1XofImpl;
+
+/// Represents BLS signatures on the BLS12-381 curve where
+/// Signatures are in G2 and Public Keys are in G1 like [`Bls12381G2Impl`]
+/// but messages are hashed to the curve with `expand_message_xof`
+/// using SHAKE-128 instead of `expand_message_xmd` using SHA-256
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub struct Bls12381G2XofImpl;
+
+/// Everything except hashing to the signature group is the same as the base
+/// implementation so forward to it
+macro_rules! impl_xof {
+    (
+        $name:ident,
+        $base:ident,
+        $point:ident,
+        $sig_dst:literal,
+        $aug_dst:literal,
+        $pop_sig_dst:literal,
+        $pop_dst:literal
+    ) => {
+        impl HashToPoint for $name {
+            type Output = $point;
+
+            fn hash_to_point<B: AsRef<[u8]>, C: AsRef<[u8]>>(m: B, dst: C) -> Self::Output {
+                $point::hash::<ExpandMsgXof<sha3::Shake128>>(m.as_ref(), dst.as_ref())
+            }
+        }
+
+        impl HashToScalar for $name {
+            type Output = Scalar;
+
+            fn hash_to_scalar<B: AsRef<[u8]>, C: AsRef<[u8]>>(m: B, dst: C) -> Self::Output {
+                <$base as HashToScalar>::hash_to_scalar(m, dst)
+            }
+        }
+
+        impl Pairing for $name {
+            type SecretKeyShare = <$base as Pairing>::SecretKeyShare;
+            type PublicKey = <$base as Pairing>::PublicKey;
+            type PublicKeyAffine = <$base as Pairing>::PublicKeyAffine;
+            type PublicKeyPrepared = <$base as Pairing>::PublicKeyPrepared;
+            type PublicKeyShare = <$base as Pairing>::PublicKeyShare;
+            type Signature = <$base as Pairing>::Signature;
+            type SignatureShare = <$base as Pairing>::SignatureShare;
+            type PairingResult = <$base as Pairing>::PairingResult;
+
+            const PUBLIC_KEY_BYTES: usize = <$base as Pairing>::PUBLIC_KEY_BYTES;
+            const SIGNATURE_BYTES: usize = <$base as Pairing>::SIGNATURE_BYTES;
+
+            fn pairing(points: &[(Self::Signature, Self::PublicKey)]) -> Self::PairingResult {
+                <$base as Pairing>::pairing(points)
+            }
+
+            fn pairing_with_affine_public_keys(
+                points: &[(Self::Signature, Self::PublicKeyAffine)],
+            ) -> Self::PairingResult {
+                <$base as Pairing>::pairing_with_affine_public_keys(points)
+            }
+
+            fn prepare_public_key(public_key: &Self::PublicKey) -> Self::PublicKeyPrepared {
+                <$base as Pairing>::prepare_public_key(public_key)
+            }
+
+            fn pairing_with_prepared_public_keys(
+                points: &[(Self::Signature, &Self::PublicKeyPrepared)],
+            ) -> Self::PairingResult {
+                <$base as Pairing>::pairing_with_prepared_public_keys(points)
+            }
+
+            fn signature_sum_of_products(
+                points: &[Self::Signature],
+                scalars: &[Scalar],
+            ) -> Self::Signature {
+                <$base as Pairing>::signature_sum_of_products(points, scalars)
+            }
+
+            fn public_key_sum_of_products(
+                points: &[Self::PublicKey],
+                scalars: &[Scalar],
+            ) -> Self::PublicKey {
+                <$base as Pairing>::public_key_sum_of_products(points, scalars)
+            }
+
+            fn signature_is_torsion_free(signature: &Self::Signature) -> Choice {
+                <$base as Pairing>::signature_is_torsion_free(signature)
+            }
+
+            fn public_key_is_torsion_free(public_key: &Self::PublicKey) -> Choice {
+                <$base as Pairing>::public_key_is_torsion_free(public_key)
+            }
+        }
+
+        impl BlsSerde for $name {
+            fn serialize_scalar<S: Serializer>(
+                scalar: &Scalar,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                <$base as BlsSerde>::serialize_scalar(scalar, serializer)
+            }
+
+            fn serialize_scalar_share<S: Serializer>(
+                share: &Self::SecretKeyShare,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                <$base as BlsSerde>::serialize_scalar_share(share, serializer)
+            }
+
+            fn serialize_signature<S: Serializer>(
+                signature: &Self::Signature,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                <$base as BlsSerde>::serialize_signature(signature, serializer)
+            }
+
+            fn serialize_public_key<S: Serializer>(
+                public_key: &Self::PublicKey,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                <$base as BlsSerde>::serialize_public_key(public_key, serializer)
+            }
+
+            fn serialize_public_key_share<S: Serializer>(
+                public_key_share: &Self::PublicKeyShare,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                <$base as BlsSerde>::serialize_public_key_share(public_key_share, serializer)
+            }
+
+            fn deserialize_scalar<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<<Self::PublicKey as Group>::Scalar, D::Error> {
+                <$base as BlsSerde>::deserialize_scalar(deserializer)
+            }
+
+            fn deserialize_scalar_share<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self::SecretKeyShare, D::Error> {
+                <$base as BlsSerde>::deserialize_scalar_share(deserializer)
+            }
+
+            fn deserialize_signature<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self::Signature, D::Error> {
+                <$base as BlsSerde>::deserialize_signature(deserializer)
+            }
+
+            fn deserialize_public_key<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self::PublicKey, D::Error> {
+                <$base as BlsSerde>::deserialize_public_key(deserializer)
+            }
+
+            fn deserialize_public_key_share<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self::PublicKeyShare, D::Error> {
+                <$base as BlsSerde>::deserialize_public_key_share(deserializer)
+            }
+        }
+
+        impl BlsSignatureCore for $name {
+            fn scalar_from_okm(okm: &[u8; 48]) -> <Self::PublicKey as Group>::Scalar {
+                <$base as BlsSignatureCore>::scalar_from_okm(okm)
+            }
+        }
+
+        impl BlsSignatureBasic for $name {
+            const DST: &'static [u8] = $sig_dst;
+        }
+
+        impl BlsSignatureMessageAugmentation for $name {
+            const DST: &'static [u8] = $aug_dst;
+        }
+
+        impl BlsSignaturePop for $name {
+            const SIG_DST: &'static [u8] = $pop_sig_dst;
+            const POP_DST: &'static [u8] = $pop_dst;
+        }
+
+        impl BlsSignatureProof for $name {}
+
+        impl BlsSignCrypt for $name {}
+
+        impl BlsTimeCrypt for $name {}
+
+        impl BlsElGamal for $name {
+            const ENC_DST: &'static [u8] = <$base as BlsElGamal>::ENC_DST;
+            type PublicKeyHasher = <$base as BlsElGamal>::PublicKeyHasher;
+
+            fn scalar_from_bytes_wide(bytes: &[u8; 64]) -> <Self::PublicKey as Group>::Scalar {
+                <$base as BlsElGamal>::scalar_from_bytes_wide(bytes)
+            }
+        }
+
+        impl BlsMultiKey for $name {}
+
+        impl BlsMultiSignature for $name {}
+
+        impl BlsSignatureImpl for $name {
+            const CURVE: Bls12381 = <$base as BlsSignatureImpl>::CURVE;
+        }
+    };
+}
+
+impl_xof!(
+    Bls12381G1XofImpl,
+    Bls12381G1Impl,
+    G1Projective,
+    b"BLS_SIG_BLS12381G1_XOF:SHAKE-128_SSWU_RO_NUL_",
+    b"BLS_SIG_BLS12381G1_XOF:SHAKE-128_SSWU_RO_AUG_",
+    b"BLS_SIG_BLS12381G1_XOF:SHAKE-128_SSWU_RO_POP_",
+    b"BLS_POP_BLS12381G1_XOF:SHAKE-128_SSWU_RO_POP_"
+);
+
+impl_xof!(
+    Bls12381G2XofImpl,
+    Bls12381G2Impl,
+    G2Projective,
+    b"BLS_SIG_BLS12381G2_XOF:SHAKE-128_SSWU_RO_NUL_",
+    b"BLS_SIG_BLS12381G2_XOF:SHAKE-128_SSWU_RO_AUG_",
+    b"BLS_SIG_BLS12381G2_XOF:SHAKE-128_SSWU_RO_POP_",
+    b"BLS_POP_BLS12381G2_XOF:SHAKE-128_SSWU_RO_POP_"
+);
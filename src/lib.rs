@@ -4,6 +4,19 @@
 //!
 //! Since BLS signatures can use either G1 or G2 fields, there are two types of
 //! public keys and signatures.
+//!
+//! All public types are `Send + Sync + 'static` so they can be
+//! moved across threads, e.g. for verifying on a thread pool.
+//!
+//! The `std` feature is on by default. Without it the crate is `no_std`
+//! and only needs `alloc`, and the following are unavailable since they
+//! need the system clock, `HashMap` or `std::io`:
+//!
+//! - `ProofOfKnowledgeTimestamp::generate` and `ProofOfKnowledgeTimestamp::verify`,
+//!   use `generate_with_time` and `verify_at` instead
+//! - `SecretKey::combine_map`, `PublicKey::from_shares_map` and `Signature::from_shares_map`
+//! - the `Read` impl for [`SignCryptReader`]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(unsafe_code)]
 #![warn(
     missing_docs,
@@ -16,20 +29,45 @@
 #[cfg(all(not(feature = "rust"), not(feature = "blst")))]
 compile_error!("At least `rust` or `blst` must be selected");
 
+#[cfg(not(feature = "alloc"))]
+compile_error!("`alloc` must be selected");
+
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
 #[macro_use]
 mod macros;
 mod helpers;
 
+pub use helpers::MAX_DISCRETE_LOG;
 use helpers::*;
 
+mod affine_public_key;
 mod aggregate_signature;
+mod anon_cred_proof;
+mod ciphertext_enum;
+mod dealer_transcript;
 mod elgamal_ciphertext;
+mod elgamal_decryption_proof;
 mod elgamal_decryption_share;
+mod elgamal_point_proof;
 mod elgamal_proof;
 mod error;
+pub mod eth;
+mod hashed_message;
 mod impls;
+mod message_hasher;
 mod multi_public_key;
 mod multi_signature;
+mod prepared_public_key;
 mod proof_commitment;
 mod proof_of_knowledge;
 mod proof_of_possession;
@@ -37,23 +75,42 @@ mod public_key;
 mod public_key_share;
 mod secret_key;
 mod secret_key_share;
+pub mod serde_0x;
+pub mod serde_byte_string;
+mod share_correctness_proof;
+mod sharing_params;
 mod sig_types;
 mod sign_crypt_ciphertext;
+mod sign_crypt_stream;
 mod sign_decryption_share;
 mod signature;
+mod signature_aggregator;
 mod signature_share;
+mod signed_message;
+mod slot_signer;
+mod time_crypt_any_ciphertext;
 mod time_crypt_ciphertext;
 mod traits;
+mod verifiable;
 
 pub use error::*;
 pub use impls::*;
 
+pub use affine_public_key::*;
 pub use aggregate_signature::*;
+pub use anon_cred_proof::*;
+pub use ciphertext_enum::*;
+pub use dealer_transcript::*;
 pub use elgamal_ciphertext::*;
+pub use elgamal_decryption_proof::*;
 pub use elgamal_decryption_share::*;
+pub use elgamal_point_proof::*;
 pub use elgamal_proof::*;
+pub use hashed_message::*;
+pub use message_hasher::*;
 pub use multi_public_key::*;
 pub use multi_signature::*;
+pub use prepared_public_key::*;
 pub use proof_commitment::*;
 pub use proof_of_knowledge::*;
 pub use proof_of_possession::*;
@@ -61,22 +118,30 @@ pub use public_key::*;
 pub use public_key_share::*;
 pub use secret_key::*;
 pub use secret_key_share::*;
+pub use share_correctness_proof::*;
+pub use sharing_params::*;
 pub use sig_types::*;
 pub use sign_crypt_ciphertext::*;
+pub use sign_crypt_stream::*;
 pub use sign_decryption_share::*;
 pub use signature::*;
+pub use signature_aggregator::*;
 pub use signature_share::*;
+pub use signed_message::*;
+pub use slot_signer::*;
+pub use time_crypt_any_ciphertext::*;
 pub use time_crypt_ciphertext::*;
 pub use traits::*;
+pub use verifiable::*;
 
 pub use vsss_rs;
 
-use inner_types::*;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::{
+use core::{
     fmt::{self, Display, Formatter, LowerHex, UpperHex},
     hash::Hash,
 };
+use inner_types::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use subtle::Choice;
 use vsss_rs::{DefaultShare, IdentifierPrimeField, Share, ValueGroup};
 use zeroize::DefaultIsZeroes;
@@ -94,7 +159,7 @@ impl subtle::ConditionallySelectable for InnerPointShareG1 {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         let identifier1 = a.0.identifier.0;
         let identifier2 = b.0.identifier.0;
-        let value1 = b.0.value.to_affine();
+        let value1 = a.0.value.to_affine();
         let value2 = b.0.value.to_affine();
 
         let identifier = Scalar::conditional_select(&identifier1, &identifier2, choice);
@@ -203,9 +268,22 @@ impl Share for InnerPointShareG1 {
 
 impl InnerPointShareG1 {
     /// Convert secret share from InnerPointShareG1 v1 to the newer v2 format
+    ///
+    /// The v1 format stores the identifier in a single byte so it can only
+    /// represent shares with identifiers up to 255. The v2 format uses
+    /// full scalar identifiers and has no such limit.
+    ///
+    /// The v1 encoding is one identifier byte followed by the 48 byte
+    /// compressed G1 point.
     pub fn from_v1_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
         #[derive(Deserialize)]
         struct V1(#[serde(deserialize_with = "fixed_arr::BigArray::deserialize")] [u8; 49]);
+        if bytes.len() != 49 {
+            return Err(BlsError::InvalidInputs(format!(
+                "v1 G1 point share must be 49 bytes, found {}",
+                bytes.len()
+            )));
+        }
         let v1 = serde_bare::from_slice::<V1>(bytes)
             .map_err(|e| BlsError::InvalidInputs(e.to_string()))?;
         let identifier = Scalar::from(v1.0[0] as u64);
@@ -216,6 +294,35 @@ impl InnerPointShareG1 {
         })?;
         Ok(Self((identifier, value).into()))
     }
+
+    /// Convert a v1 share whose identifier is stored in `width`
+    /// little-endian bytes instead of one
+    ///
+    /// Use this for legacy shares with identifiers larger than 255 that
+    /// [`Self::from_v1_bytes`] cannot represent.
+    pub fn from_v1_bytes_with_identifier_width(
+        bytes: &[u8],
+        width: usize,
+    ) -> Result<Self, BlsError> {
+        let len = width.checked_add(48).ok_or_else(|| {
+            BlsError::InvalidInputs(format!("identifier width {} is too large", width))
+        })?;
+        if bytes.len() != len {
+            return Err(BlsError::InvalidInputs(format!(
+                "v1 G1 point share with a {} byte identifier must be {} bytes, found {}",
+                width,
+                len,
+                bytes.len()
+            )));
+        }
+        let identifier = identifier_from_le_bytes::<Scalar>(&bytes[..width])?;
+        let mut repr = [0u8; 48];
+        repr.as_mut().copy_from_slice(&bytes[width..]);
+        let value = Option::from(G1Projective::from_compressed(&repr)).ok_or_else(|| {
+            BlsError::InvalidInputs("Invalid compressed G1Projective".to_string())
+        })?;
+        Ok(Self((identifier, value).into()))
+    }
 }
 
 /// The share type for points in G2
@@ -233,7 +340,7 @@ impl subtle::ConditionallySelectable for InnerPointShareG2 {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         let identifier1 = a.0.identifier.0;
         let identifier2 = b.0.identifier.0;
-        let value1 = b.0.value.to_affine();
+        let value1 = a.0.value.to_affine();
         let value2 = b.0.value.to_affine();
         let identifier = Scalar::conditional_select(&identifier1, &identifier2, choice);
         let value = G2Affine::conditional_select(&value1, &value2, choice);
@@ -338,17 +445,59 @@ impl Share for InnerPointShareG2 {
 }
 
 impl InnerPointShareG2 {
-    /// Convert secret share from InnerPointShareG1 v1 to the newer v2 format
+    /// Convert secret share from InnerPointShareG2 v1 to the newer v2 format
+    ///
+    /// The v1 format stores the identifier in a single byte so it can only
+    /// represent shares with identifiers up to 255. The v2 format uses
+    /// full scalar identifiers and has no such limit.
+    ///
+    /// The v1 encoding is one identifier byte followed by the 96 byte
+    /// compressed G2 point.
     pub fn from_v1_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
         #[derive(Deserialize)]
         struct V1(#[serde(deserialize_with = "fixed_arr::BigArray::deserialize")] [u8; 97]);
+        if bytes.len() != 97 {
+            return Err(BlsError::InvalidInputs(format!(
+                "v1 G2 point share must be 97 bytes, found {}",
+                bytes.len()
+            )));
+        }
         let v1 = serde_bare::from_slice::<V1>(bytes)
             .map_err(|e| BlsError::InvalidInputs(e.to_string()))?;
         let identifier = Scalar::from(v1.0[0] as u64);
         let mut repr = [0u8; 96];
         repr.as_mut().copy_from_slice(&v1.0[1..]);
         let value = Option::from(G2Projective::from_compressed(&repr)).ok_or_else(|| {
-            BlsError::InvalidInputs("Invalid compressed G1Projective".to_string())
+            BlsError::InvalidInputs("Invalid compressed G2Projective".to_string())
+        })?;
+        Ok(Self((identifier, value).into()))
+    }
+
+    /// Convert a v1 share whose identifier is stored in `width`
+    /// little-endian bytes instead of one
+    ///
+    /// Use this for legacy shares with identifiers larger than 255 that
+    /// [`Self::from_v1_bytes`] cannot represent.
+    pub fn from_v1_bytes_with_identifier_width(
+        bytes: &[u8],
+        width: usize,
+    ) -> Result<Self, BlsError> {
+        let len = width.checked_add(96).ok_or_else(|| {
+            BlsError::InvalidInputs(format!("identifier width {} is too large", width))
+        })?;
+        if bytes.len() != len {
+            return Err(BlsError::InvalidInputs(format!(
+                "v1 G2 point share with a {} byte identifier must be {} bytes, found {}",
+                width,
+                len,
+                bytes.len()
+            )));
+        }
+        let identifier = identifier_from_le_bytes::<Scalar>(&bytes[..width])?;
+        let mut repr = [0u8; 96];
+        repr.as_mut().copy_from_slice(&bytes[width..]);
+        let value = Option::from(G2Projective::from_compressed(&repr)).ok_or_else(|| {
+            BlsError::InvalidInputs("Invalid compressed G2Projective".to_string())
         })?;
         Ok(Self((identifier, value).into()))
     }
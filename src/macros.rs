@@ -65,3 +65,32 @@ macro_rules! impl_from_derivatives {
         }
     };
 }
+
+macro_rules! impl_hex_generic {
+    ($name:ident) => {
+        impl<C: BlsSignatureImpl> $name<C> {
+            /// Encode the bytes from `Vec::from` as a lowercase hex string
+            /// without a prefix
+            pub fn to_hex(&self) -> String {
+                let bytes = zeroize::Zeroizing::new(Vec::from(self));
+                hex::encode(bytes.as_slice())
+            }
+
+            /// Decode a hex string created by `to_hex`
+            pub fn from_hex(s: &str) -> BlsResult<Self> {
+                let bytes = zeroize::Zeroizing::new(
+                    hex::decode(s).map_err(|e| BlsError::DeserializationError(e.to_string()))?,
+                );
+                Self::try_from(bytes.as_slice())
+            }
+        }
+
+        impl<C: BlsSignatureImpl> core::str::FromStr for $name<C> {
+            type Err = BlsError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::from_hex(s)
+            }
+        }
+    };
+}
@@ -0,0 +1,90 @@
+use crate::*;
+
+/// Remembers the curve points one message hashes to, keyed by
+/// domain separation tag.
+///
+/// Only repeated requests for the same tag are saved. Each distinct
+/// tag costs a full hash to curve since the backends only expose the
+/// complete hash, so nothing is shared between tags. Useful when the
+/// same message is verified many times, e.g. one message signed by a
+/// whole committee.
+///
+/// Message augmentation prefixes the public key to the message, so
+/// those points are remembered per public key instead.
+pub struct MessageHasher<'a, C: BlsSignatureImpl> {
+    msg: &'a [u8],
+    points: Vec<(Vec<u8>, <C as Pairing>::Signature)>,
+    augmented: Vec<(<C as Pairing>::PublicKey, <C as Pairing>::Signature)>,
+}
+
+impl<C: BlsSignatureImpl> Clone for MessageHasher<'_, C> {
+    fn clone(&self) -> Self {
+        Self {
+            msg: self.msg,
+            points: self.points.clone(),
+            augmented: self.augmented.clone(),
+        }
+    }
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for MessageHasher<'_, C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "MessageHasher {{ msg: {:?}, points: {:?}, augmented: {:?} }}",
+            self.msg, self.points, self.augmented
+        )
+    }
+}
+
+impl<'a, C: BlsSignatureImpl> MessageHasher<'a, C> {
+    /// Create a new hasher for `msg`
+    pub fn new(msg: &'a [u8]) -> Self {
+        Self {
+            msg,
+            points: Vec::new(),
+            augmented: Vec::new(),
+        }
+    }
+
+    /// The message being hashed
+    pub fn message(&self) -> &'a [u8] {
+        self.msg
+    }
+
+    /// Hash the message to a point using `dst`
+    pub fn to_point<D: AsRef<[u8]>>(&mut self, dst: D) -> <C as Pairing>::Signature {
+        let dst = dst.as_ref();
+        if let Some((_, point)) = self.points.iter().find(|(d, _)| d == dst) {
+            return *point;
+        }
+        let point = <C as HashToPoint>::hash_to_point(self.msg, dst);
+        self.points.push((dst.to_vec(), point));
+        point
+    }
+
+    /// Hash the message to the point signed under `scheme`.
+    ///
+    /// `pk` is only used by message augmentation which signs the
+    /// public key followed by the message.
+    pub fn scheme_point(
+        &mut self,
+        scheme: SignatureSchemes,
+        pk: &PublicKey<C>,
+    ) -> <C as Pairing>::Signature {
+        if scheme != SignatureSchemes::MessageAugmentation {
+            return self.to_point(scheme_dst::<C>(scheme));
+        }
+        if let Some((_, point)) = self.augmented.iter().find(|(k, _)| *k == pk.0) {
+            return *point;
+        }
+        let mut overhead = <C as BlsSignatureMessageAugmentation>::pk_bytes(pk.0, self.msg.len());
+        overhead.extend_from_slice(self.msg);
+        let point = <C as HashToPoint>::hash_to_point(
+            &overhead,
+            <C as BlsSignatureMessageAugmentation>::DST,
+        );
+        self.augmented.push((pk.0, point));
+        point
+    }
+}
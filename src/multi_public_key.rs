@@ -6,7 +6,7 @@ use crate::*;
 pub struct MultiPublicKey<C: BlsSignatureImpl>(
     /// The inner raw value
     #[serde(serialize_with = "traits::public_key::serialize::<C, _>")]
-    #[serde(deserialize_with = "traits::public_key::deserialize::<C, _>")]
+    #[serde(deserialize_with = "traits::non_identity_public_key::deserialize::<C, _>")]
     pub <C as Pairing>::PublicKey,
 );
 
@@ -44,6 +44,18 @@ impl<C: BlsSignatureImpl> From<&[PublicKey<C>]> for MultiPublicKey<C> {
     }
 }
 
+impl<C: BlsSignatureImpl> From<MultiPublicKey<C>> for PublicKey<C> {
+    fn from(value: MultiPublicKey<C>) -> Self {
+        value.as_public_key()
+    }
+}
+
+impl<C: BlsSignatureImpl> From<&MultiPublicKey<C>> for PublicKey<C> {
+    fn from(value: &MultiPublicKey<C>) -> Self {
+        value.as_public_key()
+    }
+}
+
 impl_from_derivatives_generic!(MultiPublicKey);
 
 impl<C: BlsSignatureImpl> From<&MultiPublicKey<C>> for Vec<u8> {
@@ -55,7 +67,24 @@ impl<C: BlsSignatureImpl> From<&MultiPublicKey<C>> for Vec<u8> {
 impl<C: BlsSignatureImpl> TryFrom<&[u8]> for MultiPublicKey<C> {
     type Error = BlsError;
 
+    /// Deserialize a public key, rejecting the identity element
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let pk = Self::try_from_unchecked(value)?;
+        if pk.0.is_identity().into() {
+            return Err(BlsError::InvalidInputs(
+                "public key is identity".to_string(),
+            ));
+        }
+        Ok(pk)
+    }
+}
+
+impl<C: BlsSignatureImpl> MultiPublicKey<C> {
+    /// Deserialize a public key without rejecting the identity element.
+    ///
+    /// The identity verifies signatures that are themselves the identity,
+    /// so only use this when the key is checked some other way.
+    pub fn try_from_unchecked(value: &[u8]) -> BlsResult<Self> {
         let mut repr = C::PublicKey::default().to_bytes();
         let len = repr.as_ref().len();
 
@@ -72,13 +101,88 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for MultiPublicKey<C> {
         key.map(Self)
             .ok_or_else(|| BlsError::InvalidInputs("Invalid byte sequence".to_string()))
     }
-}
 
-impl<C: BlsSignatureImpl> MultiPublicKey<C> {
     /// Accumulate multiple public keys into a single public key
     pub fn from_public_keys<B: AsRef<[PublicKey<C>]>>(keys: B) -> Self {
         Self(<C as BlsMultiKey>::from_public_keys(
             keys.as_ref().iter().map(|k| k.0),
         ))
     }
+
+    /// Accumulate multiple public keys into a single public key after
+    /// checking the proof of possession of every key.
+    ///
+    /// Summing keys is only safe against rogue key attacks when each key's
+    /// proof of possession is valid. The proofs are checked together with
+    /// [`ProofOfPossession::verify_batch`]. Fails if `keys` is empty or any
+    /// proof is invalid.
+    pub fn from_public_keys_checked(
+        keys: &[(PublicKey<C>, ProofOfPossession<C>)],
+    ) -> BlsResult<Self> {
+        match ProofOfPossession::verify_batch(keys, get_crypto_rng()) {
+            Ok(()) => Ok(Self(<C as BlsMultiKey>::from_public_keys(
+                keys.iter().map(|(pk, _)| pk.0),
+            ))),
+            Err(bad) => match bad.first() {
+                Some(i) => Err(BlsError::InvalidInputs(format!(
+                    "proof of possession at {} is invalid",
+                    i + 1
+                ))),
+                None => Err(BlsError::InvalidInputs(
+                    "no public keys to aggregate".to_string(),
+                )),
+            },
+        }
+    }
+
+    /// Use the accumulated key as a [`PublicKey`] so it can be passed to any
+    /// API that takes one, e.g. [`Signature::verify`] for a multi-signature
+    /// where every signer signed the same message.
+    ///
+    /// This is only safe with the proof of possession scheme when every
+    /// accumulated key's proof of possession was checked, as with
+    /// [`MultiPublicKey::from_public_keys_checked`]. Otherwise a rogue key
+    /// chosen to cancel the honest keys can forge signatures for the set.
+    pub fn as_public_key(&self) -> PublicKey<C> {
+        PublicKey(self.0)
+    }
+
+    /// Remove a public key that was previously accumulated into this key.
+    ///
+    /// Cheaper than calling [`MultiPublicKey::from_public_keys`] on the
+    /// remaining keys. The caller must ensure `pk` was part of the accumulation
+    /// otherwise the result won't match any set of keys.
+    ///
+    /// Returns `None` if the result is the identity, e.g. when `pk` was
+    /// the only key left, since the identity is never a valid public key.
+    pub fn checked_sub(&self, pk: &PublicKey<C>) -> Option<Self> {
+        let key = self.0 - pk.0;
+        if key.is_identity().into() {
+            return None;
+        }
+        Some(Self(key))
+    }
+
+    /// Accumulate another public key into this key, e.g. when a signer
+    /// joins a validator set.
+    ///
+    /// Like [`MultiPublicKey::from_public_keys`] the key's proof of
+    /// possession should be checked before it is added.
+    pub fn add(&mut self, pk: &PublicKey<C>) {
+        self.0 += pk.0;
+    }
+
+    /// Remove a public key from this key in place, e.g. when a signer
+    /// leaves a validator set.
+    ///
+    /// `pk` must be the exact key that was previously added, there is no
+    /// way to check this so removing any other key silently corrupts the
+    /// accumulated key. Fails and leaves this key unchanged if the result
+    /// would be the identity.
+    pub fn remove(&mut self, pk: &PublicKey<C>) -> BlsResult<()> {
+        *self = self.checked_sub(pk).ok_or_else(|| {
+            BlsError::InvalidInputs("removing the key leaves the identity".to_string())
+        })?;
+        Ok(())
+    }
 }
@@ -81,23 +81,20 @@ impl<C: BlsSignatureImpl> TryFrom<&[Signature<C>]> for MultiSignature<C> {
     type Error = BlsError;
 
     fn try_from(sigs: &[Signature<C>]) -> Result<Self, Self::Error> {
-        if sigs.len() < 2 {
-            return Err(BlsError::InvalidSignature);
+        if sigs.is_empty() {
+            return Err(BlsError::InvalidInputs(
+                "no signatures provided".to_string(),
+            ));
         }
-        let mut g = <C as Pairing>::Signature::identity();
         for s in &sigs[1..] {
             if !s.same_scheme(&sigs[0]) {
                 return Err(BlsError::InvalidSignatureScheme);
             }
-            let ss = match s {
-                Signature::Basic(sig) => sig,
-                Signature::MessageAugmentation(_) => {
-                    return Err(BlsError::InvalidSignatureScheme);
-                }
-                Signature::ProofOfPossession(sig) => sig,
-            };
-            g += ss;
         }
+        if let Signature::MessageAugmentation(_) = sigs[0] {
+            return Err(BlsError::InvalidSignatureScheme);
+        }
+        let g = sum_signatures(&sigs[1..]);
         match sigs[0] {
             Signature::Basic(s) => Ok(Self::Basic(g + s)),
             Signature::MessageAugmentation(s) => Ok(Self::MessageAugmentation(g + s)),
@@ -123,6 +120,15 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for MultiSignature<C> {
 }
 
 impl<C: BlsSignatureImpl> MultiSignature<C> {
+    /// The signature scheme used by this value
+    pub fn scheme(&self) -> SignatureSchemes {
+        match self {
+            Self::Basic(_) => SignatureSchemes::Basic,
+            Self::MessageAugmentation(_) => SignatureSchemes::MessageAugmentation,
+            Self::ProofOfPossession(_) => SignatureSchemes::ProofOfPossession,
+        }
+    }
+
     /// Verify the multi-signature using the multi-public key
     pub fn verify<B: AsRef<[u8]>>(&self, pk: MultiPublicKey<C>, msg: B) -> BlsResult<()> {
         match self {
@@ -134,6 +140,21 @@ impl<C: BlsSignatureImpl> MultiSignature<C> {
         }
     }
 
+    /// Verify the multi-signature using the public keys of the signers.
+    ///
+    /// The keys are summed into a [`MultiPublicKey`] so only a single
+    /// pairing check is needed regardless of the number of signers. The
+    /// keys should have had their proofs of possession checked beforehand
+    /// to prevent rogue key attacks.
+    pub fn verify_fast<B: AsRef<[u8]>>(&self, pks: &[PublicKey<C>], msg: B) -> BlsResult<()> {
+        if pks.is_empty() {
+            return Err(BlsError::InvalidInputs(
+                "no public keys to aggregate".to_string(),
+            ));
+        }
+        self.verify(MultiPublicKey::from_public_keys(pks), msg)
+    }
+
     /// Extract the inner raw representation
     pub fn as_raw_value(&self) -> &<C as Pairing>::Signature {
         match self {
@@ -143,6 +164,13 @@ impl<C: BlsSignatureImpl> MultiSignature<C> {
         }
     }
 
+    /// Create a multi-signature of a single signature.
+    ///
+    /// Fails for message augmentation signatures like [`Self::from_signatures`].
+    pub fn from_single(sig: Signature<C>) -> BlsResult<Self> {
+        Self::try_from(&[sig][..])
+    }
+
     /// Accumulate multiple signatures into a single signature
     pub fn from_signatures<B: AsRef<[Signature<C>]>>(signatures: B) -> BlsResult<Self> {
         Self::try_from(signatures.as_ref())
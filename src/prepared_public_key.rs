@@ -0,0 +1,103 @@
+use crate::impls::inner_types::*;
+use crate::*;
+
+/// A BLS public key with its pairing inputs precomputed.
+///
+/// Use when the same public key verifies many signatures. For
+/// [`Bls12381G1Impl`] the public key is in G2, so the Miller loop
+/// line coefficients for both the key and the negated generator are
+/// computed once here instead of on every verification, which removes
+/// a large share of the per signature cost. For [`Bls12381G2Impl`] the
+/// public key is in G1 and the G2 inputs are the hashed message and the
+/// signature, which change every time, so only the affine conversion
+/// of the key is saved.
+pub struct PreparedPublicKey<C: BlsSignatureImpl> {
+    public_key: PublicKey<C>,
+    prepared: <C as Pairing>::PublicKeyPrepared,
+    neg_generator: <C as Pairing>::PublicKeyPrepared,
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for PreparedPublicKey<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "PreparedPublicKey({:?})", self.public_key)
+    }
+}
+
+impl<C: BlsSignatureImpl> Clone for PreparedPublicKey<C> {
+    fn clone(&self) -> Self {
+        Self {
+            public_key: self.public_key,
+            prepared: self.prepared.clone(),
+            neg_generator: self.neg_generator.clone(),
+        }
+    }
+}
+
+impl<C: BlsSignatureImpl> From<PublicKey<C>> for PreparedPublicKey<C> {
+    fn from(pk: PublicKey<C>) -> Self {
+        Self::from(&pk)
+    }
+}
+
+impl<C: BlsSignatureImpl> From<&PublicKey<C>> for PreparedPublicKey<C> {
+    fn from(pk: &PublicKey<C>) -> Self {
+        Self {
+            public_key: *pk,
+            prepared: <C as Pairing>::prepare_public_key(&pk.0),
+            neg_generator: <C as Pairing>::prepare_public_key(
+                &-<C as Pairing>::PublicKey::generator(),
+            ),
+        }
+    }
+}
+
+impl<C: BlsSignatureImpl> From<&PreparedPublicKey<C>> for PublicKey<C> {
+    fn from(pk: &PreparedPublicKey<C>) -> Self {
+        pk.public_key
+    }
+}
+
+impl<C: BlsSignatureImpl> PreparedPublicKey<C> {
+    /// The public key that was prepared
+    pub fn public_key(&self) -> PublicKey<C> {
+        self.public_key
+    }
+
+    /// Verify a signature using this public key
+    pub fn verify<B: AsRef<[u8]>>(&self, sig: &Signature<C>, msg: B) -> BlsResult<()> {
+        if self.public_key.0.is_identity().into() {
+            return Err(BlsError::InvalidInputs(
+                "public key is the identity point".to_string(),
+            ));
+        }
+        match sig {
+            Signature::Basic(sig) => <C as BlsSignatureCore>::core_verify_prepared(
+                &self.prepared,
+                &self.neg_generator,
+                *sig,
+                msg,
+                <C as BlsSignatureBasic>::DST,
+            ),
+            Signature::MessageAugmentation(sig) => {
+                let pk_bytes = self.public_key.0.to_bytes();
+                let mut overhead = Vec::with_capacity(pk_bytes.as_ref().len() + msg.as_ref().len());
+                overhead.extend_from_slice(pk_bytes.as_ref());
+                overhead.extend_from_slice(msg.as_ref());
+                <C as BlsSignatureCore>::core_verify_prepared(
+                    &self.prepared,
+                    &self.neg_generator,
+                    *sig,
+                    overhead.as_slice(),
+                    <C as BlsSignatureMessageAugmentation>::DST,
+                )
+            }
+            Signature::ProofOfPossession(sig) => <C as BlsSignatureCore>::core_verify_prepared(
+                &self.prepared,
+                &self.neg_generator,
+                *sig,
+                msg,
+                <C as BlsSignaturePop>::SIG_DST,
+            ),
+        }
+    }
+}
@@ -2,7 +2,8 @@ use crate::impls::inner_types::*;
 use crate::*;
 use rand::Rng;
 use rand_core::{CryptoRng, RngCore};
-use subtle::CtOption;
+use subtle::{ConstantTimeEq, CtOption};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// The commitment portion of the signature proof of knowledge
 #[derive(PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -108,6 +109,15 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for ProofCommitment<C> {
 }
 
 impl<C: BlsSignatureImpl> ProofCommitment<C> {
+    /// The signature scheme used by this value
+    pub fn scheme(&self) -> SignatureSchemes {
+        match self {
+            Self::Basic(_) => SignatureSchemes::Basic,
+            Self::MessageAugmentation(_) => SignatureSchemes::MessageAugmentation,
+            Self::ProofOfPossession(_) => SignatureSchemes::ProofOfPossession,
+        }
+    }
+
     /// Generate a new proof of knowledge commitment
     /// This is step 1 in the 3 step process
     pub fn generate<B: AsRef<[u8]>>(
@@ -166,7 +176,7 @@ impl<C: BlsSignatureImpl> ProofCommitment<C> {
 }
 
 /// A commitment secret used to create the proof of knowledge
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ProofCommitmentSecret<C: BlsSignatureImpl>(
     /// The commitment secret raw value
     #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
@@ -174,6 +184,34 @@ pub struct ProofCommitmentSecret<C: BlsSignatureImpl>(
     pub <<C as Pairing>::PublicKey as Group>::Scalar,
 );
 
+impl<C: BlsSignatureImpl> ConstantTimeEq for ProofCommitmentSecret<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<C: BlsSignatureImpl> PartialEq for ProofCommitmentSecret<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C: BlsSignatureImpl> Eq for ProofCommitmentSecret<C> {}
+
+impl<C: BlsSignatureImpl> Zeroize for ProofCommitmentSecret<C> {
+    fn zeroize(&mut self) {
+        zeroize_scalar(&mut self.0);
+    }
+}
+
+impl<C: BlsSignatureImpl> Drop for ProofCommitmentSecret<C> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C: BlsSignatureImpl> ZeroizeOnDrop for ProofCommitmentSecret<C> {}
+
 impl_from_derivatives_generic!(ProofCommitmentSecret);
 
 impl<C: BlsSignatureImpl> From<&ProofCommitmentSecret<C>> for Vec<u8> {
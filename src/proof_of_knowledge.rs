@@ -1,4 +1,6 @@
+use crate::impls::inner_types::*;
 use crate::*;
+use rand_core::{CryptoRng, RngCore};
 use subtle::Choice;
 
 /// A signature proof of knowledge
@@ -128,6 +130,129 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for ProofOfKnowledge<C> {
 impl_from_derivatives_generic!(ProofOfKnowledge);
 
 impl<C: BlsSignatureImpl> ProofOfKnowledge<C> {
+    /// The number of bytes in the compact encoding
+    pub const BYTES: usize = 1 + 2 * <C as Pairing>::SIGNATURE_BYTES;
+
+    /// Get the fixed size encoding `scheme || u || v` of compressed points
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (u, v) = self.raw_values();
+        let mut output = Vec::with_capacity(Self::BYTES);
+        output.push(self.scheme() as u8);
+        output.extend_from_slice(u.to_bytes().as_ref());
+        output.extend_from_slice(v.to_bytes().as_ref());
+        output
+    }
+
+    /// Read the fixed size encoding produced by [`ProofOfKnowledge::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> BlsResult<Self> {
+        if bytes.len() != Self::BYTES {
+            return Err(BlsError::InvalidInputs(format!(
+                "Invalid length, expected {}, got {}",
+                Self::BYTES,
+                bytes.len()
+            )));
+        }
+        let (u, v) = bytes[1..].split_at(<C as Pairing>::SIGNATURE_BYTES);
+        let read_point = |b: &[u8]| {
+            let mut repr = <C as Pairing>::Signature::default().to_bytes();
+            repr.as_mut().copy_from_slice(b);
+            Option::<<C as Pairing>::Signature>::from(<C as Pairing>::Signature::from_bytes(&repr))
+                .ok_or_else(|| BlsError::InvalidInputs("Invalid byte sequence".to_string()))
+        };
+        let u = read_point(u)?;
+        let v = read_point(v)?;
+        match bytes[0] {
+            0 => Ok(Self::Basic { u, v }),
+            1 => Ok(Self::MessageAugmentation { u, v }),
+            2 => Ok(Self::ProofOfPossession { u, v }),
+            _ => Err(BlsError::InvalidInputs(
+                "Invalid signature scheme".to_string(),
+            )),
+        }
+    }
+
+    /// The signature scheme used by this value
+    pub fn scheme(&self) -> SignatureSchemes {
+        match self {
+            Self::Basic { .. } => SignatureSchemes::Basic,
+            Self::MessageAugmentation { .. } => SignatureSchemes::MessageAugmentation,
+            Self::ProofOfPossession { .. } => SignatureSchemes::ProofOfPossession,
+        }
+    }
+
+    /// Create a non-interactive proof of knowledge of `signature` on `msg`
+    ///
+    /// The challenge is derived from the commitment, `pk` and `msg` so no
+    /// verifier challenge or timestamp is needed.
+    pub fn create_noninteractive<B: AsRef<[u8]>>(
+        msg: B,
+        signature: Signature<C>,
+        pk: PublicKey<C>,
+    ) -> BlsResult<Self> {
+        match signature {
+            Signature::Basic(s) => {
+                let (u, v) = <C as BlsSignatureProof>::generate_noninteractive_proof(
+                    msg,
+                    <C as BlsSignatureBasic>::DST,
+                    s,
+                    pk.0,
+                )?;
+                Ok(Self::Basic { u, v })
+            }
+            Signature::MessageAugmentation(s) => {
+                let (u, v) = <C as BlsSignatureProof>::generate_noninteractive_proof(
+                    msg,
+                    <C as BlsSignatureMessageAugmentation>::DST,
+                    s,
+                    pk.0,
+                )?;
+                Ok(Self::MessageAugmentation { u, v })
+            }
+            Signature::ProofOfPossession(s) => {
+                let (u, v) = <C as BlsSignatureProof>::generate_noninteractive_proof(
+                    msg,
+                    <C as BlsSignaturePop>::SIG_DST,
+                    s,
+                    pk.0,
+                )?;
+                Ok(Self::ProofOfPossession { u, v })
+            }
+        }
+    }
+
+    /// Verify a proof created by [`ProofOfKnowledge::create_noninteractive`]
+    pub fn verify_noninteractive<B: AsRef<[u8]>>(&self, pk: PublicKey<C>, msg: B) -> BlsResult<()> {
+        match self {
+            ProofOfKnowledge::Basic { u, v } => {
+                <C as BlsSignatureProof>::verify_noninteractive_proof(
+                    *u,
+                    *v,
+                    pk.0,
+                    msg,
+                    <C as BlsSignatureBasic>::DST,
+                )
+            }
+            ProofOfKnowledge::MessageAugmentation { u, v } => {
+                <C as BlsSignatureProof>::verify_noninteractive_proof(
+                    *u,
+                    *v,
+                    pk.0,
+                    msg,
+                    <C as BlsSignatureMessageAugmentation>::DST,
+                )
+            }
+            ProofOfKnowledge::ProofOfPossession { u, v } => {
+                <C as BlsSignatureProof>::verify_noninteractive_proof(
+                    *u,
+                    *v,
+                    pk.0,
+                    msg,
+                    <C as BlsSignaturePop>::SIG_DST,
+                )
+            }
+        }
+    }
+
     /// Verify the proof of knowledge
     pub fn verify<B: AsRef<[u8]>>(
         &self,
@@ -162,6 +287,70 @@ impl<C: BlsSignatureImpl> ProofOfKnowledge<C> {
             ),
         }
     }
+
+    /// Verify many proofs of knowledge that all use the same scheme using a
+    /// random linear combination so only one multi-pairing is computed.
+    ///
+    /// On failure the position of every proof that does not verify is
+    /// returned. Proofs using a different scheme than the first proof or
+    /// with identity points or a zero challenge are reported without checking
+    /// the pairing. An empty list means there was nothing to verify.
+    pub fn verify_batch(
+        items: &[(
+            PublicKey<C>,
+            &[u8],
+            ProofCommitmentChallenge<C>,
+            ProofOfKnowledge<C>,
+        )],
+        mut rng: impl RngCore + CryptoRng,
+    ) -> Result<(), Vec<usize>> {
+        let scheme = items.first().ok_or_else(Vec::new)?.3.scheme();
+        let bad = items
+            .iter()
+            .enumerate()
+            .filter(|(_, (pk, _, y, proof))| {
+                let (u, v) = proof.raw_values();
+                proof.scheme() != scheme
+                    || bool::from(u.is_identity())
+                    || bool::from(v.is_identity())
+                    || bool::from(pk.0.is_identity())
+                    || bool::from(y.0.is_zero())
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        if !bad.is_empty() {
+            return Err(bad);
+        }
+
+        let dst = scheme_dst::<C>(scheme);
+        let mut pairs = Vec::with_capacity(items.len() + 1);
+        let mut v_sum = <C as Pairing>::Signature::identity();
+        for (pk, msg, y, proof) in items {
+            let r = <<C as Pairing>::Signature as Group>::Scalar::random(&mut rng);
+            let (u, v) = proof.raw_values();
+            let a = <C as HashToPoint>::hash_to_point(msg, dst);
+            pairs.push(((u + a * y.0) * r, pk.0));
+            v_sum += v * r;
+        }
+        pairs.push((v_sum, <C as Pairing>::PublicKey::generator()));
+        if <C as Pairing>::pairing(&pairs).is_identity().into() {
+            return Ok(());
+        }
+        Err(items
+            .iter()
+            .enumerate()
+            .filter(|(_, (pk, msg, y, proof))| proof.verify(*pk, msg, *y).is_err())
+            .map(|(i, _)| i)
+            .collect())
+    }
+
+    fn raw_values(&self) -> (<C as Pairing>::Signature, <C as Pairing>::Signature) {
+        match self {
+            Self::Basic { u, v }
+            | Self::MessageAugmentation { u, v }
+            | Self::ProofOfPossession { u, v } => (*u, *v),
+        }
+    }
 }
 
 /// A signature proof of knowledge based on a timestamp
@@ -244,37 +433,54 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for ProofOfKnowledgeTimestamp<C> {
 impl_from_derivatives_generic!(ProofOfKnowledgeTimestamp);
 
 impl<C: BlsSignatureImpl> ProofOfKnowledgeTimestamp<C> {
-    /// Create a new signature proof of knowledge using a timestamp
+    /// Create a new signature proof of knowledge using the current system time
+    #[cfg(feature = "std")]
     pub fn generate<B: AsRef<[u8]>>(msg: B, signature: Signature<C>) -> BlsResult<Self> {
+        Self::generate_with_time(msg, signature, now_ms())
+    }
+
+    /// Create a new signature proof of knowledge using `now_ms`,
+    /// the milliseconds since the unix epoch, as the timestamp
+    pub fn generate_with_time<B: AsRef<[u8]>>(
+        msg: B,
+        signature: Signature<C>,
+        now_ms: u64,
+    ) -> BlsResult<Self> {
         match signature {
             Signature::Basic(s) => {
-                let (u, v, timestamp) = <C as BlsSignatureProof>::generate_timestamp_proof(
-                    msg,
-                    <C as BlsSignatureBasic>::DST,
-                    s,
-                )?;
+                let (u, v, timestamp) =
+                    <C as BlsSignatureProof>::generate_timestamp_proof_with_time(
+                        msg,
+                        <C as BlsSignatureBasic>::DST,
+                        s,
+                        now_ms,
+                    )?;
                 Ok(Self {
                     proof: ProofOfKnowledge::Basic { u, v },
                     timestamp,
                 })
             }
             Signature::MessageAugmentation(s) => {
-                let (u, v, timestamp) = <C as BlsSignatureProof>::generate_timestamp_proof(
-                    msg,
-                    <C as BlsSignatureMessageAugmentation>::DST,
-                    s,
-                )?;
+                let (u, v, timestamp) =
+                    <C as BlsSignatureProof>::generate_timestamp_proof_with_time(
+                        msg,
+                        <C as BlsSignatureMessageAugmentation>::DST,
+                        s,
+                        now_ms,
+                    )?;
                 Ok(Self {
                     proof: ProofOfKnowledge::MessageAugmentation { u, v },
                     timestamp,
                 })
             }
             Signature::ProofOfPossession(s) => {
-                let (u, v, timestamp) = <C as BlsSignatureProof>::generate_timestamp_proof(
-                    msg,
-                    <C as BlsSignaturePop>::SIG_DST,
-                    s,
-                )?;
+                let (u, v, timestamp) =
+                    <C as BlsSignatureProof>::generate_timestamp_proof_with_time(
+                        msg,
+                        <C as BlsSignaturePop>::SIG_DST,
+                        s,
+                        now_ms,
+                    )?;
                 Ok(Self {
                     proof: ProofOfKnowledge::ProofOfPossession { u, v },
                     timestamp,
@@ -283,40 +489,61 @@ impl<C: BlsSignatureImpl> ProofOfKnowledgeTimestamp<C> {
         }
     }
 
-    /// Verify this proof of knowledge
+    /// Verify this proof of knowledge against the current system time
+    #[cfg(feature = "std")]
     pub fn verify<B: AsRef<[u8]>>(
         &self,
         pk: PublicKey<C>,
         msg: B,
         timeout_ms: Option<u64>,
+    ) -> BlsResult<()> {
+        self.verify_at(pk, msg, now_ms(), timeout_ms)
+    }
+
+    /// Verify this proof of knowledge where `now_ms` is the current time
+    /// in milliseconds since the unix epoch.
+    ///
+    /// When `timeout_ms` is set, proofs older than the timeout or
+    /// timestamped after `now_ms` are rejected.
+    pub fn verify_at<B: AsRef<[u8]>>(
+        &self,
+        pk: PublicKey<C>,
+        msg: B,
+        now_ms: u64,
+        timeout_ms: Option<u64>,
     ) -> BlsResult<()> {
         match self.proof {
-            ProofOfKnowledge::Basic { u, v } => <C as BlsSignatureProof>::verify_timestamp_proof(
-                u,
-                v,
-                pk.0,
-                self.timestamp,
-                timeout_ms,
-                msg,
-                <C as BlsSignatureBasic>::DST,
-            ),
+            ProofOfKnowledge::Basic { u, v } => {
+                <C as BlsSignatureProof>::verify_timestamp_proof_at(
+                    u,
+                    v,
+                    pk.0,
+                    self.timestamp,
+                    now_ms,
+                    timeout_ms,
+                    msg,
+                    <C as BlsSignatureBasic>::DST,
+                )
+            }
             ProofOfKnowledge::MessageAugmentation { u, v } => {
-                <C as BlsSignatureProof>::verify_timestamp_proof(
+                <C as BlsSignatureProof>::verify_timestamp_proof_at(
                     u,
                     v,
                     pk.0,
                     self.timestamp,
+                    now_ms,
                     timeout_ms,
                     msg,
                     <C as BlsSignatureMessageAugmentation>::DST,
                 )
             }
             ProofOfKnowledge::ProofOfPossession { u, v } => {
-                <C as BlsSignatureProof>::verify_timestamp_proof(
+                <C as BlsSignatureProof>::verify_timestamp_proof_at(
                     u,
                     v,
                     pk.0,
                     self.timestamp,
+                    now_ms,
                     timeout_ms,
                     msg,
                     <C as BlsSignaturePop>::SIG_DST,
@@ -1,4 +1,5 @@
 use crate::*;
+use rand_core::{CryptoRng, RngCore};
 use subtle::{Choice, ConditionallySelectable};
 
 /// A proof of possession of the secret key
@@ -45,6 +46,7 @@ impl<C: BlsSignatureImpl> ConditionallySelectable for ProofOfPossession<C> {
 }
 
 impl_from_derivatives_generic!(ProofOfPossession);
+impl_hex_generic!(ProofOfPossession);
 
 impl<C: BlsSignatureImpl> From<&ProofOfPossession<C>> for Vec<u8> {
     fn from(value: &ProofOfPossession<C>) -> Self {
@@ -79,4 +81,52 @@ impl<C: BlsSignatureImpl> ProofOfPossession<C> {
     pub fn verify(&self, pk: PublicKey<C>) -> BlsResult<()> {
         <C as BlsSignaturePop>::pop_verify(pk.0, self.0)
     }
+
+    /// Verify this proof of possession was created for `context`
+    pub fn verify_with_context(&self, pk: PublicKey<C>, context: &[u8]) -> BlsResult<()> {
+        <C as BlsSignaturePop>::pop_verify_with_context(pk.0, self.0, context)
+    }
+
+    /// Verify many proofs of possession using a random linear combination so
+    /// only one multi-pairing is computed, e.g. when onboarding a validator set.
+    ///
+    /// Each proof is still hashed against its own public key. On failure the
+    /// position of every proof that does not verify is returned. Identity
+    /// keys or proofs are reported without checking the pairing. An empty
+    /// list means there was nothing to verify.
+    pub fn verify_batch(
+        items: &[(PublicKey<C>, ProofOfPossession<C>)],
+        rng: impl RngCore + CryptoRng,
+    ) -> Result<(), Vec<usize>> {
+        if items.is_empty() {
+            return Err(Vec::new());
+        }
+        let bad = items
+            .iter()
+            .enumerate()
+            .filter(|(_, (pk, pop))| bool::from(pk.0.is_identity() | pop.0.is_identity()))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        if !bad.is_empty() {
+            return Err(bad);
+        }
+
+        let ii = items.iter().map(|(pk, pop)| {
+            (
+                pk.0,
+                pop.0,
+                pk.0.to_bytes(),
+                <C as BlsSignaturePop>::POP_DST,
+            )
+        });
+        if <C as BlsSignatureCore>::core_batch_verify(ii, rng).is_ok() {
+            return Ok(());
+        }
+        Err(items
+            .iter()
+            .enumerate()
+            .filter(|(_, (pk, pop))| pop.verify(*pk).is_err())
+            .map(|(i, _)| i)
+            .collect())
+    }
 }
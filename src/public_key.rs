@@ -1,12 +1,16 @@
 use crate::impls::inner_types::*;
 use crate::*;
+use serde::de::{SeqAccess, Visitor};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+use subtle::ConstantTimeEq;
 
 /// A BLS public key
 #[derive(Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct PublicKey<C: BlsSignatureImpl>(
     /// The BLS public key raw value
     #[serde(serialize_with = "traits::public_key::serialize::<C, _>")]
-    #[serde(deserialize_with = "traits::public_key::deserialize::<C, _>")]
+    #[serde(deserialize_with = "traits::non_identity_public_key::deserialize::<C, _>")]
     pub <C as Pairing>::PublicKey,
 );
 
@@ -44,7 +48,17 @@ impl<C: BlsSignatureImpl> subtle::ConditionallySelectable for PublicKey<C> {
     }
 }
 
+impl<C: BlsSignatureImpl> ConstantTimeEq for PublicKey<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0
+            .to_bytes()
+            .as_ref()
+            .ct_eq(other.0.to_bytes().as_ref())
+    }
+}
+
 impl_from_derivatives_generic!(PublicKey);
+impl_hex_generic!(PublicKey);
 
 impl<C: BlsSignatureImpl> From<&PublicKey<C>> for Vec<u8> {
     fn from(value: &PublicKey<C>) -> Self {
@@ -55,7 +69,24 @@ impl<C: BlsSignatureImpl> From<&PublicKey<C>> for Vec<u8> {
 impl<C: BlsSignatureImpl> TryFrom<&[u8]> for PublicKey<C> {
     type Error = BlsError;
 
+    /// Deserialize a public key, rejecting the identity element
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let pk = Self::try_from_unchecked(value)?;
+        if pk.0.is_identity().into() {
+            return Err(BlsError::InvalidInputs(
+                "public key is identity".to_string(),
+            ));
+        }
+        Ok(pk)
+    }
+}
+
+impl<C: BlsSignatureImpl> PublicKey<C> {
+    /// Deserialize a public key without rejecting the identity element.
+    ///
+    /// The identity verifies signatures that are themselves the identity,
+    /// so only use this when the key is checked some other way.
+    pub fn try_from_unchecked(value: &[u8]) -> BlsResult<Self> {
         let mut repr = C::PublicKey::default().to_bytes();
         let len = repr.as_ref().len();
 
@@ -72,24 +103,47 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for PublicKey<C> {
         key.map(Self)
             .ok_or_else(|| BlsError::InvalidInputs("Invalid byte sequence".to_string()))
     }
-}
 
-impl<C: BlsSignatureImpl> PublicKey<C> {
+    /// Add the generator multiplied by the hash of `tweak` to this public key
+    /// so it stays the public key of [`SecretKey::add_tweak`] with the same tweak
+    pub fn add_tweak(&self, tweak: &[u8]) -> BlsResult<Self> {
+        let t = <C as HashToScalar>::hash_to_scalar(tweak, KEY_TWEAK_DST);
+        let pk = self.0 + <C as BlsSignatureCore>::public_key(&t);
+        if pk.is_identity().into() {
+            return Err(BlsError::InvalidInputs(
+                "tweaked public key is the identity point".to_string(),
+            ));
+        }
+        Ok(Self(pk))
+    }
+
     /// Encrypt a message using signcryption
     pub fn sign_crypt<B: AsRef<[u8]>>(
         &self,
         scheme: SignatureSchemes,
         msg: B,
     ) -> SignCryptCiphertext<C> {
-        let dst = match scheme {
-            SignatureSchemes::Basic => <C as BlsSignatureBasic>::DST,
-            SignatureSchemes::MessageAugmentation => <C as BlsSignatureMessageAugmentation>::DST,
-            SignatureSchemes::ProofOfPossession => <C as BlsSignaturePop>::SIG_DST,
-        };
+        let dst = scheme_dst::<C>(scheme);
         let (u, v, w) = <C as BlsSignCrypt>::seal(self.0, msg.as_ref(), dst);
         SignCryptCiphertext { u, v, w, scheme }
     }
 
+    /// Encrypt a message using signcryption and bind it to associated data
+    ///
+    /// The associated data isn't encrypted or stored in the ciphertext but
+    /// the same data must be supplied to decrypt. The ciphertext never
+    /// decrypts with [`SignCryptCiphertext::decrypt`], even if `aad` is empty.
+    pub fn sign_crypt_with_aad<B: AsRef<[u8]>, A: AsRef<[u8]>>(
+        &self,
+        scheme: SignatureSchemes,
+        msg: B,
+        aad: A,
+    ) -> SignCryptCiphertext<C> {
+        let dst = scheme_dst::<C>(scheme);
+        let (u, v, w) = <C as BlsSignCrypt>::seal_with_aad(self.0, msg.as_ref(), aad.as_ref(), dst);
+        SignCryptCiphertext { u, v, w, scheme }
+    }
+
     /// Encrypt a message using time lock encryption
     pub fn encrypt_time_lock<B: AsRef<[u8]>, D: AsRef<[u8]>>(
         &self,
@@ -97,21 +151,64 @@ impl<C: BlsSignatureImpl> PublicKey<C> {
         msg: B,
         id: D,
     ) -> BlsResult<TimeCryptCiphertext<C>> {
-        let dst = match scheme {
-            SignatureSchemes::Basic => <C as BlsSignatureBasic>::DST,
-            SignatureSchemes::MessageAugmentation => <C as BlsSignatureMessageAugmentation>::DST,
-            SignatureSchemes::ProofOfPossession => <C as BlsSignaturePop>::SIG_DST,
-        };
+        let dst = scheme_dst::<C>(scheme);
         let (u, v, w) = <C as BlsTimeCrypt>::seal(self.0, msg.as_ref(), id.as_ref(), dst)?;
         Ok(TimeCryptCiphertext { u, v, w, scheme })
     }
 
+    /// Encrypt a message using time lock encryption so a signature over
+    /// `id` from any one of `recipients` can decrypt it
+    pub fn encrypt_time_lock_any<B: AsRef<[u8]>, D: AsRef<[u8]>>(
+        recipients: &[Self],
+        scheme: SignatureSchemes,
+        msg: B,
+        id: D,
+    ) -> BlsResult<TimeCryptAnyCiphertext<C>> {
+        TimeCryptAnyCiphertext::new(recipients, scheme, msg.as_ref(), id.as_ref())
+    }
+
+    /// Encrypt a message for `recipient` that can only be opened after
+    /// this time lock key signs `id`.
+    ///
+    /// The message is first sign crypted to `recipient` then the result is
+    /// time lock encrypted, so once the time lock opens only the recipient
+    /// can read it. Open with [`TimeCryptCiphertext::decrypt_ephemeral`].
+    pub fn seal_ephemeral<B: AsRef<[u8]>, D: AsRef<[u8]>>(
+        &self,
+        recipient: &PublicKey<C>,
+        scheme: SignatureSchemes,
+        msg: B,
+        id: D,
+    ) -> BlsResult<TimeCryptCiphertext<C>> {
+        let inner = recipient.sign_crypt(scheme, msg);
+        self.encrypt_time_lock(scheme, Vec::<u8>::from(&inner), id)
+    }
+
     /// Encrypt a message using ElGamal
     pub fn encrypt_key_el_gamal(&self, sk: &SecretKey<C>) -> BlsResult<ElGamalCiphertext<C>> {
         let (c1, c2) = <C as BlsElGamal>::seal_scalar(self.0, sk.0, None, None, get_crypto_rng())?;
         Ok(ElGamalCiphertext { c1, c2 })
     }
 
+    /// Encrypt a message using ElGamal with a caller supplied blinder.
+    ///
+    /// The same blinder must never be used to encrypt more than one message
+    /// as the difference between the ciphertexts leaks the difference of
+    /// the messages. Prefer [`PublicKey::encrypt_key_el_gamal`] unless
+    /// deterministic output is required.
+    pub fn encrypt_key_el_gamal_with_blinder(
+        &self,
+        sk: &SecretKey<C>,
+        blinder: &SecretKey<C>,
+    ) -> BlsResult<ElGamalCiphertext<C>> {
+        if blinder.0.is_zero().into() {
+            return Err(BlsError::InvalidInputs("Blinder is zero".to_string()));
+        }
+        let (c1, c2) =
+            <C as BlsElGamal>::seal_scalar(self.0, sk.0, None, Some(blinder.0), get_crypto_rng())?;
+        Ok(ElGamalCiphertext { c1, c2 })
+    }
+
     /// Encrypt a message using ElGamal and generate a proof
     pub fn encrypt_key_el_gamal_with_proof(&self, sk: &SecretKey<C>) -> BlsResult<ElGamalProof<C>> {
         let (c1, c2, message_proof, blinder_proof, challenge) =
@@ -124,12 +221,259 @@ impl<C: BlsSignatureImpl> PublicKey<C> {
         })
     }
 
+    /// Encrypt a curve point using ElGamal
+    ///
+    /// Decrypting with [`ElGamalCiphertext::decrypt`] returns the point itself.
+    pub fn encrypt_point_el_gamal(
+        &self,
+        point: <C as Pairing>::PublicKey,
+    ) -> BlsResult<ElGamalCiphertext<C>> {
+        let (c1, c2) = <C as BlsElGamal>::seal_point(self.0, point, None, get_crypto_rng())?;
+        Ok(ElGamalCiphertext { c1, c2 })
+    }
+
+    /// Encrypt a curve point using ElGamal and prove knowledge of the blinder
+    pub fn encrypt_point_el_gamal_with_proof(
+        &self,
+        point: <C as Pairing>::PublicKey,
+    ) -> BlsResult<ElGamalPointProof<C>> {
+        let (c1, c2, blinder_proof, challenge) =
+            <C as BlsElGamal>::seal_point_with_proof(self.0, point, None, get_crypto_rng())?;
+        Ok(ElGamalPointProof {
+            ciphertext: ElGamalCiphertext { c1, c2 },
+            blinder_proof,
+            challenge,
+        })
+    }
+
+    /// Compute the public keys for many secret keys at once.
+    ///
+    /// Builds a fixed-base table for the generator once and reuses it
+    /// for every key, which is much faster than independent scalar
+    /// multiplications when generating large key sets.
+    pub fn batch_from_secret_keys(sks: &[SecretKey<C>]) -> Vec<PublicKey<C>> {
+        if sks.len() < 4 {
+            return sks.iter().map(PublicKey::from).collect();
+        }
+        let table = FixedBaseTable::new(<C as Pairing>::PublicKey::generator());
+        sks.iter().map(|sk| Self(table.mul(&sk.0))).collect()
+    }
+
+    /// Precompute the affine form of this public key for repeated verifications
+    pub fn to_affine(&self) -> AffinePublicKey<C> {
+        AffinePublicKey::from(self)
+    }
+
+    /// Hash `msg` to a curve point once so it can be verified against
+    /// many signatures or signature shares without hashing again
+    pub fn hash_message<B: AsRef<[u8]>>(
+        &self,
+        scheme: SignatureSchemes,
+        msg: B,
+    ) -> HashedMessage<C> {
+        HashedMessage::new(scheme, self, msg)
+    }
+
+    /// Precompute the pairing inputs of this public key for repeated verifications
+    pub fn prepare(&self) -> PreparedPublicKey<C> {
+        PreparedPublicKey::from(self)
+    }
+
+    /// Create a public key from its affine form
+    pub fn from_affine(pk: &AffinePublicKey<C>) -> Self {
+        pk.to_projective()
+    }
+
     /// Create a public key from secret shares
     pub fn from_shares(shares: &[PublicKeyShare<C>]) -> BlsResult<Self> {
         let points = shares
             .iter()
             .map(|s| s.0)
             .collect::<Vec<<C as Pairing>::PublicKeyShare>>();
-        <C as BlsSignatureCore>::core_combine_public_key_shares(&points).map(Self)
+        <C as BlsSignatureCore>::core_combine_public_key_shares_msm(&points).map(Self)
+    }
+
+    /// Create a public key from public key shares keyed by participant
+    #[cfg(feature = "std")]
+    pub fn from_shares_map<K, S>(shares: &HashMap<K, PublicKeyShare<C>, S>) -> BlsResult<Self> {
+        Self::from_shares(&shares.values().copied().collect::<Vec<_>>())
+    }
+}
+
+/// A public key that carries which curve it belongs to.
+///
+/// Mirrors [`SecretKeyEnum`] so a public key for one implementation
+/// can't be mistakenly deserialized as the other. The serialized form is
+/// tagged with [`Bls12381`] and decoding reports which curve failed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PublicKeyEnum {
+    /// A public key in G2 for signatures in G1
+    G1(PublicKey<Bls12381G1Impl>),
+    /// A public key in G1 for signatures in G2
+    G2(PublicKey<Bls12381G2Impl>),
+}
+
+impl Serialize for PublicKeyEnum {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            PublicKeyEnum::G1(v) => (Bls12381::G1, v).serialize(s),
+            PublicKeyEnum::G2(v) => (Bls12381::G2, v).serialize(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicKeyEnum {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct PublicKeyEnumVisitor;
+
+        impl<'de> Visitor<'de> for PublicKeyEnumVisitor {
+            type Value = PublicKeyEnum;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "a tuple of the type and public key")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ee = seq
+                    .next_element::<Bls12381>()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                match ee {
+                    Bls12381::G1 => {
+                        let v = seq
+                            .next_element::<PublicKey<Bls12381G1Impl>>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                        Ok(PublicKeyEnum::G1(v))
+                    }
+                    Bls12381::G2 => {
+                        let v = seq
+                            .next_element::<PublicKey<Bls12381G2Impl>>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                        Ok(PublicKeyEnum::G2(v))
+                    }
+                }
+            }
+        }
+        d.deserialize_tuple(2, PublicKeyEnumVisitor)
+    }
+}
+
+impl Default for PublicKeyEnum {
+    fn default() -> Self {
+        Self::G1(PublicKey::default())
+    }
+}
+
+impl From<PublicKey<Bls12381G1Impl>> for PublicKeyEnum {
+    fn from(value: PublicKey<Bls12381G1Impl>) -> Self {
+        Self::G1(value)
+    }
+}
+
+impl From<PublicKey<Bls12381G2Impl>> for PublicKeyEnum {
+    fn from(value: PublicKey<Bls12381G2Impl>) -> Self {
+        Self::G2(value)
+    }
+}
+
+impl From<&PublicKeyEnum> for Vec<u8> {
+    fn from(value: &PublicKeyEnum) -> Self {
+        let (tt, mut output) = match value {
+            PublicKeyEnum::G1(v) => (Bls12381::G1, Vec::from(v)),
+            PublicKeyEnum::G2(v) => (Bls12381::G2, Vec::from(v)),
+        };
+        output.insert(0, u8::from(tt));
+        output
+    }
+}
+
+impl TryFrom<&[u8]> for PublicKeyEnum {
+    type Error = BlsError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let tt = value
+            .first()
+            .ok_or_else(|| BlsError::DeserializationError("empty input".to_string()))?;
+        let ee = Bls12381::try_from(tt)?;
+        let err = |e: BlsError| {
+            BlsError::DeserializationError(format!("invalid {} public key: {}", ee, e))
+        };
+        match ee {
+            Bls12381::G1 => {
+                let v = PublicKey::<Bls12381G1Impl>::try_from(&value[1..]).map_err(err)?;
+                Ok(PublicKeyEnum::G1(v))
+            }
+            Bls12381::G2 => {
+                let v = PublicKey::<Bls12381G2Impl>::try_from(&value[1..]).map_err(err)?;
+                Ok(PublicKeyEnum::G2(v))
+            }
+        }
+    }
+}
+
+impl_from_derivatives!(PublicKeyEnum);
+
+impl PublicKeyEnum {
+    /// The curve implementation of this public key
+    pub fn curve(&self) -> Bls12381 {
+        match self {
+            PublicKeyEnum::G1(_) => Bls12381::G1,
+            PublicKeyEnum::G2(_) => Bls12381::G2,
+        }
+    }
+
+    /// Get the big-endian byte representation of this key
+    /// prefixed with the curve tag
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        Vec::from(self)
+    }
+
+    /// Convert a big-endian representation of the public key
+    /// prefixed with the curve tag
+    pub fn from_be_bytes(bytes: &[u8]) -> BlsResult<Self> {
+        Self::try_from(bytes)
+    }
+
+    /// Verify a signature given as the compressed signature point
+    /// for `scheme` using the curve of this public key
+    pub fn verify<B: AsRef<[u8]>>(
+        &self,
+        scheme: SignatureSchemes,
+        sig_bytes: &[u8],
+        msg: B,
+    ) -> BlsResult<()> {
+        match self {
+            PublicKeyEnum::G1(pk) => verify_signature_bytes(pk, scheme, sig_bytes, msg),
+            PublicKeyEnum::G2(pk) => verify_signature_bytes(pk, scheme, sig_bytes, msg),
+        }
+    }
+}
+
+fn verify_signature_bytes<C: BlsSignatureImpl, B: AsRef<[u8]>>(
+    pk: &PublicKey<C>,
+    scheme: SignatureSchemes,
+    sig_bytes: &[u8],
+    msg: B,
+) -> BlsResult<()> {
+    let mut repr = <C as Pairing>::Signature::default().to_bytes();
+    if repr.as_ref().len() != sig_bytes.len() {
+        return Err(BlsError::InvalidInputs(format!(
+            "Invalid length, expected {}, got {}",
+            repr.as_ref().len(),
+            sig_bytes.len()
+        )));
     }
+    repr.as_mut().copy_from_slice(sig_bytes);
+    let point: Option<<C as Pairing>::Signature> =
+        <C as Pairing>::Signature::from_bytes(&repr).into();
+    let point =
+        point.ok_or_else(|| BlsError::InvalidInputs("Invalid byte sequence".to_string()))?;
+    let sig = match scheme {
+        SignatureSchemes::Basic => Signature::<C>::Basic(point),
+        SignatureSchemes::MessageAugmentation => Signature::<C>::MessageAugmentation(point),
+        SignatureSchemes::ProofOfPossession => Signature::<C>::ProofOfPossession(point),
+    };
+    sig.verify(pk, msg)
 }
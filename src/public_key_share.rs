@@ -33,6 +33,20 @@ impl<C: BlsSignatureImpl> Display for PublicKeyShare<C> {
     }
 }
 
+impl<C: BlsSignatureImpl + Eq> PartialOrd for PublicKeyShare<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by identifier first so sorted shares can be scanned for duplicates
+impl<C: BlsSignatureImpl + Eq> Ord for PublicKeyShare<C> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        scalar_cmp(&self.0.identifier().0, &other.0.identifier().0)
+            .then_with(|| Vec::<u8>::from(self).cmp(&Vec::<u8>::from(other)))
+    }
+}
+
 impl_from_derivatives_generic!(PublicKeyShare);
 
 impl<C: BlsSignatureImpl> From<&PublicKeyShare<C>> for Vec<u8> {
@@ -1,12 +1,18 @@
-use crate::helpers::{get_crypto_rng, KEYGEN_SALT};
+use crate::helpers::{
+    eip2333_lamport_pk, get_crypto_rng, hkdf_expand, hkdf_mod_r, zeroize_scalar, KEYGEN_SALT,
+    KEY_TWEAK_DST, SUBKEY_SALT,
+};
 use crate::impls::inner_types::*;
 use crate::*;
 use core::fmt::{self, Formatter};
 use rand::Rng;
 use rand_core::{CryptoRng, RngCore};
 use serde::de::{SeqAccess, Visitor};
-use subtle::CtOption;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+use subtle::{ConstantTimeEq, CtOption};
 use vsss_rs::*;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Number of bytes needed to represent the secret key
 pub const SECRET_KEY_BYTES: usize = 32;
@@ -87,7 +93,7 @@ impl From<&SecretKeyEnum> for Vec<u8> {
             SecretKeyEnum::G1(sk) => (Bls12381::G1, Vec::from(sk)),
             SecretKeyEnum::G2(sk) => (Bls12381::G2, Vec::from(sk)),
         };
-        output.insert(0, tt as u8);
+        output.insert(0, u8::from(tt));
         output
     }
 }
@@ -96,7 +102,10 @@ impl TryFrom<&[u8]> for SecretKeyEnum {
     type Error = BlsError;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        let ee = Bls12381::try_from(value[0])?;
+        let tt = value
+            .first()
+            .ok_or_else(|| BlsError::DeserializationError("empty input".to_string()))?;
+        let ee = Bls12381::try_from(tt)?;
         match ee {
             Bls12381::G1 => {
                 let sk = SecretKey::<Bls12381G1Impl>::try_from(&value[1..])?;
@@ -129,6 +138,14 @@ impl SecretKeyEnum {
         }
     }
 
+    /// Get the public key corresponding to this secret key
+    pub fn public_key(&self) -> PublicKeyEnum {
+        match self {
+            SecretKeyEnum::G1(sk) => PublicKeyEnum::G1(sk.public_key()),
+            SecretKeyEnum::G2(sk) => PublicKeyEnum::G2(sk.public_key()),
+        }
+    }
+
     /// Compute a secret key from a CS-PRNG
     pub fn random(t: Bls12381, rng: impl RngCore + CryptoRng) -> Self {
         match t {
@@ -227,7 +244,7 @@ impl SecretKeyEnum {
 /// The secret key is field element 0 < `x` < `r`
 /// where `r` is the curve order. See Section 4.3 in
 /// <https://eprint.iacr.org/2016/663.pdf>
-#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct SecretKey<C: BlsSignatureImpl>(
     /// The secret key raw value
     #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
@@ -247,7 +264,36 @@ impl<'a, C: BlsSignatureImpl> From<&'a SecretKey<C>> for [u8; SECRET_KEY_BYTES]
     }
 }
 
+impl<C: BlsSignatureImpl> ConstantTimeEq for SecretKey<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<C: BlsSignatureImpl> PartialEq for SecretKey<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C: BlsSignatureImpl> Eq for SecretKey<C> {}
+
+impl<C: BlsSignatureImpl> Zeroize for SecretKey<C> {
+    fn zeroize(&mut self) {
+        zeroize_scalar(&mut self.0);
+    }
+}
+
+impl<C: BlsSignatureImpl> Drop for SecretKey<C> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C: BlsSignatureImpl> ZeroizeOnDrop for SecretKey<C> {}
+
 impl_from_derivatives_generic!(SecretKey);
+impl_hex_generic!(SecretKey);
 
 impl<C: BlsSignatureImpl> From<&SecretKey<C>> for Vec<u8> {
     fn from(value: &SecretKey<C>) -> Self {
@@ -280,6 +326,136 @@ impl<C: BlsSignatureImpl> SecretKey<C> {
         ))
     }
 
+    /// Compute a secret key by reducing 48 bytes of output keying material
+    /// modulo the curve order.
+    ///
+    /// This is the final reduction of the IETF `KeyGen` without the HKDF step,
+    /// for interoperating with key generators that produce the OKM externally.
+    /// Unlike [`SecretKey::from_hash`] the input must already be uniformly random.
+    ///
+    /// Returns none if the OKM reduces to zero, the IETF `KeyGen` rehashes
+    /// its salt and retries in that case.
+    pub fn from_okm(okm: &[u8; 48]) -> CtOption<Self> {
+        let sk = <C as BlsSignatureCore>::scalar_from_okm(okm);
+        CtOption::new(Self(sk), !sk.is_zero())
+    }
+
+    /// Derive an independent secret key from this one bound to the `info` label.
+    ///
+    /// Unlike tree based derivation such as EIP-2333 the derivation is flat:
+    /// each label maps directly to one sub key, e.g. separating a signing key
+    /// from an encryption key that both come from one seed. The same key and
+    /// label always produce the same sub key.
+    pub fn derive_subkey(&self, info: &[u8]) -> Self {
+        use sha2::{Digest, Sha256};
+
+        let ikm = self.to_be_bytes();
+        let mut salt = SUBKEY_SALT.to_vec();
+        loop {
+            let okm = hkdf_expand(&salt, &ikm, info);
+            let sk = <C as BlsSignatureCore>::scalar_from_okm(&okm);
+            if !bool::from(sk.is_zero()) {
+                return Self(sk);
+            }
+            // Same as the IETF KeyGen, rehash the salt on the
+            // negligible chance of a zero key
+            salt = Sha256::digest(&salt).to_vec();
+        }
+    }
+
+    /// Derive the master secret key from `seed` as in EIP-2333 `derive_master_SK`.
+    ///
+    /// The seed must be at least 32 bytes.
+    pub fn derive_master(seed: &[u8]) -> BlsResult<Self> {
+        if seed.len() < 32 {
+            return Err(BlsError::InvalidInputs(
+                "seed must be at least 32 bytes".to_string(),
+            ));
+        }
+        Ok(Self(hkdf_mod_r::<C>(seed)))
+    }
+
+    /// Derive the child secret key at `index` as in EIP-2333 `derive_child_SK`
+    pub fn derive_child(&self, index: u32) -> Self {
+        let lamport_pk = eip2333_lamport_pk(&self.to_be_bytes(), index);
+        Self(hkdf_mod_r::<C>(&lamport_pk))
+    }
+
+    /// Derive the secret key for an EIP-2334 path such as `m/12381/3600/0/0/0`.
+    ///
+    /// The path must start with `m` and every other component must be a
+    /// child index. `m` alone is the master secret key.
+    pub fn derive_path(seed: &[u8], path: &str) -> BlsResult<Self> {
+        let mut components = path.split('/');
+        match components.next() {
+            Some("m") => {}
+            Some(t) => {
+                return Err(BlsError::InvalidInputs(format!(
+                    "path must start with 'm', found '{}'",
+                    t
+                )))
+            }
+            None => unreachable!("split always yields at least one item"),
+        }
+        let indices = components
+            .map(|t| {
+                let err = || BlsError::InvalidInputs(format!("invalid path component '{}'", t));
+                if !t.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(err());
+                }
+                t.parse::<u32>().map_err(|_| err())
+            })
+            .collect::<BlsResult<Vec<_>>>()?;
+        let mut sk = Self::derive_master(seed)?;
+        for index in indices {
+            sk = sk.derive_child(index);
+        }
+        Ok(sk)
+    }
+
+    /// Add the hash of `tweak` to this secret key.
+    ///
+    /// The result matches [`PublicKey::add_tweak`] applied to this key's
+    /// public key with the same tweak. Fails in the negligible case the
+    /// tweaked key is zero.
+    pub fn add_tweak(&self, tweak: &[u8]) -> BlsResult<Self> {
+        let t = <C as HashToScalar>::hash_to_scalar(tweak, KEY_TWEAK_DST);
+        let sk = self.0 + t;
+        if sk.is_zero().into() {
+            return Err(BlsError::InvalidInputs(
+                "tweaked secret key is zero".to_string(),
+            ));
+        }
+        Ok(Self(sk))
+    }
+
+    /// Add `other` to this secret key.
+    ///
+    /// The public key of the result is the sum of both public keys.
+    /// Fails if the sum is zero, e.g. when adding a key's negation.
+    pub fn checked_add(&self, other: &Self) -> BlsResult<Self> {
+        let sk = self.0 + other.0;
+        if sk.is_zero().into() {
+            return Err(BlsError::InvalidInputs(
+                "sum of secret keys is zero".to_string(),
+            ));
+        }
+        Ok(Self(sk))
+    }
+
+    /// Subtract `other` from this secret key.
+    ///
+    /// Fails if the difference is zero, i.e. both keys are the same.
+    pub fn checked_sub(&self, other: &Self) -> BlsResult<Self> {
+        let sk = self.0 - other.0;
+        if sk.is_zero().into() {
+            return Err(BlsError::InvalidInputs(
+                "difference of secret keys is zero".to_string(),
+            ));
+        }
+        Ok(Self(sk))
+    }
+
     /// Compute a secret key from a CS-PRNG
     pub fn random(mut rng: impl RngCore + CryptoRng) -> Self {
         Self(<C as HashToScalar>::hash_to_scalar(
@@ -310,6 +486,10 @@ impl<C: BlsSignatureImpl> SecretKey<C> {
 
     /// Secret share this key by creating `limit` shares where `threshold` are required
     /// to combine back into this secret
+    ///
+    /// The shares are returned in order with identifiers `1..=limit`. Identifiers
+    /// are full scalars so `limit` is not bound to 255, and any `threshold` shares
+    /// can be combined regardless of gaps between their identifiers.
     pub fn split(&self, threshold: usize, limit: usize) -> BlsResult<Vec<SecretKeyShare<C>>> {
         self.split_with_rng(threshold, limit, get_crypto_rng())
     }
@@ -331,6 +511,89 @@ impl<C: BlsSignatureImpl> SecretKey<C> {
         Ok(shares)
     }
 
+    /// Secret share this key with one share for each of `identifiers` where
+    /// `threshold` are required to combine back into this secret.
+    ///
+    /// Use when participants own fixed identifiers such as node ids
+    /// instead of the sequential `1..=limit`.
+    pub fn split_with_identifiers(
+        &self,
+        threshold: usize,
+        identifiers: &[IdentifierPrimeField<<<C as Pairing>::PublicKey as Group>::Scalar>],
+        rng: impl RngCore + CryptoRng,
+    ) -> BlsResult<Vec<SecretKeyShare<C>>> {
+        if threshold < 2 || threshold > identifiers.len() {
+            return Err(BlsError::InvalidInputs(format!(
+                "invalid threshold {} for {} shares",
+                threshold,
+                identifiers.len()
+            )));
+        }
+        check_identifiers(identifiers.iter())?;
+        if self.0.is_zero().into() {
+            return Err(BlsError::InvalidInputs("secret key is zero".to_string()));
+        }
+        let secret = IdentifierPrimeField(self.0);
+        let shares =
+            shamir::split_secret_with_participant_generator::<<C as Pairing>::SecretKeyShare>(
+                threshold,
+                identifiers.len(),
+                &secret,
+                rng,
+                &[ParticipantIdGeneratorType::list(identifiers)],
+            )?
+            .into_iter()
+            .map(SecretKeyShare)
+            .collect::<Vec<_>>();
+        Ok(shares)
+    }
+
+    /// Secret share this key like [`SecretKey::split`] and also return the
+    /// [`SharingParams`] needed to validate the shares when combining
+    pub fn split_with_params(
+        &self,
+        threshold: usize,
+        limit: usize,
+    ) -> BlsResult<(Vec<SecretKeyShare<C>>, SharingParams<C>)> {
+        let shares = self.split(threshold, limit)?;
+        let params = SharingParams {
+            threshold,
+            limit,
+            group_public_key: self.public_key(),
+        };
+        Ok((shares, params))
+    }
+
+    /// Secret share this key like [`SecretKey::split_with_rng`] and also return
+    /// a [`DealerTranscript`] that can later be used to audit the public key shares
+    pub fn split_with_transcript(
+        &self,
+        threshold: usize,
+        limit: usize,
+        rng: impl RngCore + CryptoRng,
+    ) -> BlsResult<(Vec<SecretKeyShare<C>>, DealerTranscript<C>)> {
+        if threshold < 2 || threshold > limit {
+            return Err(BlsError::InvalidInputs(format!(
+                "invalid threshold {} for {} shares",
+                threshold, limit
+            )));
+        }
+        if self.0.is_zero().into() {
+            return Err(BlsError::InvalidInputs("secret key is zero".to_string()));
+        }
+        let secret = IdentifierPrimeField(self.0);
+        let (shares, verifiers) = feldman::split_secret::<
+            <C as Pairing>::SecretKeyShare,
+            ValueGroup<<C as Pairing>::PublicKey>,
+        >(threshold, limit, &secret, None, rng)?;
+        // The first verifier is the generator, the rest commit to the coefficients
+        let transcript = DealerTranscript {
+            threshold,
+            commitments: verifiers[1..].iter().map(|v| PublicKey(v.0)).collect(),
+        };
+        Ok((shares.into_iter().map(SecretKeyShare).collect(), transcript))
+    }
+
     /// Reconstruct a secret from shares created from `split`
     pub fn combine(shares: &[SecretKeyShare<C>]) -> BlsResult<Self> {
         let ss = shares.iter().map(|s| s.0.clone()).collect::<Vec<_>>();
@@ -338,6 +601,35 @@ impl<C: BlsSignatureImpl> SecretKey<C> {
         Ok(Self(secret.0))
     }
 
+    /// Reconstruct a secret from shares keyed by participant
+    #[cfg(feature = "std")]
+    pub fn combine_map<K, S>(shares: &HashMap<K, SecretKeyShare<C>, S>) -> BlsResult<Self> {
+        Self::combine(&shares.values().cloned().collect::<Vec<_>>())
+    }
+
+    /// Reconstruct a secret from shares created by [`SecretKey::split_with_params`].
+    ///
+    /// Fails if fewer than `params.threshold` shares are given, any identifiers
+    /// are repeated, or the result doesn't match `params.group_public_key`.
+    pub fn combine_with_params(
+        shares: &[SecretKeyShare<C>],
+        params: &SharingParams<C>,
+    ) -> BlsResult<Self> {
+        if shares.len() < params.threshold {
+            return Err(BlsError::InsufficientShares(params.threshold, shares.len()));
+        }
+        let ss = shares.iter().map(|s| s.0.clone()).collect::<Vec<_>>();
+        check_share_identifiers(&ss)?;
+        let secret = ss.combine()?;
+        let sk = Self(secret.0);
+        if sk.public_key().0 != params.group_public_key.0 {
+            return Err(BlsError::InvalidInputs(
+                "combined secret key does not match the group public key".to_string(),
+            ));
+        }
+        Ok(sk)
+    }
+
     /// Compute the public key
     pub fn public_key(&self) -> PublicKey<C> {
         PublicKey(<C as BlsSignatureCore>::public_key(&self.0))
@@ -350,6 +642,19 @@ impl<C: BlsSignatureImpl> SecretKey<C> {
         )?))
     }
 
+    /// Get the proof of possession bound to `context`, e.g. a chain id or epoch,
+    /// so the proof cannot be replayed in a different context.
+    ///
+    /// An empty context produces the same proof as [`SecretKey::proof_of_possession`].
+    pub fn proof_of_possession_with_context(
+        &self,
+        context: &[u8],
+    ) -> BlsResult<ProofOfPossession<C>> {
+        Ok(ProofOfPossession(
+            <C as BlsSignaturePop>::pop_prove_with_context(&self.0, context)?,
+        ))
+    }
+
     /// Sign a message with this secret key using the specified scheme
     pub fn sign(&self, scheme: SignatureSchemes, msg: &[u8]) -> BlsResult<Signature<C>> {
         match scheme {
@@ -368,6 +673,78 @@ impl<C: BlsSignatureImpl> SecretKey<C> {
         }
     }
 
+    /// Sign a message with this secret key and verify the result before
+    /// returning it.
+    ///
+    /// Costs a verification per signature but stops a signature corrupted
+    /// by a fault during signing from ever leaving the signer.
+    pub fn sign_verified(&self, scheme: SignatureSchemes, msg: &[u8]) -> BlsResult<Signature<C>> {
+        let sig = self.sign(scheme, msg)?;
+        sig.verify(&self.public_key(), msg)
+            .map_err(|_| BlsError::SigningError("self-check failed".to_string()))?;
+        Ok(sig)
+    }
+
+    /// Sign a message with this secret key using the specified scheme
+    /// if the message length is allowed by `policy`
+    pub fn sign_with_policy(
+        &self,
+        scheme: SignatureSchemes,
+        msg: &[u8],
+        policy: SignPolicy,
+    ) -> BlsResult<Signature<C>> {
+        policy.check(msg)?;
+        self.sign(scheme, msg)
+    }
+
+    /// Sign a message bound to a specific slot with this secret key using the specified scheme.
+    ///
+    /// The slot is prepended as a fixed width big-endian value to the message
+    /// which is signed in a dedicated slot domain like [`Self::sign_in_domain`],
+    /// so a regular signature never verifies as a slot signature or vice versa.
+    /// Use [`Signature::verify_with_slot`] to verify.
+    pub fn sign_with_slot(
+        &self,
+        scheme: SignatureSchemes,
+        slot: u64,
+        msg: &[u8],
+    ) -> BlsResult<Signature<C>> {
+        self.sign_in_domain(scheme, SLOT_DOMAIN, &slot_message(slot, msg))
+    }
+
+    /// Sign a message in an isolated `domain`, e.g. a chain identifier.
+    ///
+    /// The scheme's domain separation tag is extended with a hash of `domain`
+    /// so a signature made in one domain never verifies in another or as a
+    /// regular signature. Use [`Signature::verify_in_domain`] to verify.
+    pub fn sign_in_domain(
+        &self,
+        scheme: SignatureSchemes,
+        domain: &[u8],
+        msg: &[u8],
+    ) -> BlsResult<Signature<C>> {
+        let dst = domain_dst(scheme_dst::<C>(scheme), domain);
+        match scheme {
+            SignatureSchemes::Basic => {
+                let inner = <C as BlsSignatureCore>::core_sign(&self.0, msg, dst)?;
+                Ok(Signature::Basic(inner))
+            }
+            SignatureSchemes::MessageAugmentation => {
+                let mut overhead = <C as BlsSignatureMessageAugmentation>::pk_bytes(
+                    self.public_key().0,
+                    msg.len(),
+                );
+                overhead.extend_from_slice(msg);
+                let inner = <C as BlsSignatureCore>::core_sign(&self.0, overhead, dst)?;
+                Ok(Signature::MessageAugmentation(inner))
+            }
+            SignatureSchemes::ProofOfPossession => {
+                let inner = <C as BlsSignatureCore>::core_sign(&self.0, msg, dst)?;
+                Ok(Signature::ProofOfPossession(inner))
+            }
+        }
+    }
+
     /// Create a Signcrypt decryption key where the secret key is hidden
     /// that can decrypt ciphertext
     pub fn sign_decryption_key<B: AsRef<[u8]>>(
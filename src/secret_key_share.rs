@@ -1,5 +1,10 @@
+use crate::impls::inner_types::*;
 use crate::*;
+use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
+use subtle::ConstantTimeEq;
+use vsss_rs::{shamir, ParticipantIdGeneratorType};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// A secret key share is field element 0 < `x` < `r`
 /// where `r` is the curve order.
@@ -9,7 +14,7 @@ use serde::{Deserialize, Serialize};
 /// to produce the completed key, or used for
 /// creating partial signatures which can be
 /// combined into a complete signature
-#[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SecretKeyShare<C: BlsSignatureImpl>(
     #[serde(serialize_with = "traits::secret_key_share::serialize::<C, _>")]
     #[serde(deserialize_with = "traits::secret_key_share::deserialize::<C, _>")]
@@ -22,6 +27,49 @@ impl<C: BlsSignatureImpl> Clone for SecretKeyShare<C> {
     }
 }
 
+impl<C: BlsSignatureImpl> ConstantTimeEq for SecretKeyShare<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.identifier().0.ct_eq(&other.0.identifier().0)
+            & self.0.value().0.ct_eq(&other.0.value().0)
+    }
+}
+
+impl<C: BlsSignatureImpl> PartialEq for SecretKeyShare<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C: BlsSignatureImpl> Eq for SecretKeyShare<C> {}
+
+impl<C: BlsSignatureImpl> Zeroize for SecretKeyShare<C> {
+    fn zeroize(&mut self) {
+        zeroize_scalar(&mut self.0.value_mut().0);
+    }
+}
+
+impl<C: BlsSignatureImpl> Drop for SecretKeyShare<C> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C: BlsSignatureImpl> ZeroizeOnDrop for SecretKeyShare<C> {}
+
+impl<C: BlsSignatureImpl + Eq> PartialOrd for SecretKeyShare<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by identifier first so sorted shares can be scanned for duplicates
+impl<C: BlsSignatureImpl + Eq> Ord for SecretKeyShare<C> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        scalar_cmp(&self.0.identifier().0, &other.0.identifier().0)
+            .then_with(|| Vec::<u8>::from(self).cmp(&Vec::<u8>::from(other)))
+    }
+}
+
 impl_from_derivatives_generic!(SecretKeyShare);
 
 impl<C: BlsSignatureImpl> From<&SecretKeyShare<C>> for Vec<u8> {
@@ -46,6 +94,94 @@ impl<C: BlsSignatureImpl> SecretKeyShare<C> {
         )?))
     }
 
+    /// Check this share was dealt from the polynomial committed to in
+    /// `commitment` by comparing `g^share` with the commitments
+    /// evaluated at the share identifier
+    pub fn verify_feldman(&self, commitment: &FeldmanCommitment<C>) -> BlsResult<()> {
+        commitment.check_commitments()?;
+        let id = self.0.identifier();
+        if id.0.is_zero().into() {
+            return Err(BlsError::InvalidInputs(
+                "secret key share has a zero identifier".to_string(),
+            ));
+        }
+        let commitments = commitment
+            .commitments
+            .iter()
+            .map(|c| c.0)
+            .collect::<Vec<_>>();
+        let pk = <C as BlsSignatureCore>::public_key(&self.0.value().0);
+        if evaluate_commitments(&commitments, id.0) != pk {
+            return Err(BlsError::InvalidInputs(
+                "secret key share does not match the commitment".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Refresh a set of shares by adding a random sharing of zero.
+    ///
+    /// The shares keep their identifiers and still combine to the same
+    /// secret, but the new values are independent of the old ones so
+    /// shares leaked before the refresh can't be combined with shares
+    /// leaked after it. `threshold` must be the threshold the shares
+    /// were created with.
+    pub fn refresh(
+        shares: &[SecretKeyShare<C>],
+        threshold: usize,
+        rng: impl RngCore + CryptoRng,
+    ) -> BlsResult<Vec<SecretKeyShare<C>>> {
+        if threshold < 2 {
+            return Err(BlsError::InvalidInputs(format!(
+                "invalid threshold {}",
+                threshold
+            )));
+        }
+        if shares.len() < threshold {
+            return Err(BlsError::InsufficientShares(threshold, shares.len()));
+        }
+        let inner = shares.iter().map(|s| s.0.clone()).collect::<Vec<_>>();
+        check_share_identifiers(&inner)?;
+        let identifiers = inner.iter().map(|s| *s.identifier()).collect::<Vec<_>>();
+        let zero = shamir::split_secret_with_participant_generator::<<C as Pairing>::SecretKeyShare>(
+            threshold,
+            identifiers.len(),
+            &IdentifierPrimeField(<<C as Pairing>::PublicKey as Group>::Scalar::ZERO),
+            rng,
+            &[ParticipantIdGeneratorType::list(&identifiers)],
+        )?;
+        let refreshed = inner
+            .iter()
+            .zip(zero.iter())
+            .map(|(s, z)| {
+                SecretKeyShare(<C as Pairing>::SecretKeyShare::with_identifier_and_value(
+                    *s.identifier(),
+                    IdentifierPrimeField(s.value().0 + z.value().0),
+                ))
+            })
+            .collect();
+        Ok(refreshed)
+    }
+
+    /// Remove repeated copies of the same share so the rest can be combined.
+    ///
+    /// Fails with [`BlsError::DuplicateShareIdentifier`] if two shares with
+    /// the same identifier have different values.
+    pub fn dedup_by_identifier(shares: &[SecretKeyShare<C>]) -> BlsResult<Vec<SecretKeyShare<C>>> {
+        let mut output = Vec::<SecretKeyShare<C>>::with_capacity(shares.len());
+        for share in shares {
+            match output
+                .iter()
+                .find(|s| s.0.identifier() == share.0.identifier())
+            {
+                Some(s) if s.0.value().0 == share.0.value().0 => {}
+                Some(_) => return Err(BlsError::DuplicateShareIdentifier),
+                None => output.push(share.clone()),
+            }
+        }
+        Ok(output)
+    }
+
     /// Sign a message with this secret key using the specified scheme
     pub fn sign<B: AsRef<[u8]>>(
         &self,
@@ -65,12 +201,61 @@ impl<C: BlsSignatureImpl> SecretKeyShare<C> {
         }
     }
 
+    /// Sign a message like [`SecretKeyShare::sign`] and prove the signature
+    /// share was created with this secret key share.
+    ///
+    /// Fails with [`BlsError::InvalidSignatureScheme`] for message
+    /// augmentation which has no threshold signatures.
+    pub fn sign_with_proof<B: AsRef<[u8]>>(
+        &self,
+        scheme: SignatureSchemes,
+        msg: B,
+    ) -> BlsResult<(SignatureShare<C>, ShareCorrectnessProof<C>)> {
+        if scheme == SignatureSchemes::MessageAugmentation {
+            return Err(BlsError::InvalidSignatureScheme);
+        }
+        let sig = self.sign(scheme, msg.as_ref())?;
+        let h = <C as HashToPoint>::hash_to_point(msg, scheme_dst::<C>(scheme));
+        let sk = self.0.value().0;
+        let proof = ShareCorrectnessProof::new(
+            sk,
+            <C as BlsSignatureCore>::public_key(&sk),
+            h,
+            sig.as_raw_value().value().0,
+            get_crypto_rng(),
+        );
+        Ok((sig, proof))
+    }
+
+    /// Create a decryption share for `ciphertext` with a proof that it was
+    /// computed with this secret key share
+    pub fn elgamal_decryption_share(
+        &self,
+        ciphertext: &ElGamalCiphertext<C>,
+    ) -> BlsResult<ElGamalDecryptionShare<C>> {
+        let share =
+            <C as BlsSignatureCore>::public_key_share_with_generator(&self.0, ciphertext.c1)?;
+        let sk = self.0.value().0;
+        let proof = ElGamalDecryptionProof::new(
+            sk,
+            <C as BlsSignatureCore>::public_key(&sk),
+            ciphertext.c1,
+            share.value().0,
+            get_crypto_rng(),
+        );
+        Ok(ElGamalDecryptionShare(share, Some(proof)))
+    }
+
     /// Extract the inner raw representation
     pub fn as_raw_value(&self) -> &<C as Pairing>::SecretKeyShare {
         &self.0
     }
 
     /// Convert secret share from SecretKeyShare v1 to the newer v2 format
+    ///
+    /// The v1 format stores the identifier in a single byte so it can only
+    /// represent shares with identifiers up to 255. The v2 format uses
+    /// full scalar identifiers and has no such limit.
     pub fn from_v1_bytes(bytes: &[u8]) -> BlsResult<Self> {
         #[derive(Deserialize)]
         struct V1(#[serde(deserialize_with = "fixed_arr::BigArray::deserialize")] [u8; 33]);
@@ -92,4 +277,38 @@ impl<C: BlsSignatureImpl> SecretKeyShare<C> {
             identifier, value,
         )))
     }
+
+    /// Convert a v1 share whose identifier is stored in `width`
+    /// little-endian bytes instead of one
+    ///
+    /// Use this for legacy shares with identifiers larger than 255 that
+    /// [`Self::from_v1_bytes`] cannot represent.
+    pub fn from_v1_bytes_with_identifier_width(bytes: &[u8], width: usize) -> BlsResult<Self> {
+        let mut repr =
+            <<<C as Pairing>::PublicKey as Group>::Scalar as PrimeField>::Repr::default();
+        let value_len = repr.as_ref().len();
+        let len = width.checked_add(value_len).ok_or_else(|| {
+            BlsError::InvalidInputs(format!("identifier width {} is too large", width))
+        })?;
+        if bytes.len() != len {
+            return Err(BlsError::InvalidInputs(format!(
+                "v1 share with a {} byte identifier must be {} bytes, found {}",
+                width,
+                len,
+                bytes.len()
+            )));
+        }
+        let identifier = IdentifierPrimeField(identifier_from_le_bytes::<
+            <<C as Pairing>::PublicKey as Group>::Scalar,
+        >(&bytes[..width])?);
+        repr.as_mut().copy_from_slice(&bytes[width..]);
+        let inner_value = Option::<<<C as Pairing>::PublicKey as Group>::Scalar>::from(
+            <<C as Pairing>::PublicKey as Group>::Scalar::from_repr(repr),
+        )
+        .ok_or_else(|| BlsError::InvalidInputs("Invalid scalar".to_string()))?;
+        let value = IdentifierPrimeField(inner_value);
+        Ok(Self(C::SecretKeyShare::with_identifier_and_value(
+            identifier, value,
+        )))
+    }
 }
@@ -0,0 +1,84 @@
+//! Serialize public keys and signatures as `0x` prefixed hex strings in
+//! human readable formats as Ethereum JSON APIs expect, e.g.
+//! `#[serde(with = "blsful::serde_0x")]`.
+//!
+//! Binary formats use the type's own compact encoding.
+
+use crate::impls::inner_types::*;
+use crate::*;
+use serde::de::{DeserializeOwned, Error as _};
+use serde::ser::Error as _;
+
+/// Types that can be written as `0x` prefixed hex
+pub trait Hex0x: Sized {
+    /// The bytes written after the `0x` prefix
+    fn to_0x_bytes(&self) -> BlsResult<Vec<u8>>;
+
+    /// Read the bytes written by [`Hex0x::to_0x_bytes`]
+    fn from_0x_bytes(bytes: &[u8]) -> BlsResult<Self>;
+}
+
+/// The compressed public key
+impl<C: BlsSignatureImpl> Hex0x for PublicKey<C> {
+    fn to_0x_bytes(&self) -> BlsResult<Vec<u8>> {
+        Ok(Vec::from(self))
+    }
+
+    fn from_0x_bytes(bytes: &[u8]) -> BlsResult<Self> {
+        Self::try_from(bytes)
+    }
+}
+
+/// The compressed signature without the scheme.
+///
+/// Only proof of possession signatures can be written, as used by Ethereum,
+/// since the scheme is needed to read them back.
+impl<C: BlsSignatureImpl> Hex0x for Signature<C> {
+    fn to_0x_bytes(&self) -> BlsResult<Vec<u8>> {
+        match self {
+            Self::ProofOfPossession(sig) => Ok(sig.to_bytes().as_ref().to_vec()),
+            _ => Err(BlsError::InvalidSignatureScheme),
+        }
+    }
+
+    fn from_0x_bytes(bytes: &[u8]) -> BlsResult<Self> {
+        let mut repr = <C as Pairing>::Signature::default().to_bytes();
+        if repr.as_ref().len() != bytes.len() {
+            return Err(BlsError::InvalidInputs(format!(
+                "Invalid length, expected {}, got {}",
+                repr.as_ref().len(),
+                bytes.len()
+            )));
+        }
+        repr.as_mut().copy_from_slice(bytes);
+        Option::<<C as Pairing>::Signature>::from(<C as Pairing>::Signature::from_bytes(&repr))
+            .map(Self::ProofOfPossession)
+            .ok_or_else(|| BlsError::InvalidInputs("Invalid byte sequence".to_string()))
+    }
+}
+
+/// Serialize as `0x` prefixed hex in human readable formats
+pub fn serialize<T: Hex0x + Serialize, S: Serializer>(value: &T, s: S) -> Result<S::Ok, S::Error> {
+    if s.is_human_readable() {
+        let bytes = value.to_0x_bytes().map_err(S::Error::custom)?;
+        s.serialize_str(&format!("0x{}", hex::encode(bytes)))
+    } else {
+        value.serialize(s)
+    }
+}
+
+/// Deserialize from `0x` prefixed hex in human readable formats
+pub fn deserialize<'de, T: Hex0x + DeserializeOwned, D: Deserializer<'de>>(
+    d: D,
+) -> Result<T, D::Error> {
+    if d.is_human_readable() {
+        let s = String::deserialize(d)?;
+        let s = s
+            .strip_prefix("0x")
+            .ok_or_else(|| D::Error::custom("missing 0x prefix"))?;
+        let bytes = hex::decode(s).map_err(D::Error::custom)?;
+        T::from_0x_bytes(&bytes).map_err(D::Error::custom)
+    } else {
+        T::deserialize(d)
+    }
+}
@@ -0,0 +1,65 @@
+//! Serialize a value as a single byte string in binary formats, e.g.
+//! `#[serde(with = "blsful::serde_byte_string")]`.
+//!
+//! Formats like CBOR and MessagePack write fixed size arrays as a list of
+//! integers, one per byte. This writes the bytes from `Vec::from` as serde
+//! `bytes` instead so they are encoded compactly. Human readable formats
+//! use the type's own encoding.
+
+use crate::*;
+use serde::de::{DeserializeOwned, Error as _, SeqAccess, Visitor};
+
+/// Serialize as a byte string in binary formats
+pub fn serialize<T, S>(value: &T, s: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    for<'a> Vec<u8>: From<&'a T>,
+    S: Serializer,
+{
+    if s.is_human_readable() {
+        value.serialize(s)
+    } else {
+        let bytes = zeroize::Zeroizing::new(Vec::from(value));
+        s.serialize_bytes(bytes.as_slice())
+    }
+}
+
+/// Deserialize from a byte string in binary formats
+pub fn deserialize<'de, T, D>(d: D) -> Result<T, D::Error>
+where
+    T: DeserializeOwned + for<'a> TryFrom<&'a [u8], Error = BlsError>,
+    D: Deserializer<'de>,
+{
+    if d.is_human_readable() {
+        return T::deserialize(d);
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = zeroize::Zeroizing<Vec<u8>>;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "a byte string")
+        }
+
+        fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(zeroize::Zeroizing::new(v.to_vec()))
+        }
+
+        fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(zeroize::Zeroizing::new(v))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = zeroize::Zeroizing::new(Vec::new());
+            while let Some(b) = seq.next_element()? {
+                bytes.push(b);
+            }
+            Ok(bytes)
+        }
+    }
+
+    let bytes = d.deserialize_bytes(BytesVisitor)?;
+    T::try_from(bytes.as_slice()).map_err(D::Error::custom)
+}
@@ -0,0 +1,125 @@
+use crate::impls::inner_types::*;
+use crate::*;
+use rand_core::{CryptoRng, RngCore};
+
+const SALT: &[u8] = b"BLS_SIG_SHARE_CORRECTNESS_PROOF_";
+
+/// A Chaum-Pedersen proof that a signature share was created with the
+/// same secret key share as its public key share.
+///
+/// Proves the discrete log of the public key share with respect to the
+/// public key generator equals the discrete log of the signature share
+/// with respect to the hashed message, so a malformed share is caught
+/// before it is combined.
+#[derive(PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShareCorrectnessProof<C: BlsSignatureImpl> {
+    /// The fiat-shamir heuristic challenge
+    #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::scalar::deserialize::<C, _>")]
+    pub challenge: <<C as Pairing>::PublicKey as Group>::Scalar,
+    /// The response to the challenge
+    #[serde(serialize_with = "traits::scalar::serialize::<C, _>")]
+    #[serde(deserialize_with = "traits::scalar::deserialize::<C, _>")]
+    pub response: <<C as Pairing>::PublicKey as Group>::Scalar,
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for ShareCorrectnessProof<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ShareCorrectnessProof {{ challenge: {:?}, response: {:?} }}",
+            self.challenge, self.response
+        )
+    }
+}
+
+impl<C: BlsSignatureImpl> Copy for ShareCorrectnessProof<C> {}
+
+impl<C: BlsSignatureImpl> Clone for ShareCorrectnessProof<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: BlsSignatureImpl> From<&ShareCorrectnessProof<C>> for Vec<u8> {
+    fn from(value: &ShareCorrectnessProof<C>) -> Self {
+        serde_bare::to_vec(value).expect("failed to serialize ShareCorrectnessProof")
+    }
+}
+
+impl<C: BlsSignatureImpl> TryFrom<&[u8]> for ShareCorrectnessProof<C> {
+    type Error = BlsError;
+
+    fn try_from(value: &[u8]) -> BlsResult<Self> {
+        let proof = serde_bare::from_slice(value)?;
+        Ok(proof)
+    }
+}
+
+impl_from_derivatives_generic!(ShareCorrectnessProof);
+
+impl<C: BlsSignatureImpl> ShareCorrectnessProof<C> {
+    /// Prove `pk = g^x` and `sig = h^x` for the secret key share `x`
+    pub(crate) fn new(
+        sk: <<C as Pairing>::PublicKey as Group>::Scalar,
+        pk: <C as Pairing>::PublicKey,
+        h: <C as Pairing>::Signature,
+        sig: <C as Pairing>::Signature,
+        mut rng: impl RngCore + CryptoRng,
+    ) -> Self {
+        let k = <<C as Pairing>::PublicKey as Group>::Scalar::random(&mut rng);
+        let t1 = <C as Pairing>::PublicKey::generator() * k;
+        let t2 = h * k;
+        let challenge = Self::compute_challenge(pk, h, sig, t1, t2);
+        Self {
+            challenge,
+            response: k + challenge * sk,
+        }
+    }
+
+    /// Check the proof for `pk = g^x` and `sig = h^x`
+    pub(crate) fn verify(
+        &self,
+        pk: <C as Pairing>::PublicKey,
+        h: <C as Pairing>::Signature,
+        sig: <C as Pairing>::Signature,
+    ) -> BlsResult<()> {
+        if (pk.is_identity() | sig.is_identity()).into() {
+            return Err(BlsError::InvalidInputs(
+                "public key or signature share is the identity point".to_string(),
+            ));
+        }
+        if (self.challenge.is_zero() | self.response.is_zero()).into() {
+            return Err(BlsError::InvalidInputs("Proof values are zero".to_string()));
+        }
+        let t1 = <C as Pairing>::PublicKey::generator() * self.response - pk * self.challenge;
+        let t2 = h * self.response - sig * self.challenge;
+        if Self::compute_challenge(pk, h, sig, t1, t2) != self.challenge {
+            return Err(BlsError::InvalidProof);
+        }
+        Ok(())
+    }
+
+    fn compute_challenge(
+        pk: <C as Pairing>::PublicKey,
+        h: <C as Pairing>::Signature,
+        sig: <C as Pairing>::Signature,
+        t1: <C as Pairing>::PublicKey,
+        t2: <C as Pairing>::Signature,
+    ) -> <<C as Pairing>::PublicKey as Group>::Scalar {
+        let mut transcript = merlin::Transcript::new(b"ShareCorrectnessProof");
+        transcript.append_message(b"dst", SALT);
+        transcript.append_message(
+            b"base point",
+            <C as Pairing>::PublicKey::generator().to_bytes().as_ref(),
+        );
+        transcript.append_message(b"pk", pk.to_bytes().as_ref());
+        transcript.append_message(b"h", h.to_bytes().as_ref());
+        transcript.append_message(b"sig", sig.to_bytes().as_ref());
+        transcript.append_message(b"t1", t1.to_bytes().as_ref());
+        transcript.append_message(b"t2", t2.to_bytes().as_ref());
+        let mut challenge = [0u8; 64];
+        transcript.challenge_bytes(b"challenge", &mut challenge);
+        <C as BlsElGamal>::scalar_from_bytes_wide(&challenge)
+    }
+}
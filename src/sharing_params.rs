@@ -0,0 +1,55 @@
+use crate::*;
+
+/// The parameters used when splitting a secret key.
+///
+/// Returned by [`SecretKey::split_with_params`] so the threshold and
+/// group public key can be kept with the shares and checked when
+/// the shares are combined again.
+#[derive(PartialEq, Eq, Serialize, Deserialize)]
+pub struct SharingParams<C: BlsSignatureImpl> {
+    /// The number of shares required to reconstruct the secret
+    pub threshold: usize,
+    /// The number of shares created
+    pub limit: usize,
+    /// The public key of the secret that was split
+    #[serde(bound(
+        serialize = "PublicKey<C>: Serialize",
+        deserialize = "PublicKey<C>: Deserialize<'de>"
+    ))]
+    pub group_public_key: PublicKey<C>,
+}
+
+impl<C: BlsSignatureImpl> Copy for SharingParams<C> {}
+
+impl<C: BlsSignatureImpl> Clone for SharingParams<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for SharingParams<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SharingParams {{ threshold: {}, limit: {}, group_public_key: {:?} }}",
+            self.threshold, self.limit, self.group_public_key
+        )
+    }
+}
+
+impl_from_derivatives_generic!(SharingParams);
+
+impl<C: BlsSignatureImpl> From<&SharingParams<C>> for Vec<u8> {
+    fn from(value: &SharingParams<C>) -> Self {
+        serde_bare::to_vec(value).expect("failed to serialize sharing params")
+    }
+}
+
+impl<C: BlsSignatureImpl> TryFrom<&[u8]> for SharingParams<C> {
+    type Error = BlsError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let output = serde_bare::from_slice(value)?;
+        Ok(output)
+    }
+}
@@ -1,4 +1,8 @@
 use crate::BlsError;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 
 /// The BLS signature algorithm schemes
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -87,3 +91,48 @@ impl<'de> serde::Deserialize<'de> for SignatureSchemes {
         }
     }
 }
+
+/// A policy restricting the length of messages that may be signed
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SignPolicy {
+    /// The minimum message length in bytes
+    pub min_len: usize,
+    /// The maximum message length in bytes
+    pub max_len: usize,
+}
+
+impl Default for SignPolicy {
+    fn default() -> Self {
+        Self {
+            min_len: 0,
+            max_len: usize::MAX,
+        }
+    }
+}
+
+impl SignPolicy {
+    /// A policy that rejects empty messages
+    pub const NON_EMPTY: Self = Self {
+        min_len: 1,
+        max_len: usize::MAX,
+    };
+
+    /// Check the message is allowed by this policy
+    pub fn check(&self, msg: &[u8]) -> Result<(), BlsError> {
+        if msg.len() < self.min_len {
+            return Err(BlsError::InvalidInputs(format!(
+                "message length {} is less than the minimum {}",
+                msg.len(),
+                self.min_len
+            )));
+        }
+        if msg.len() > self.max_len {
+            return Err(BlsError::InvalidInputs(format!(
+                "message length {} is greater than the maximum {}",
+                msg.len(),
+                self.max_len
+            )));
+        }
+        Ok(())
+    }
+}
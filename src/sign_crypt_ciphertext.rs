@@ -46,6 +46,89 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for SignCryptCiphertext<C> {
 impl_from_derivatives_generic!(SignCryptCiphertext);
 
 impl<C: BlsSignatureImpl> SignCryptCiphertext<C> {
+    /// The signature group of `C` which tags [`SignCryptCiphertext::to_bytes`].
+    ///
+    /// The tag identifies the group only, so implementations sharing a
+    /// group like the XOF variants also share a tag.
+    pub fn curve() -> Bls12381 {
+        C::CURVE
+    }
+
+    /// Get the self-describing encoding
+    /// `curve || scheme || u || len(v) || v || w` where the curve and scheme
+    /// are one byte each, the points are compressed and the length of `v` is
+    /// an 8 byte big endian integer
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut output = Vec::with_capacity(
+            10 + <C as Pairing>::PUBLIC_KEY_BYTES + self.v.len() + <C as Pairing>::SIGNATURE_BYTES,
+        );
+        output.push(u8::from(Self::curve()));
+        output.push(self.scheme as u8);
+        output.extend_from_slice(self.u.to_bytes().as_ref());
+        output.extend_from_slice(&(self.v.len() as u64).to_be_bytes());
+        output.extend_from_slice(&self.v);
+        output.extend_from_slice(self.w.to_bytes().as_ref());
+        output
+    }
+
+    /// Read the encoding produced by [`SignCryptCiphertext::to_bytes`]
+    ///
+    /// Fails if the encoding is for the other group.
+    pub fn from_bytes(bytes: &[u8]) -> BlsResult<Self> {
+        let pk_len = <C as Pairing>::PUBLIC_KEY_BYTES;
+        let sig_len = <C as Pairing>::SIGNATURE_BYTES;
+        if bytes.len() < 10 + pk_len + sig_len {
+            return Err(BlsError::DeserializationError(format!(
+                "sign crypt ciphertext must be at least {} bytes, found {}",
+                10 + pk_len + sig_len,
+                bytes.len()
+            )));
+        }
+        if Bls12381::try_from(bytes[0])? != Self::curve() {
+            return Err(BlsError::DeserializationError(
+                "sign crypt ciphertext is for a different curve".to_string(),
+            ));
+        }
+        let scheme = match bytes[1] {
+            0..=2 => SignatureSchemes::from(bytes[1]),
+            _ => {
+                return Err(BlsError::DeserializationError(
+                    "Invalid signature scheme".to_string(),
+                ))
+            }
+        };
+        let (u, rest) = bytes[2..].split_at(pk_len);
+        let (v_len, rest) = rest.split_at(8);
+        let v_len = u64::from_be_bytes(<[u8; 8]>::try_from(v_len).expect("8 bytes"));
+        if v_len != (rest.len() - sig_len) as u64 {
+            return Err(BlsError::DeserializationError(format!(
+                "sign crypt ciphertext length is {} but {} bytes remain",
+                v_len,
+                rest.len() - sig_len
+            )));
+        }
+        let (v, w) = rest.split_at(rest.len() - sig_len);
+
+        let mut u_repr = <C as Pairing>::PublicKey::default().to_bytes();
+        u_repr.as_mut().copy_from_slice(u);
+        let u = Option::<<C as Pairing>::PublicKey>::from(<C as Pairing>::PublicKey::from_bytes(
+            &u_repr,
+        ))
+        .ok_or_else(|| BlsError::DeserializationError("Invalid u".to_string()))?;
+        let mut w_repr = <C as Pairing>::Signature::default().to_bytes();
+        w_repr.as_mut().copy_from_slice(w);
+        let w = Option::<<C as Pairing>::Signature>::from(<C as Pairing>::Signature::from_bytes(
+            &w_repr,
+        ))
+        .ok_or_else(|| BlsError::DeserializationError("Invalid w".to_string()))?;
+        Ok(Self {
+            u,
+            v: v.to_vec(),
+            w,
+            scheme,
+        })
+    }
+
     /// Create a decryption share from a secret key share
     pub fn create_decryption_share(
         &self,
@@ -61,43 +144,42 @@ impl<C: BlsSignatureImpl> SignCryptCiphertext<C> {
         &self,
         shares: B,
     ) -> CtOption<Vec<u8>> {
-        let dst = match self.scheme {
-            SignatureSchemes::Basic => <C as BlsSignatureBasic>::DST,
-            SignatureSchemes::MessageAugmentation => <C as BlsSignatureMessageAugmentation>::DST,
-            SignatureSchemes::ProofOfPossession => <C as BlsSignaturePop>::SIG_DST,
-        };
+        let dst = scheme_dst::<C>(self.scheme);
 
         let shares = shares.as_ref().iter().map(|s| s.0).collect::<Vec<_>>();
         <C as BlsSignCrypt>::unseal_with_shares(self.u, &self.v, self.w, shares.as_slice(), dst)
     }
 
     /// Decrypt the signcrypt ciphertext
+    ///
+    /// The plaintext is only valid if the returned `Choice` is set.
     pub fn decrypt(&self, sk: &SecretKey<C>) -> CtOption<Vec<u8>> {
-        let dst = match self.scheme {
-            SignatureSchemes::Basic => <C as BlsSignatureBasic>::DST,
-            SignatureSchemes::MessageAugmentation => <C as BlsSignatureMessageAugmentation>::DST,
-            SignatureSchemes::ProofOfPossession => <C as BlsSignaturePop>::SIG_DST,
-        };
+        let dst = scheme_dst::<C>(self.scheme);
 
         <C as BlsSignCrypt>::unseal(self.u, &self.v, self.w, &sk.0, dst)
     }
 
+    /// Decrypt a signcrypt ciphertext created with
+    /// [`PublicKey::sign_crypt_with_aad`]
+    ///
+    /// Fails if `aad` is not the associated data used to encrypt.
+    pub fn decrypt_with_aad<A: AsRef<[u8]>>(&self, sk: &SecretKey<C>, aad: A) -> CtOption<Vec<u8>> {
+        let dst = scheme_dst::<C>(self.scheme);
+
+        <C as BlsSignCrypt>::unseal_with_aad(self.u, &self.v, self.w, &sk.0, aad.as_ref(), dst)
+    }
+
+    /// Check if the ciphertext is valid for the associated data
+    pub fn is_valid_with_aad<A: AsRef<[u8]>>(&self, aad: A) -> Choice {
+        let dst = scheme_dst::<C>(self.scheme);
+
+        <C as BlsSignCrypt>::valid_with_aad(self.u, &self.v, self.w, aad.as_ref(), dst)
+    }
+
     /// Check if the ciphertext is valid
     pub fn is_valid(&self) -> Choice {
-        match self.scheme {
-            SignatureSchemes::Basic => {
-                <C as BlsSignCrypt>::valid(self.u, &self.v, self.w, <C as BlsSignatureBasic>::DST)
-            }
-            SignatureSchemes::MessageAugmentation => <C as BlsSignCrypt>::valid(
-                self.u,
-                &self.v,
-                self.w,
-                <C as BlsSignatureMessageAugmentation>::DST,
-            ),
-            SignatureSchemes::ProofOfPossession => {
-                <C as BlsSignCrypt>::valid(self.u, &self.v, self.w, <C as BlsSignaturePop>::SIG_DST)
-            }
-        }
+        let dst = scheme_dst::<C>(self.scheme);
+        <C as BlsSignCrypt>::valid(self.u, &self.v, self.w, dst)
     }
 }
 
@@ -142,11 +224,7 @@ impl_from_derivatives_generic!(SignCryptDecryptionKey);
 impl<C: BlsSignatureImpl> SignCryptDecryptionKey<C> {
     /// Decrypt signcrypt ciphertext
     pub fn decrypt(&self, ciphertext: &SignCryptCiphertext<C>) -> CtOption<Vec<u8>> {
-        let dst = match ciphertext.scheme {
-            SignatureSchemes::Basic => <C as BlsSignatureBasic>::DST,
-            SignatureSchemes::MessageAugmentation => <C as BlsSignatureMessageAugmentation>::DST,
-            SignatureSchemes::ProofOfPossession => <C as BlsSignaturePop>::SIG_DST,
-        };
+        let dst = scheme_dst::<C>(ciphertext.scheme);
 
         let choice = <C as BlsSignCrypt>::valid(ciphertext.u, &ciphertext.v, ciphertext.w, dst);
         <C as BlsSignCrypt>::decrypt(&ciphertext.v, self.0, choice)
@@ -0,0 +1,206 @@
+use crate::impls::inner_types::*;
+use crate::*;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+#[cfg(feature = "std")]
+use std::io;
+
+type Shake128Reader = <Shake128 as ExtendableOutput>::Reader;
+
+/// Encrypts a signcrypt message given in chunks.
+///
+/// This saves joining the chunks into one plaintext buffer but it is not
+/// a streaming encryptor: `w` hashes all of `v` to a curve point and the
+/// backends only hash complete inputs, so the encrypted `v` is held in
+/// full until [`SignCryptWriter::finalize`]. The output is identical to
+/// [`PublicKey::sign_crypt`] and can be opened with
+/// [`SignCryptCiphertext::decrypt`] or [`SignCryptReader`].
+pub struct SignCryptWriter<C: BlsSignatureImpl> {
+    scheme: SignatureSchemes,
+    r: <<C as Pairing>::PublicKey as Group>::Scalar,
+    u: <C as Pairing>::PublicKey,
+    keystream: Shake128Reader,
+    v: Vec<u8>,
+    remaining: usize,
+}
+
+impl<C: BlsSignatureImpl> Drop for SignCryptWriter<C> {
+    fn drop(&mut self) {
+        zeroize_scalar(&mut self.r);
+    }
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for SignCryptWriter<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SignCryptWriter {{ scheme: {:?}, u: {:?}, written: {}, remaining: {} }}",
+            self.scheme,
+            self.u,
+            self.v.len(),
+            self.remaining
+        )
+    }
+}
+
+impl<C: BlsSignatureImpl> SignCryptWriter<C> {
+    /// Start encrypting a message of exactly `len` bytes to `pk`
+    ///
+    /// The length is needed up front because it prefixes the message.
+    pub fn new(pk: &PublicKey<C>, scheme: SignatureSchemes, len: usize) -> Self {
+        let r = <<C as Pairing>::PublicKey as Group>::Scalar::random(get_crypto_rng());
+        debug_assert_eq!(r.is_zero().unwrap_u8(), 0u8);
+        let u = <C as Pairing>::PublicKey::generator() * r;
+        let mut hasher = Shake128::default();
+        hasher.update((pk.0 * r).to_bytes().as_ref());
+        let mut writer = Self {
+            scheme,
+            r,
+            u,
+            keystream: hasher.finalize_xof(),
+            v: Vec::new(),
+            remaining: 0,
+        };
+        writer.encrypt(&uint_zigzag::Uint::from(len).to_vec());
+        writer.remaining = len;
+        writer
+    }
+
+    /// Encrypt the next chunk of the message
+    pub fn update(&mut self, chunk: &[u8]) -> BlsResult<()> {
+        if chunk.len() > self.remaining {
+            return Err(BlsError::InvalidInputs(format!(
+                "chunk of {} bytes exceeds the {} bytes remaining",
+                chunk.len(),
+                self.remaining
+            )));
+        }
+        self.encrypt(chunk);
+        self.remaining -= chunk.len();
+        Ok(())
+    }
+
+    /// Produce the ciphertext once the whole message has been written
+    pub fn finalize(mut self) -> BlsResult<SignCryptCiphertext<C>> {
+        if self.remaining != 0 {
+            return Err(BlsError::InvalidInputs(format!(
+                "message is missing {} bytes",
+                self.remaining
+            )));
+        }
+        // Always use at least 32 bytes
+        if self.v.len() < 32 {
+            let padding = [0u8; 32];
+            let len = 32 - self.v.len();
+            self.encrypt(&padding[..len]);
+        }
+        let dst = scheme_dst::<C>(self.scheme);
+        let w = <C as BlsSignCrypt>::compute_w(self.u, &self.v, dst) * self.r;
+        Ok(SignCryptCiphertext {
+            u: self.u,
+            v: core::mem::take(&mut self.v),
+            w,
+            scheme: self.scheme,
+        })
+    }
+
+    fn encrypt(&mut self, plaintext: &[u8]) {
+        let start = self.v.len();
+        self.v.resize(start + plaintext.len(), 0u8);
+        let out = &mut self.v[start..];
+        self.keystream.read(out);
+        out.iter_mut().zip(plaintext).for_each(|(o, p)| *o ^= p);
+    }
+}
+
+/// Decrypts a signcrypt ciphertext in chunks.
+///
+/// The ciphertext is checked when the reader is created and the plaintext
+/// is produced as it is read, so it never has to be held in memory. The
+/// whole ciphertext must already be in memory to be checked.
+pub struct SignCryptReader<'a, C: BlsSignatureImpl> {
+    ciphertext: &'a SignCryptCiphertext<C>,
+    keystream: Shake128Reader,
+    pending: Vec<u8>,
+    offset: usize,
+    remaining: usize,
+}
+
+impl<'a, C: BlsSignatureImpl> fmt::Debug for SignCryptReader<'a, C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SignCryptReader {{ offset: {}, remaining: {} }}",
+            self.offset,
+            self.remaining + self.pending.len()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, C: BlsSignatureImpl> io::Read for SignCryptReader<'a, C> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if !self.pending.is_empty() {
+            let n = buf.len().min(self.pending.len());
+            buf[..n].copy_from_slice(&self.pending[..n]);
+            self.pending.drain(..n);
+            return Ok(n);
+        }
+        let n = buf.len().min(self.remaining);
+        let out = &mut buf[..n];
+        self.keystream.read(out);
+        out.iter_mut()
+            .zip(&self.ciphertext.v[self.offset..self.offset + n])
+            .for_each(|(o, c)| *o ^= c);
+        self.offset += n;
+        self.remaining -= n;
+        Ok(n)
+    }
+}
+
+impl<'a, C: BlsSignatureImpl> SignCryptReader<'a, C> {
+    /// Check `ciphertext` and prepare to decrypt it with `sk`
+    pub fn new(ciphertext: &'a SignCryptCiphertext<C>, sk: &SecretKey<C>) -> BlsResult<Self> {
+        if !bool::from(ciphertext.is_valid()) {
+            return Err(BlsError::InvalidInputs("invalid ciphertext".to_string()));
+        }
+        let mut hasher = Shake128::default();
+        hasher.update((ciphertext.u * sk.0).to_bytes().as_ref());
+        let mut keystream = hasher.finalize_xof();
+
+        // The length prefix is at most 10 bytes
+        let mut header = [0u8; 10];
+        let header_len = header.len().min(ciphertext.v.len());
+        let header = &mut header[..header_len];
+        keystream.read(header);
+        header
+            .iter_mut()
+            .zip(&ciphertext.v)
+            .for_each(|(h, c)| *h ^= c);
+        let overhead = uint_zigzag::Uint::peek(header)
+            .ok_or_else(|| BlsError::InvalidInputs("invalid length prefix".to_string()))?;
+        let len = uint_zigzag::Uint::try_from(&header[..overhead])
+            .map_err(|_| BlsError::InvalidInputs("invalid length prefix".to_string()))?
+            .0 as usize;
+        if len > ciphertext.v.len() - overhead {
+            return Err(BlsError::InvalidInputs(
+                "length prefix exceeds the ciphertext".to_string(),
+            ));
+        }
+        let pending = header[overhead..header_len.min(overhead + len)].to_vec();
+        Ok(Self {
+            ciphertext,
+            keystream,
+            remaining: len - pending.len(),
+            pending,
+            offset: header_len,
+        })
+    }
+
+    /// The number of plaintext bytes left to read
+    pub fn remaining(&self) -> usize {
+        self.remaining + self.pending.len()
+    }
+}
@@ -1,5 +1,10 @@
+use crate::impls::inner_types::*;
 use crate::*;
-use subtle::ConditionallySelectable;
+use rand_core::{CryptoRng, RngCore};
+use serde::de::{SeqAccess, Visitor};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
 
 /// A BLS signature wrapped in the appropriate scheme used to generate it
 #[derive(PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -77,7 +82,21 @@ impl<C: BlsSignatureImpl> ConditionallySelectable for Signature<C> {
     }
 }
 
+impl<C: BlsSignatureImpl> ConstantTimeEq for Signature<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // The scheme is public so only the point comparison needs to be constant time
+        let same_scheme = Choice::from(self.same_scheme(other) as u8);
+        same_scheme
+            & self
+                .as_raw_value()
+                .to_bytes()
+                .as_ref()
+                .ct_eq(other.as_raw_value().to_bytes().as_ref())
+    }
+}
+
 impl_from_derivatives_generic!(Signature);
+impl_hex_generic!(Signature);
 
 impl<C: BlsSignatureImpl> From<&Signature<C>> for Vec<u8> {
     fn from(value: &Signature<C>) -> Self {
@@ -85,6 +104,9 @@ impl<C: BlsSignatureImpl> From<&Signature<C>> for Vec<u8> {
     }
 }
 
+/// Decodes the signature with the same point checks as the underlying
+/// curve library. Use [`Signature::try_from_validated`] to also reject
+/// the identity.
 impl<C: BlsSignatureImpl> TryFrom<&[u8]> for Signature<C> {
     type Error = BlsError;
 
@@ -94,6 +116,30 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for Signature<C> {
 }
 
 impl<C: BlsSignatureImpl> Signature<C> {
+    /// Decode a signature from untrusted bytes and check the point is
+    /// a valid signature.
+    ///
+    /// Malformed bytes or a point outside the prime order subgroup fail
+    /// with [`BlsError::InvalidInputs`] like `try_from`, and the identity
+    /// fails with [`BlsError::InvalidSignature`].
+    pub fn try_from_validated(value: &[u8]) -> BlsResult<Self> {
+        let sig = Self::try_from(value)?;
+        let point = sig.as_raw_value();
+        if (point.is_identity() | !<C as Pairing>::signature_is_torsion_free(point)).into() {
+            return Err(BlsError::InvalidSignature);
+        }
+        Ok(sig)
+    }
+
+    /// The signature scheme used by this value
+    pub fn scheme(&self) -> SignatureSchemes {
+        match self {
+            Self::Basic(_) => SignatureSchemes::Basic,
+            Self::MessageAugmentation(_) => SignatureSchemes::MessageAugmentation,
+            Self::ProofOfPossession(_) => SignatureSchemes::ProofOfPossession,
+        }
+    }
+
     /// Verify the signature using the public key
     pub fn verify<B: AsRef<[u8]>>(&self, pk: &PublicKey<C>, msg: B) -> BlsResult<()> {
         match self {
@@ -105,6 +151,141 @@ impl<C: BlsSignatureImpl> Signature<C> {
         }
     }
 
+    /// Verify the signature using the public key, reusing any point
+    /// `hasher` already computed for the message
+    pub fn verify_with_hasher(
+        &self,
+        pk: &PublicKey<C>,
+        hasher: &mut MessageHasher<'_, C>,
+    ) -> BlsResult<()> {
+        let a = hasher.scheme_point(self.scheme(), pk);
+        <C as BlsSignatureCore>::core_verify_hashed(pk.0, *self.as_raw_value(), a)
+    }
+
+    /// Verify the signature against a message that was already hashed with
+    /// [`PublicKey::hash_message`]
+    pub fn verify_prehashed(&self, pk: &PublicKey<C>, msg: &HashedMessage<C>) -> BlsResult<()> {
+        if self.scheme() != msg.scheme() {
+            return Err(BlsError::InvalidSignatureScheme);
+        }
+        <C as BlsSignatureCore>::core_verify_hashed(pk.0, *self.as_raw_value(), *msg.as_raw_value())
+    }
+
+    /// Verify the signature was created by [`SecretKey::sign_with_slot`]
+    /// for the given slot and message
+    pub fn verify_with_slot<B: AsRef<[u8]>>(
+        &self,
+        pk: &PublicKey<C>,
+        slot: u64,
+        msg: B,
+    ) -> BlsResult<()> {
+        self.verify_in_domain(pk, SLOT_DOMAIN, slot_message(slot, msg.as_ref()))
+    }
+
+    /// Verify the signature was created by [`SecretKey::sign_in_domain`]
+    /// for the given domain and message
+    pub fn verify_in_domain<B: AsRef<[u8]>>(
+        &self,
+        pk: &PublicKey<C>,
+        domain: &[u8],
+        msg: B,
+    ) -> BlsResult<()> {
+        let dst = domain_dst(scheme_dst::<C>(self.scheme()), domain);
+        match self {
+            Self::Basic(sig) | Self::ProofOfPossession(sig) => {
+                <C as BlsSignatureCore>::core_verify(pk.0, *sig, msg, dst)
+            }
+            Self::MessageAugmentation(sig) => {
+                let mut overhead =
+                    <C as BlsSignatureMessageAugmentation>::pk_bytes(pk.0, msg.as_ref().len());
+                overhead.extend_from_slice(msg.as_ref());
+                <C as BlsSignatureCore>::core_verify(pk.0, *sig, overhead, dst)
+            }
+        }
+    }
+
+    /// The `FastAggregateVerify` algorithm from the IETF draft and the Ethereum
+    /// consensus specification.
+    ///
+    /// Verifies a signature from multiple public keys on the same message.
+    /// Only defined for the proof of possession scheme and fails if `pks`
+    /// is empty or any public key is the identity.
+    pub fn fast_aggregate_verify<B: AsRef<[u8]>>(
+        &self,
+        pks: &[PublicKey<C>],
+        msg: B,
+    ) -> BlsResult<()> {
+        let sig = match self {
+            Self::ProofOfPossession(sig) => *sig,
+            _ => return Err(BlsError::InvalidSignatureScheme),
+        };
+        if pks.is_empty() {
+            return Err(BlsError::InvalidInputs(
+                "no public keys to verify".to_string(),
+            ));
+        }
+        if pks.iter().any(|pk| pk.0.is_identity().into()) {
+            return Err(BlsError::InvalidInputs(
+                "public key is the identity point".to_string(),
+            ));
+        }
+        <C as BlsSignaturePop>::multi_sig_verify(pks.iter().map(|pk| pk.0), sig, msg)
+    }
+
+    /// Verify many independent signatures that all use the same scheme in a single pass.
+    ///
+    /// Each signature is weighted by a random scalar drawn from `rng` so a
+    /// single multi-pairing replaces one pairing check per signature.
+    /// Fails if `items` is empty, the schemes differ, or any signature is invalid.
+    pub fn verify_batch(
+        items: &[(PublicKey<C>, Signature<C>, &[u8])],
+        rng: impl RngCore + CryptoRng,
+    ) -> BlsResult<()> {
+        if items.windows(2).any(|w| !w[0].1.same_scheme(&w[1].1)) {
+            return Err(BlsError::InvalidSignatureScheme);
+        }
+        let items = items
+            .iter()
+            .map(|(pk, sig, msg)| (*pk, *msg, *sig))
+            .collect::<Vec<_>>();
+        Self::batch_verify_mixed_with_rng(&items, rng)
+    }
+
+    /// Verify a batch of signatures that may use different schemes in a single pass.
+    ///
+    /// Each signature is hashed with the domain separation tag of its scheme
+    /// then all signatures are checked together using a random linear combination.
+    /// Fails if `items` is empty or any signature is invalid.
+    pub fn batch_verify_mixed(items: &[(PublicKey<C>, &[u8], Signature<C>)]) -> BlsResult<()> {
+        Self::batch_verify_mixed_with_rng(items, get_crypto_rng())
+    }
+
+    /// Verify a batch of signatures that may use different schemes in a single pass
+    /// using a specified RNG for the random linear combination
+    pub fn batch_verify_mixed_with_rng(
+        items: &[(PublicKey<C>, &[u8], Signature<C>)],
+        rng: impl RngCore + CryptoRng,
+    ) -> BlsResult<()> {
+        let ii = items.iter().map(|(pk, msg, sig)| match sig {
+            Self::Basic(sig) => (pk.0, *sig, msg.to_vec(), <C as BlsSignatureBasic>::DST),
+            Self::MessageAugmentation(sig) => {
+                let mut overhead =
+                    <C as BlsSignatureMessageAugmentation>::pk_bytes(pk.0, msg.len());
+                overhead.extend_from_slice(msg);
+                (
+                    pk.0,
+                    *sig,
+                    overhead,
+                    <C as BlsSignatureMessageAugmentation>::DST,
+                )
+            }
+            Self::ProofOfPossession(sig) => {
+                (pk.0, *sig, msg.to_vec(), <C as BlsSignaturePop>::SIG_DST)
+            }
+        });
+        <C as BlsSignatureCore>::core_batch_verify(ii, rng)
+    }
+
     /// Determine if two signature were signed using the same scheme
     pub fn same_scheme(&self, &other: &Self) -> bool {
         matches!(
@@ -115,16 +296,32 @@ impl<C: BlsSignatureImpl> Signature<C> {
         )
     }
 
+    /// Check this signature equals an `expected` capability token
+    /// in constant time.
+    ///
+    /// Use this instead of `==` when the expected signature acts as
+    /// a shared secret so the comparison doesn't leak timing.
+    pub fn verify_token(&self, expected: &Self) -> BlsResult<()> {
+        if !self.same_scheme(expected) {
+            return Err(BlsError::InvalidSignatureScheme);
+        }
+        if self.ct_eq(expected).into() {
+            Ok(())
+        } else {
+            Err(BlsError::InvalidSignature)
+        }
+    }
+
     /// Create a signature from shares
     pub fn from_shares(shares: &[SignatureShare<C>]) -> BlsResult<Self> {
-        if !shares.iter().skip(1).all(|s| s.same_scheme(&shares[0])) {
+        if !shares.windows(2).all(|w| w[0].same_scheme(&w[1])) {
             return Err(BlsError::InvalidSignatureScheme);
         }
         let points = shares
             .iter()
             .map(|s| *s.as_raw_value())
             .collect::<Vec<<C as Pairing>::SignatureShare>>();
-        let sig = <C as BlsSignatureCore>::core_combine_signature_shares(&points)?;
+        let sig = <C as BlsSignatureCore>::core_combine_signature_shares_msm(&points)?;
         match shares[0] {
             SignatureShare::Basic(_) => Ok(Self::Basic(sig)),
             SignatureShare::MessageAugmentation(_) => Ok(Self::MessageAugmentation(sig)),
@@ -132,6 +329,30 @@ impl<C: BlsSignatureImpl> Signature<C> {
         }
     }
 
+    /// Create a signature from shares like [`Signature::from_shares`] but fail
+    /// early if fewer than `threshold` shares with distinct identifiers are
+    /// given instead of producing a signature that won't verify.
+    ///
+    /// Repeated copies of the same share are only counted once.
+    pub fn from_shares_checked(shares: &[SignatureShare<C>], threshold: usize) -> BlsResult<Self> {
+        let shares = SignatureShare::dedup_by_identifier(shares)?;
+        if shares.len() < threshold {
+            return Err(BlsError::InvalidInputs(format!(
+                "expected at least {} shares with distinct identifiers, got {}",
+                threshold,
+                shares.len()
+            )));
+        }
+        Self::from_shares(&shares)
+    }
+
+    /// Create a signature from shares keyed by participant, e.g. as collected
+    /// by a threshold coordinator
+    #[cfg(feature = "std")]
+    pub fn from_shares_map<K, S>(shares: &HashMap<K, SignatureShare<C>, S>) -> BlsResult<Self> {
+        Self::from_shares(&shares.values().copied().collect::<Vec<_>>())
+    }
+
     /// Extract the inner raw representation
     pub fn as_raw_value(&self) -> &<C as Pairing>::Signature {
         match self {
@@ -140,6 +361,174 @@ impl<C: BlsSignatureImpl> Signature<C> {
             Self::ProofOfPossession(s) => s,
         }
     }
+
+    /// Hash `msg` to a point in the signature group using the domain
+    /// separation tag of `scheme`, e.g. for protocols built on BLS like
+    /// VRFs or distributed randomness.
+    ///
+    /// Signing `msg` under the basic or proof of possession schemes
+    /// multiplies this point by the secret key. Message augmentation
+    /// signs the public key followed by the message, use
+    /// [`Signature::hash_to_point_augmented`] for that point.
+    pub fn hash_to_point<B: AsRef<[u8]>>(
+        scheme: SignatureSchemes,
+        msg: B,
+    ) -> <C as Pairing>::Signature {
+        <C as HashToPoint>::hash_to_point(msg, scheme_dst::<C>(scheme))
+    }
+
+    /// Hash `msg` to the point signed under message augmentation, which
+    /// prefixes `pk` to the message
+    pub fn hash_to_point_augmented<B: AsRef<[u8]>>(
+        pk: &PublicKey<C>,
+        msg: B,
+    ) -> <C as Pairing>::Signature {
+        let msg = msg.as_ref();
+        let mut overhead = <C as BlsSignatureMessageAugmentation>::pk_bytes(pk.0, msg.len());
+        overhead.extend_from_slice(msg);
+        <C as HashToPoint>::hash_to_point(
+            &overhead,
+            scheme_dst::<C>(SignatureSchemes::MessageAugmentation),
+        )
+    }
+}
+
+/// A signature that carries which curve it belongs to.
+///
+/// Mirrors [`SecretKeyEnum`] so a signature for one implementation
+/// can't be mistakenly deserialized as the other. The serialized form is
+/// tagged with [`Bls12381`] and decoding reports which curve failed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SignatureEnum {
+    /// A signature in G1
+    G1(Signature<Bls12381G1Impl>),
+    /// A signature in G2
+    G2(Signature<Bls12381G2Impl>),
+}
+
+impl Serialize for SignatureEnum {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SignatureEnum::G1(v) => (Bls12381::G1, v).serialize(s),
+            SignatureEnum::G2(v) => (Bls12381::G2, v).serialize(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SignatureEnum {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct SignatureEnumVisitor;
+
+        impl<'de> Visitor<'de> for SignatureEnumVisitor {
+            type Value = SignatureEnum;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "a tuple of the type and signature")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ee = seq
+                    .next_element::<Bls12381>()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                match ee {
+                    Bls12381::G1 => {
+                        let v = seq
+                            .next_element::<Signature<Bls12381G1Impl>>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                        Ok(SignatureEnum::G1(v))
+                    }
+                    Bls12381::G2 => {
+                        let v = seq
+                            .next_element::<Signature<Bls12381G2Impl>>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                        Ok(SignatureEnum::G2(v))
+                    }
+                }
+            }
+        }
+        d.deserialize_tuple(2, SignatureEnumVisitor)
+    }
+}
+
+impl Default for SignatureEnum {
+    fn default() -> Self {
+        Self::G1(Signature::default())
+    }
+}
+
+impl From<Signature<Bls12381G1Impl>> for SignatureEnum {
+    fn from(value: Signature<Bls12381G1Impl>) -> Self {
+        Self::G1(value)
+    }
+}
+
+impl From<Signature<Bls12381G2Impl>> for SignatureEnum {
+    fn from(value: Signature<Bls12381G2Impl>) -> Self {
+        Self::G2(value)
+    }
+}
+
+impl From<&SignatureEnum> for Vec<u8> {
+    fn from(value: &SignatureEnum) -> Self {
+        let (tt, mut output) = match value {
+            SignatureEnum::G1(v) => (Bls12381::G1, Vec::from(v)),
+            SignatureEnum::G2(v) => (Bls12381::G2, Vec::from(v)),
+        };
+        output.insert(0, u8::from(tt));
+        output
+    }
+}
+
+impl TryFrom<&[u8]> for SignatureEnum {
+    type Error = BlsError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let tt = value
+            .first()
+            .ok_or_else(|| BlsError::DeserializationError("empty input".to_string()))?;
+        let ee = Bls12381::try_from(tt)?;
+        let err = |e: BlsError| {
+            BlsError::DeserializationError(format!("invalid {} signature: {}", ee, e))
+        };
+        match ee {
+            Bls12381::G1 => {
+                let v = Signature::<Bls12381G1Impl>::try_from(&value[1..]).map_err(err)?;
+                Ok(SignatureEnum::G1(v))
+            }
+            Bls12381::G2 => {
+                let v = Signature::<Bls12381G2Impl>::try_from(&value[1..]).map_err(err)?;
+                Ok(SignatureEnum::G2(v))
+            }
+        }
+    }
+}
+
+impl_from_derivatives!(SignatureEnum);
+
+impl SignatureEnum {
+    /// The curve implementation of this signature
+    pub fn curve(&self) -> Bls12381 {
+        match self {
+            SignatureEnum::G1(_) => Bls12381::G1,
+            SignatureEnum::G2(_) => Bls12381::G2,
+        }
+    }
+
+    /// Verify the signature using the public key.
+    ///
+    /// Fails if the public key is for the other curve.
+    pub fn verify<B: AsRef<[u8]>>(&self, pk: &PublicKeyEnum, msg: B) -> BlsResult<()> {
+        match (self, pk) {
+            (SignatureEnum::G1(sig), PublicKeyEnum::G1(pk)) => sig.verify(pk, msg),
+            (SignatureEnum::G2(sig), PublicKeyEnum::G2(pk)) => sig.verify(pk, msg),
+            _ => Err(BlsError::InvalidInputs(
+                "public key is for a different curve".to_string(),
+            )),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,85 @@
+use crate::impls::inner_types::*;
+use crate::*;
+
+/// Aggregates signatures as they arrive instead of collecting them first.
+///
+/// Only the running sum and the count are kept so votes can be folded
+/// in straight off the network.
+pub struct SignatureAggregator<C: BlsSignatureImpl> {
+    scheme: SignatureSchemes,
+    sum: <C as Pairing>::Signature,
+    count: usize,
+}
+
+impl<C: BlsSignatureImpl> Copy for SignatureAggregator<C> {}
+
+impl<C: BlsSignatureImpl> Clone for SignatureAggregator<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for SignatureAggregator<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SignatureAggregator {{ scheme: {:?}, sum: {:?}, count: {} }}",
+            self.scheme, self.sum, self.count
+        )
+    }
+}
+
+impl<C: BlsSignatureImpl> SignatureAggregator<C> {
+    /// Create an empty aggregator for signatures using `scheme`
+    pub fn new(scheme: SignatureSchemes) -> Self {
+        Self {
+            scheme,
+            sum: <C as Pairing>::Signature::identity(),
+            count: 0,
+        }
+    }
+
+    /// The signature scheme this aggregator accepts
+    pub fn scheme(&self) -> SignatureSchemes {
+        self.scheme
+    }
+
+    /// Add a signature to the aggregate
+    pub fn push(&mut self, sig: Signature<C>) -> BlsResult<()> {
+        if sig.scheme() != self.scheme {
+            return Err(BlsError::InvalidSignatureScheme);
+        }
+        self.sum += sig.as_raw_value();
+        self.count += 1;
+        Ok(())
+    }
+
+    /// The number of signatures added so far
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Whether no signatures have been added yet
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Produce the aggregate signature
+    ///
+    /// Fails like [`AggregateSignature::from_signatures`] if no signatures
+    /// were added.
+    pub fn finalize(self) -> BlsResult<AggregateSignature<C>> {
+        if self.is_empty() {
+            return Err(BlsError::InvalidInputs(
+                "no signatures provided".to_string(),
+            ));
+        }
+        Ok(match self.scheme {
+            SignatureSchemes::Basic => AggregateSignature::Basic(self.sum),
+            SignatureSchemes::MessageAugmentation => {
+                AggregateSignature::MessageAugmentation(self.sum)
+            }
+            SignatureSchemes::ProofOfPossession => AggregateSignature::ProofOfPossession(self.sum),
+        })
+    }
+}
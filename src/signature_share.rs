@@ -1,4 +1,6 @@
+use crate::impls::inner_types::*;
 use crate::*;
+use rand_core::{CryptoRng, RngCore};
 
 /// Represents a share of a signature
 #[derive(PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -64,6 +66,23 @@ impl<C: BlsSignatureImpl> subtle::ConditionallySelectable for SignatureShare<C>
     }
 }
 
+impl<C: BlsSignatureImpl + Eq> PartialOrd for SignatureShare<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by identifier first so sorted shares can be scanned for duplicates
+impl<C: BlsSignatureImpl + Eq> Ord for SignatureShare<C> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        scalar_cmp(
+            &self.as_raw_value().identifier().0,
+            &other.as_raw_value().identifier().0,
+        )
+        .then_with(|| Vec::<u8>::from(self).cmp(&Vec::<u8>::from(other)))
+    }
+}
+
 impl_from_derivatives_generic!(SignatureShare);
 
 impl<C: BlsSignatureImpl> From<&SignatureShare<C>> for Vec<u8> {
@@ -96,11 +115,152 @@ impl<C: BlsSignatureImpl> TryFrom<&[u8]> for SignatureShare<C> {
 }
 
 impl<C: BlsSignatureImpl> SignatureShare<C> {
+    /// The signature scheme used by this value
+    pub fn scheme(&self) -> SignatureSchemes {
+        match self {
+            Self::Basic(_) => SignatureSchemes::Basic,
+            Self::MessageAugmentation(_) => SignatureSchemes::MessageAugmentation,
+            Self::ProofOfPossession(_) => SignatureSchemes::ProofOfPossession,
+        }
+    }
+
+    /// Create a signature share from its parts as received from a protocol
+    /// that frames shares its own way.
+    ///
+    /// Fails if the identifier is zero or the point is the identity or not
+    /// in the prime order subgroup.
+    pub fn from_parts(
+        scheme: SignatureSchemes,
+        identifier: IdentifierPrimeField<<<C as Pairing>::Signature as Group>::Scalar>,
+        point: <C as Pairing>::Signature,
+    ) -> BlsResult<Self> {
+        if identifier.0.is_zero().into() {
+            return Err(BlsError::InvalidInputs("identifier is zero".to_string()));
+        }
+        if point.is_identity().into() {
+            return Err(BlsError::InvalidSignature);
+        }
+        if (!<C as Pairing>::signature_is_torsion_free(&point)).into() {
+            return Err(BlsError::InvalidSignature);
+        }
+        let share = <C as Pairing>::SignatureShare::with_identifier_and_value(
+            identifier,
+            ValueGroup(point),
+        );
+        Ok(match scheme {
+            SignatureSchemes::Basic => Self::Basic(share),
+            SignatureSchemes::MessageAugmentation => Self::MessageAugmentation(share),
+            SignatureSchemes::ProofOfPossession => Self::ProofOfPossession(share),
+        })
+    }
+
     /// Verify the signature share with the public key share
     pub fn verify<B: AsRef<[u8]>>(&self, pks: &PublicKeyShare<C>, msg: B) -> BlsResult<()> {
         pks.verify(self, msg)
     }
 
+    /// Verify the signature share against a message that was already hashed
+    /// with [`PublicKey::hash_message`]
+    pub fn verify_prehashed(
+        &self,
+        pks: &PublicKeyShare<C>,
+        msg: &HashedMessage<C>,
+    ) -> BlsResult<()> {
+        if self.scheme() != msg.scheme() {
+            return Err(BlsError::InvalidSignatureScheme);
+        }
+        <C as BlsSignatureCore>::core_verify_hashed(
+            pks.0.value().0,
+            self.as_raw_value().value().0,
+            *msg.as_raw_value(),
+        )
+    }
+
+    /// Verify the proof from [`SecretKeyShare::sign_with_proof`] that this
+    /// share was created with the secret key share behind `pks`
+    pub fn verify_proof<B: AsRef<[u8]>>(
+        &self,
+        pks: &PublicKeyShare<C>,
+        msg: B,
+        proof: &ShareCorrectnessProof<C>,
+    ) -> BlsResult<()> {
+        let scheme = self.scheme();
+        if scheme == SignatureSchemes::MessageAugmentation {
+            return Err(BlsError::InvalidSignatureScheme);
+        }
+        let sig = self.as_raw_value();
+        if pks.0.identifier() != sig.identifier() {
+            return Err(BlsError::InvalidInputs(
+                "signature and public shares do not correspond".to_string(),
+            ));
+        }
+        let h = <C as HashToPoint>::hash_to_point(msg, scheme_dst::<C>(scheme));
+        proof.verify(pks.0.value().0, h, sig.value().0)
+    }
+
+    /// Verify signature shares on the same message against their public key
+    /// shares using a random linear combination so only one multi-pairing is
+    /// computed.
+    ///
+    /// On failure the position of every share that does not verify is
+    /// returned. Shares using a different scheme than the first share or an
+    /// identifier different from their public key share are reported without
+    /// checking the pairing. An empty list means there was nothing to verify.
+    pub fn verify_batch<B: AsRef<[u8]>>(
+        items: &[(PublicKeyShare<C>, SignatureShare<C>)],
+        msg: B,
+        mut rng: impl RngCore + CryptoRng,
+    ) -> Result<(), Vec<usize>> {
+        let msg = msg.as_ref();
+        let scheme = items.first().ok_or_else(Vec::new)?.1.scheme();
+        let bad = items
+            .iter()
+            .enumerate()
+            .filter(|(_, (pks, sig))| {
+                let raw = sig.as_raw_value();
+                sig.scheme() != scheme
+                    || bool::from(raw.value().0.is_identity())
+                    || bool::from(pks.0.value().0.is_identity())
+                    || pks.0.identifier() != raw.identifier()
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        if !bad.is_empty() {
+            return Err(bad);
+        }
+
+        // Without message augmentation every share signs the same point
+        // so the public keys can be summed into a single pairing
+        let mut hasher = MessageHasher::<C>::new(msg.as_ref());
+        let shared = (scheme != SignatureSchemes::MessageAugmentation)
+            .then(|| hasher.to_point(scheme_dst::<C>(scheme)));
+        let mut pairs = Vec::with_capacity(2);
+        let mut pk_sum = <C as Pairing>::PublicKey::identity();
+        let mut sig_sum = <C as Pairing>::Signature::identity();
+        for (pks, sig) in items {
+            let r = <<C as Pairing>::PublicKey as Group>::Scalar::random(&mut rng);
+            let pk = pks.0.value().0;
+            match shared {
+                Some(_) => pk_sum += pk * r,
+                None => pairs.push((hasher.scheme_point(scheme, &PublicKey(pk)), pk * r)),
+            }
+            sig_sum += sig.as_raw_value().value().0 * r;
+        }
+        if let Some(h) = shared {
+            pairs.push((h, pk_sum));
+        }
+        pairs.push((sig_sum, -<C as Pairing>::PublicKey::generator()));
+        if <C as Pairing>::pairing(&pairs).is_identity().into() {
+            return Ok(());
+        }
+        Err(items
+            .iter()
+            .enumerate()
+            .filter(|(_, (pks, sig))| pks.verify(sig, msg).is_err())
+            .map(|(i, _)| i)
+            .collect())
+    }
+
     /// Determine if two signature shares were signed using the same scheme
     pub fn same_scheme(&self, other: &Self) -> bool {
         matches!(
@@ -111,6 +271,27 @@ impl<C: BlsSignatureImpl> SignatureShare<C> {
         )
     }
 
+    /// Remove repeated copies of the same share, such as when a signer's
+    /// share is received twice, so the rest can be combined.
+    ///
+    /// Fails with [`BlsError::DuplicateShareIdentifier`] if two shares with
+    /// the same identifier have different values or schemes.
+    pub fn dedup_by_identifier(shares: &[SignatureShare<C>]) -> BlsResult<Vec<SignatureShare<C>>> {
+        let mut output = Vec::<SignatureShare<C>>::with_capacity(shares.len());
+        for share in shares {
+            let raw = share.as_raw_value();
+            match output
+                .iter()
+                .find(|s| s.as_raw_value().identifier() == raw.identifier())
+            {
+                Some(s) if s.same_scheme(share) && s.as_raw_value().value().0 == raw.value().0 => {}
+                Some(_) => return Err(BlsError::DuplicateShareIdentifier),
+                None => output.push(*share),
+            }
+        }
+        Ok(output)
+    }
+
     /// Extract the inner raw representation
     pub fn as_raw_value(&self) -> &<C as Pairing>::SignatureShare {
         match self {
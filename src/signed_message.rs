@@ -0,0 +1,120 @@
+use crate::*;
+
+/// A signature kept together with the public key and message it
+/// is supposed to verify against.
+///
+/// Aggregating these instead of bare signatures keeps each signature
+/// paired with its signer and message, so the aggregate can't be
+/// checked against a mismatched key or message set.
+#[derive(PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedMessage<C: BlsSignatureImpl> {
+    /// The signer's public key
+    #[serde(bound(
+        serialize = "PublicKey<C>: Serialize",
+        deserialize = "PublicKey<C>: Deserialize<'de>"
+    ))]
+    pub pk: PublicKey<C>,
+    /// The signed message
+    pub msg: Vec<u8>,
+    /// The signature on `msg` by `pk`
+    #[serde(bound(
+        serialize = "Signature<C>: Serialize",
+        deserialize = "Signature<C>: Deserialize<'de>"
+    ))]
+    pub sig: Signature<C>,
+}
+
+impl<C: BlsSignatureImpl> Clone for SignedMessage<C> {
+    fn clone(&self) -> Self {
+        Self {
+            pk: self.pk,
+            msg: self.msg.clone(),
+            sig: self.sig,
+        }
+    }
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for SignedMessage<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SignedMessage {{ pk: {:?}, msg: {:?}, sig: {:?} }}",
+            self.pk, self.msg, self.sig
+        )
+    }
+}
+
+impl_from_derivatives_generic!(SignedMessage);
+
+impl<C: BlsSignatureImpl> From<&SignedMessage<C>> for Vec<u8> {
+    fn from(value: &SignedMessage<C>) -> Self {
+        serde_bare::to_vec(value).expect("failed to serialize signed message")
+    }
+}
+
+impl<C: BlsSignatureImpl> TryFrom<&[u8]> for SignedMessage<C> {
+    type Error = BlsError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let output = serde_bare::from_slice(value)?;
+        Ok(output)
+    }
+}
+
+impl<C: BlsSignatureImpl> SignedMessage<C> {
+    /// Sign `msg` with `sk` and keep the public key and message alongside
+    pub fn sign<B: AsRef<[u8]>>(
+        sk: &SecretKey<C>,
+        scheme: SignatureSchemes,
+        msg: B,
+    ) -> BlsResult<Self> {
+        let msg = msg.as_ref().to_vec();
+        let sig = sk.sign(scheme, &msg)?;
+        Ok(Self {
+            pk: sk.public_key(),
+            msg,
+            sig,
+        })
+    }
+
+    /// Verify the signature against the bundled public key and message
+    pub fn verify(&self) -> BlsResult<()> {
+        self.sig.verify(&self.pk, &self.msg)
+    }
+}
+
+/// The result of aggregating [`SignedMessage`]s: the aggregate signature
+/// and the public key and message pairs it must be verified against.
+#[derive(PartialEq, Eq)]
+pub struct AggregatedSignedMessages<C: BlsSignatureImpl> {
+    /// The aggregate of every bundled signature
+    pub signature: AggregateSignature<C>,
+    /// The public key and message pairs in the order they were aggregated
+    pub data: Vec<(PublicKey<C>, Vec<u8>)>,
+}
+
+impl<C: BlsSignatureImpl> Clone for AggregatedSignedMessages<C> {
+    fn clone(&self) -> Self {
+        Self {
+            signature: self.signature,
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<C: BlsSignatureImpl> fmt::Debug for AggregatedSignedMessages<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "AggregatedSignedMessages {{ signature: {:?}, data: {:?} }}",
+            self.signature, self.data
+        )
+    }
+}
+
+impl<C: BlsSignatureImpl> AggregatedSignedMessages<C> {
+    /// Verify the aggregate signature against the public key and message pairs
+    pub fn verify(&self) -> BlsResult<()> {
+        self.signature.verify(&self.data)
+    }
+}
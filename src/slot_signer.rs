@@ -0,0 +1,62 @@
+use crate::*;
+
+/// A signer that refuses to sign more than once per slot.
+///
+/// Tracks the highest slot signed and rejects any request
+/// to sign an equal or lower slot with [`BlsError::DoubleSign`].
+/// The highest slot should be persisted by the caller so protection
+/// survives restarts, see [`SlotSigner::with_last_slot`].
+///
+/// Deliberately not `Clone`, two copies would each track their own
+/// last slot and could sign the same slot.
+#[derive(Debug)]
+pub struct SlotSigner<C: BlsSignatureImpl> {
+    secret_key: SecretKey<C>,
+    last_slot: Option<u64>,
+}
+
+impl<C: BlsSignatureImpl> SlotSigner<C> {
+    /// Create a new signer that has not signed any slots
+    pub fn new(secret_key: SecretKey<C>) -> Self {
+        Self {
+            secret_key,
+            last_slot: None,
+        }
+    }
+
+    /// Create a new signer that has already signed up to `last_slot`
+    pub fn with_last_slot(secret_key: SecretKey<C>, last_slot: u64) -> Self {
+        Self {
+            secret_key,
+            last_slot: Some(last_slot),
+        }
+    }
+
+    /// The highest slot signed so far
+    pub fn last_slot(&self) -> Option<u64> {
+        self.last_slot
+    }
+
+    /// The public key for this signer
+    pub fn public_key(&self) -> PublicKey<C> {
+        self.secret_key.public_key()
+    }
+
+    /// Sign a message for `slot`.
+    ///
+    /// Fails with [`BlsError::DoubleSign`] if `slot` is not greater
+    /// than the last signed slot.
+    pub fn sign(
+        &mut self,
+        scheme: SignatureSchemes,
+        slot: u64,
+        msg: &[u8],
+    ) -> BlsResult<Signature<C>> {
+        if matches!(self.last_slot, Some(last) if slot <= last) {
+            return Err(BlsError::DoubleSign);
+        }
+        let sig = self.secret_key.sign_with_slot(scheme, slot, msg)?;
+        self.last_slot = Some(slot);
+        Ok(sig)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::*;
+use rand_core::RngCore;
+use sha2::{Digest, Sha256};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+use subtle::{ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
+
+const PAYLOAD_DST: &[u8] = b"BLS_TIME_CRYPT_ANY_PAYLOAD_";
+
+/// A time lock ciphertext that a signature over the identifier from any
+/// one of several public keys can open.
+///
+/// The message is encrypted once with a random payload key. Each branch
+/// time locks the payload key and a digest of the encrypted payload to
+/// one of the public keys.
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TimeCryptAnyCiphertext<C: BlsSignatureImpl> {
+    /// One time lock ciphertext per public key
+    #[serde(bound(
+        serialize = "TimeCryptCiphertext<C>: serde::Serialize",
+        deserialize = "TimeCryptCiphertext<C>: serde::Deserialize<'de>"
+    ))]
+    pub branches: Vec<TimeCryptCiphertext<C>>,
+    /// The encrypted message
+    pub payload: Vec<u8>,
+}
+
+impl<C: BlsSignatureImpl> From<&TimeCryptAnyCiphertext<C>> for Vec<u8> {
+    fn from(value: &TimeCryptAnyCiphertext<C>) -> Self {
+        serde_bare::to_vec(value).expect("failed to serialize time crypt any ciphertext")
+    }
+}
+
+impl<C: BlsSignatureImpl> TryFrom<&[u8]> for TimeCryptAnyCiphertext<C> {
+    type Error = BlsError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let output = serde_bare::from_slice(value)?;
+        Ok(output)
+    }
+}
+
+impl_from_derivatives_generic!(TimeCryptAnyCiphertext);
+
+impl<C: BlsSignatureImpl> TimeCryptAnyCiphertext<C> {
+    pub(crate) fn new(
+        recipients: &[PublicKey<C>],
+        scheme: SignatureSchemes,
+        msg: &[u8],
+        id: &[u8],
+    ) -> BlsResult<Self> {
+        if recipients.is_empty() {
+            return Err(BlsError::InvalidInputs(
+                "no public keys provided".to_string(),
+            ));
+        }
+        let mut key = [0u8; 32];
+        get_crypto_rng().fill_bytes(&mut key);
+        let payload = apply_keystream(&key, msg);
+        let mut branch_msg = key.to_vec();
+        branch_msg.extend_from_slice(&Sha256::digest(&payload));
+        key.zeroize();
+        let branches = recipients
+            .iter()
+            .map(|pk| pk.encrypt_time_lock(scheme, &branch_msg, id))
+            .collect::<BlsResult<Vec<_>>>();
+        branch_msg.zeroize();
+        Ok(Self {
+            branches: branches?,
+            payload,
+        })
+    }
+
+    /// Decrypt using a signature over the identifier from any of the
+    /// public keys
+    ///
+    /// Each branch is tried in turn so the time taken reveals which key
+    /// signed.
+    pub fn decrypt(&self, sig: &Signature<C>) -> CtOption<Vec<u8>> {
+        let digest = Sha256::digest(&self.payload);
+        for branch in &self.branches {
+            let mut opened = match Option::<Vec<u8>>::from(branch.decrypt(sig)) {
+                Some(opened) if opened.len() == 64 => opened,
+                _ => continue,
+            };
+            let matches = bool::from(opened[32..].ct_eq(digest.as_slice()));
+            let plaintext = matches.then(|| {
+                let key = <&[u8; 32]>::try_from(&opened[..32]).expect("opened is 64 bytes");
+                apply_keystream(key, &self.payload)
+            });
+            opened.zeroize();
+            if let Some(plaintext) = plaintext {
+                return CtOption::new(plaintext, Choice::from(1u8));
+            }
+        }
+        CtOption::new(Vec::new(), Choice::from(0u8))
+    }
+}
+
+fn apply_keystream(key: &[u8; 32], data: &[u8]) -> Vec<u8> {
+    let mut hasher = Shake128::default();
+    hasher.update(PAYLOAD_DST);
+    hasher.update(key);
+    let mut output = vec![0u8; data.len()];
+    hasher.finalize_xof().read(&mut output);
+    output.iter_mut().zip(data).for_each(|(o, d)| *o ^= d);
+    output
+}
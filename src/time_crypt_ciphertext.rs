@@ -48,4 +48,78 @@ impl<C: BlsSignatureImpl> TimeCryptCiphertext<C> {
         };
         <C as BlsTimeCrypt>::unseal(self.u, &self.v, &self.w, s, valid)
     }
+
+    /// Decrypt the time lock ciphertext using a signature over an identifier.
+    ///
+    /// Unlike [`TimeCryptCiphertext::decrypt`] this reports why decryption failed:
+    /// - [`BlsError::InvalidSignatureScheme`] if the signature's scheme does not match the ciphertext
+    /// - [`BlsError::DecryptionFailed`] if the signature does not open the ciphertext,
+    ///   either because it is over a different identifier or the ciphertext is corrupt
+    pub fn decrypt_checked(&self, sig: &Signature<C>) -> BlsResult<Vec<u8>> {
+        if sig.scheme() != self.scheme {
+            return Err(BlsError::InvalidSignatureScheme);
+        }
+        Option::<Vec<u8>>::from(self.decrypt(sig)).ok_or(BlsError::DecryptionFailed)
+    }
+
+    /// Decrypt a ciphertext created by [`PublicKey::seal_ephemeral`] using
+    /// the time lock signature and the recipient's secret key
+    pub fn decrypt_ephemeral(&self, sig: &Signature<C>, sk: &SecretKey<C>) -> CtOption<Vec<u8>> {
+        let inner = Option::<Vec<u8>>::from(self.decrypt(sig))
+            .and_then(|bytes| SignCryptCiphertext::<C>::try_from(bytes.as_slice()).ok());
+        match inner {
+            Some(ciphertext) => ciphertext.decrypt(sk),
+            None => CtOption::new(Vec::new(), Choice::from(0u8)),
+        }
+    }
+
+    /// Decrypt the time lock ciphertext using signature shares over `id`
+    /// from the key shares of `pk`.
+    ///
+    /// Unlike [`TimeCryptCiphertext::decrypt`] this reports why decryption failed:
+    /// - [`BlsError::InsufficientShares`] if fewer than `threshold` shares are supplied
+    /// - [`BlsError::DuplicateShareIdentifier`] if two shares have the same identifier
+    /// - [`BlsError::InvalidSignatureScheme`] if a share's scheme does not match the ciphertext
+    /// - [`BlsError::InvalidSignature`] if the combined signature is not a signature over `id`
+    ///   by `pk`, i.e. a share is invalid
+    /// - [`BlsError::DecryptionFailed`] if the valid combined signature does not open the
+    ///   ciphertext, i.e. the ciphertext is corrupt or was not sealed to `pk` and `id`
+    pub fn decrypt_with_shares<B: AsRef<[u8]>>(
+        &self,
+        shares: &[SignatureShare<C>],
+        threshold: usize,
+        pk: &PublicKey<C>,
+        id: B,
+    ) -> BlsResult<Vec<u8>> {
+        if shares.len() < threshold.max(1) {
+            return Err(BlsError::InsufficientShares(threshold, shares.len()));
+        }
+        for (i, share) in shares.iter().enumerate() {
+            let id = share.as_raw_value().identifier();
+            if shares[..i]
+                .iter()
+                .any(|s| s.as_raw_value().identifier() == id)
+            {
+                return Err(BlsError::DuplicateShareIdentifier);
+            }
+            let same_scheme = matches!(
+                (share, self.scheme),
+                (SignatureShare::Basic(_), SignatureSchemes::Basic)
+                    | (
+                        SignatureShare::MessageAugmentation(_),
+                        SignatureSchemes::MessageAugmentation
+                    )
+                    | (
+                        SignatureShare::ProofOfPossession(_),
+                        SignatureSchemes::ProofOfPossession
+                    )
+            );
+            if !same_scheme {
+                return Err(BlsError::InvalidSignatureScheme);
+            }
+        }
+        let sig = Signature::from_shares(shares)?;
+        sig.verify(pk, id).map_err(|_| BlsError::InvalidSignature)?;
+        Option::<Vec<u8>>::from(self.decrypt(&sig)).ok_or(BlsError::DecryptionFailed)
+    }
 }
@@ -1,6 +1,7 @@
 use super::*;
 use crate::impls::inner_types::*;
 use crate::{BlsError, BlsResult};
+use alloc::string::ToString;
 use rand_core::{CryptoRng, RngCore};
 
 const SALT: &[u8] = b"ELGAMAL_BLS12381_XOF:HKDF-SHA2-256_";
@@ -43,7 +44,6 @@ pub trait BlsElGamal: Pairing + HashToScalar<Output = <Self::PublicKey as Group>
         debug_assert_eq!(blinder.is_zero().unwrap_u8(), 0u8);
 
         let ek = generator * message;
-        debug_assert_eq!(ek.is_identity().unwrap_u8(), 0u8);
         let c1 = Self::PublicKey::generator() * blinder;
         debug_assert_eq!(c1.is_identity().unwrap_u8(), 0u8);
         let c2 = pk * blinder + ek;
@@ -74,6 +74,84 @@ pub trait BlsElGamal: Pairing + HashToScalar<Output = <Self::PublicKey as Group>
         Ok((c1, c2))
     }
 
+    /// Encrypt a point and generate a proof of knowledge of the blinder
+    #[allow(clippy::type_complexity)]
+    fn seal_point_with_proof(
+        pk: Self::PublicKey,
+        message: Self::PublicKey,
+        blinder: Option<<Self::PublicKey as Group>::Scalar>,
+        mut rng: impl CryptoRng + RngCore,
+    ) -> BlsResult<(
+        Self::PublicKey,
+        Self::PublicKey,
+        <Self::PublicKey as Group>::Scalar,
+        <Self::PublicKey as Group>::Scalar,
+    )> {
+        let b = blinder.unwrap_or_else(|| <Self::PublicKey as Group>::Scalar::random(&mut rng));
+        debug_assert_eq!(b.is_zero().unwrap_u8(), 0u8);
+        let r = <Self::PublicKey as Group>::Scalar::random(&mut rng);
+        debug_assert_eq!(r.is_zero().unwrap_u8(), 0u8);
+        // c1 = P^b
+        // c2 = M * P^ab
+        let (c1, c2) = Self::seal_point(pk, message, Some(b), &mut rng)?;
+        // r1 = P^r
+        let r1 = Self::PublicKey::generator() * r;
+        let challenge = Self::point_proof_challenge(pk, c1, c2, r1);
+        debug_assert_eq!(challenge.is_zero().unwrap_u8(), 0u8);
+        let blinder_proof = r + challenge * b;
+        debug_assert_eq!(blinder_proof.is_zero().unwrap_u8(), 0u8);
+        Ok((c1, c2, blinder_proof, challenge))
+    }
+
+    /// Verify the proof of knowledge of the blinder for an encrypted point
+    fn verify_point_proof(
+        pk: Self::PublicKey,
+        c1: Self::PublicKey,
+        c2: Self::PublicKey,
+        blinder_proof: <Self::PublicKey as Group>::Scalar,
+        challenge: <Self::PublicKey as Group>::Scalar,
+    ) -> BlsResult<()> {
+        if (pk.is_identity() | c1.is_identity() | c2.is_identity()).into() {
+            return Err(BlsError::InvalidInputs(
+                "Parameters or ciphertext values are identity point".to_string(),
+            ));
+        }
+        if (blinder_proof.is_zero() | challenge.is_zero()).into() {
+            return Err(BlsError::InvalidInputs("Proof values are zero".to_string()));
+        }
+        // r1 = P^-bc P^(r + b * c)
+        let r1 = c1 * -challenge + Self::PublicKey::generator() * blinder_proof;
+        if challenge != Self::point_proof_challenge(pk, c1, c2, r1) {
+            Err(BlsError::InvalidInputs(
+                "Challenge values do not match".to_string(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Compute the fiat-shamir challenge for an encrypted point proof
+    fn point_proof_challenge(
+        pk: Self::PublicKey,
+        c1: Self::PublicKey,
+        c2: Self::PublicKey,
+        r1: Self::PublicKey,
+    ) -> <Self::PublicKey as Group>::Scalar {
+        let mut transcript = merlin::Transcript::new(b"ElGamalPointProof");
+        transcript.append_message(b"dst", SALT);
+        transcript.append_message(
+            b"base point",
+            Self::PublicKey::generator().to_bytes().as_ref(),
+        );
+        transcript.append_message(b"pk", pk.to_bytes().as_ref());
+        transcript.append_message(b"c1", c1.to_bytes().as_ref());
+        transcript.append_message(b"c2", c2.to_bytes().as_ref());
+        transcript.append_message(b"r1", r1.to_bytes().as_ref());
+        let mut challenge = [0u8; 64];
+        transcript.challenge_bytes(b"challenge", &mut challenge);
+        Self::scalar_from_bytes_wide(&challenge)
+    }
+
     /// Encrypt a scalar and generate a ZKP
     #[allow(clippy::type_complexity)]
     fn seal_scalar_with_proof(
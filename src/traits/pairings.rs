@@ -2,19 +2,34 @@ use crate::impls::inner_types::*;
 use core::fmt::Display;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use subtle::ConditionallySelectable;
+use subtle::{Choice, ConditionallySelectable};
 use vsss_rs::*;
 
 /// Operations that support pairing trait
+///
+/// All associated types are `Send + Sync + 'static` so every type
+/// in this crate that is generic over an implementation is as well.
 pub trait Pairing {
     /// The secret key share
     type SecretKeyShare: Share<
             Identifier = IdentifierPrimeField<<Self::PublicKey as Group>::Scalar>,
             Value = ValuePrimeField<<Self::PublicKey as Group>::Scalar>,
         > + core::fmt::Debug
-        + DeserializeOwned;
+        + DeserializeOwned
+        + Send
+        + Sync;
     /// The public key group
     type PublicKey: Group + GroupEncoding + Default + Display + ConditionallySelectable;
+    /// The public key group in affine form
+    type PublicKeyAffine: GroupEncoding
+        + Copy
+        + core::fmt::Debug
+        + From<Self::PublicKey>
+        + Into<Self::PublicKey>
+        + Send
+        + Sync;
+    /// The public key in the form consumed directly by the Miller loop
+    type PublicKeyPrepared: Clone + core::fmt::Debug + Send + Sync;
     /// The public key share
     type PublicKeyShare: Share<
             Identifier = IdentifierPrimeField<<Self::PublicKey as Group>::Scalar>,
@@ -24,7 +39,9 @@ pub trait Pairing {
         + core::fmt::Debug
         + ConditionallySelectable
         + Serialize
-        + DeserializeOwned;
+        + DeserializeOwned
+        + Send
+        + Sync;
     /// The signature group
     type Signature: Group<Scalar = <Self::PublicKey as Group>::Scalar>
         + GroupEncoding
@@ -40,9 +57,41 @@ pub trait Pairing {
         + core::fmt::Debug
         + ConditionallySelectable
         + Serialize
-        + DeserializeOwned;
+        + DeserializeOwned
+        + Send
+        + Sync;
+    /// The number of bytes in a compressed public key
+    const PUBLIC_KEY_BYTES: usize;
+    /// The number of bytes in a compressed signature
+    const SIGNATURE_BYTES: usize;
     /// The target group from a pairing computation
     type PairingResult: Group + GroupEncoding + Default + Display + ConditionallySelectable;
     /// Compute the pairing based on supplied points
     fn pairing(points: &[(Self::Signature, Self::PublicKey)]) -> Self::PairingResult;
+    /// Compute the pairing where the public keys are already in affine form
+    fn pairing_with_affine_public_keys(
+        points: &[(Self::Signature, Self::PublicKeyAffine)],
+    ) -> Self::PairingResult;
+    /// Precompute the pairing input for a public key
+    fn prepare_public_key(public_key: &Self::PublicKey) -> Self::PublicKeyPrepared;
+    /// Compute the pairing where the public keys are already prepared
+    fn pairing_with_prepared_public_keys(
+        points: &[(Self::Signature, &Self::PublicKeyPrepared)],
+    ) -> Self::PairingResult;
+    /// Compute the sum of each signature point times its scalar in one
+    /// multi-scalar multiplication
+    fn signature_sum_of_products(
+        points: &[Self::Signature],
+        scalars: &[<Self::Signature as Group>::Scalar],
+    ) -> Self::Signature;
+    /// Compute the sum of each public key point times its scalar in one
+    /// multi-scalar multiplication
+    fn public_key_sum_of_products(
+        points: &[Self::PublicKey],
+        scalars: &[<Self::PublicKey as Group>::Scalar],
+    ) -> Self::PublicKey;
+    /// Check whether the signature point is in the prime order subgroup
+    fn signature_is_torsion_free(signature: &Self::Signature) -> Choice;
+    /// Check whether the public key point is in the prime order subgroup
+    fn public_key_is_torsion_free(public_key: &Self::PublicKey) -> Choice;
 }
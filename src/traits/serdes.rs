@@ -1,5 +1,6 @@
 use crate::impls::inner_types::*;
 use crate::traits::Pairing;
+use alloc::vec::Vec;
 use serde::{Deserializer, Serializer};
 
 /// Serialization trait for inner types
@@ -103,6 +104,22 @@ pub(crate) mod public_key {
     }
 }
 
+pub(crate) mod non_identity_public_key {
+    use super::*;
+
+    /// Deserialize a public key, rejecting the identity element like
+    /// the byte conversions of [`crate::PublicKey`] do
+    pub fn deserialize<'de, B: BlsSerde, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<B::PublicKey, D::Error> {
+        let pk = B::deserialize_public_key(d)?;
+        if pk.is_identity().into() {
+            return Err(serde::de::Error::custom("public key is identity"));
+        }
+        Ok(pk)
+    }
+}
+
 pub(crate) mod signature {
     use super::*;
 
@@ -136,3 +153,37 @@ pub(crate) mod scalar {
         B::deserialize_scalar(d)
     }
 }
+
+pub(crate) mod scalars {
+    use super::*;
+
+    struct ScalarRef<'a, B: BlsSerde>(&'a <B::PublicKey as Group>::Scalar);
+
+    impl<B: BlsSerde> serde::Serialize for ScalarRef<'_, B> {
+        fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            B::serialize_scalar(self.0, s)
+        }
+    }
+
+    struct ScalarOwned<B: BlsSerde>(<B::PublicKey as Group>::Scalar);
+
+    impl<'de, B: BlsSerde> serde::Deserialize<'de> for ScalarOwned<B> {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            B::deserialize_scalar(d).map(Self)
+        }
+    }
+
+    pub fn serialize<B: BlsSerde, S: Serializer>(
+        scalars: &[<B::PublicKey as Group>::Scalar],
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        s.collect_seq(scalars.iter().map(ScalarRef::<B>))
+    }
+
+    pub fn deserialize<'de, B: BlsSerde, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Vec<<B::PublicKey as Group>::Scalar>, D::Error> {
+        let scalars = <Vec<ScalarOwned<B>> as serde::Deserialize>::deserialize(d)?;
+        Ok(scalars.into_iter().map(|s| s.0).collect())
+    }
+}
@@ -1,6 +1,5 @@
 use crate::impls::inner_types::*;
 use crate::*;
-use std::collections::HashMap;
 
 /// BLS signature basic trait
 pub trait BlsSignatureBasic: BlsSignatureCore + BlsMultiSignature + BlsMultiKey {
@@ -43,21 +42,31 @@ pub trait BlsSignatureBasic: BlsSignatureCore + BlsMultiSignature + BlsMultiKey
         P: Iterator<Item = (Self::PublicKey, B)>,
         B: AsRef<[u8]>,
     {
-        // check uniqueness
-        let mut set = HashMap::new();
-        let mut inputs = Vec::new();
-        for (i, (pk, m)) in pks.enumerate() {
-            let item = m.as_ref().to_vec();
-            if let Some(old) = set.insert(item.clone(), i) {
-                return Err(BlsError::InvalidInputs(format!(
-                    "duplicate messages detected at {} and {}",
-                    old, i
-                )));
-            }
-            inputs.push((pk, item));
+        let inputs = pks.collect::<Vec<_>>();
+        // check uniqueness by sorting indices by message so duplicates
+        // are adjacent, ties are broken by index to report the same pair
+        // as a first to last scan would
+        let mut order = (0..inputs.len()).collect::<Vec<_>>();
+        order.sort_unstable_by(|&a, &b| {
+            inputs[a]
+                .1
+                .as_ref()
+                .cmp(inputs[b].1.as_ref())
+                .then(a.cmp(&b))
+        });
+        if let Some((old, i)) = order
+            .windows(2)
+            .filter(|w| inputs[w[0]].1.as_ref() == inputs[w[1]].1.as_ref())
+            .map(|w| (w[0], w[1]))
+            .min_by_key(|&(_, i)| i)
+        {
+            return Err(BlsError::InvalidInputs(format!(
+                "duplicate messages detected at {} and {}",
+                old, i
+            )));
         }
         <Self as BlsSignatureCore>::core_aggregate_verify(
-            inputs.iter().map(|(pk, b)| (*pk, b.as_slice())),
+            inputs.iter().map(|(pk, b)| (*pk, b.as_ref())),
             sig,
             Self::DST,
         )
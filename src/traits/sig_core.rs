@@ -1,5 +1,6 @@
 use crate::impls::inner_types::*;
 use crate::*;
+use rand_core::{CryptoRng, RngCore};
 use vsss_rs::*;
 
 /// The core methods used by BLS signatures
@@ -12,6 +13,10 @@ pub trait BlsSignatureCore:
     + BlsTimeCrypt
     + BlsElGamal
 {
+    /// Reduce 48 bytes of uniformly random output keying material
+    /// into a scalar as in the final step of the IETF `KeyGen`
+    fn scalar_from_okm(okm: &[u8; 48]) -> <Self::PublicKey as Group>::Scalar;
+
     /// Get the public key corresponding to the secret key
     fn public_key(sk: &<Self::PublicKey as Group>::Scalar) -> Self::PublicKey {
         <Self::PublicKey as Group>::generator() * sk
@@ -92,6 +97,7 @@ pub trait BlsSignatureCore:
     fn core_combine_signature_shares(
         shares: &[Self::SignatureShare],
     ) -> BlsResult<Self::Signature> {
+        check_share_identifiers(shares)?;
         let sig = shares.combine()?;
         Ok(sig.0)
     }
@@ -100,10 +106,35 @@ pub trait BlsSignatureCore:
     fn core_combine_public_key_shares(
         shares: &[Self::PublicKeyShare],
     ) -> BlsResult<Self::PublicKey> {
+        check_share_identifiers(shares)?;
         let pk = shares.combine()?;
         Ok(pk.0)
     }
 
+    /// Combine signature shares to form a signature using a single
+    /// multi-scalar multiplication over the Lagrange coefficients
+    fn core_combine_signature_shares_msm(
+        shares: &[Self::SignatureShare],
+    ) -> BlsResult<Self::Signature> {
+        check_share_identifiers(shares)?;
+        let ids = shares.iter().map(|s| s.identifier().0).collect::<Vec<_>>();
+        let coefficients = lagrange_coefficients(&ids)?;
+        let points = shares.iter().map(|s| s.value().0).collect::<Vec<_>>();
+        Ok(Self::signature_sum_of_products(&points, &coefficients))
+    }
+
+    /// Combine public key shares to form a public key using a single
+    /// multi-scalar multiplication over the Lagrange coefficients
+    fn core_combine_public_key_shares_msm(
+        shares: &[Self::PublicKeyShare],
+    ) -> BlsResult<Self::PublicKey> {
+        check_share_identifiers(shares)?;
+        let ids = shares.iter().map(|s| s.identifier().0).collect::<Vec<_>>();
+        let coefficients = lagrange_coefficients(&ids)?;
+        let points = shares.iter().map(|s| s.value().0).collect::<Vec<_>>();
+        Ok(Self::public_key_sum_of_products(&points, &coefficients))
+    }
+
     /// Compute a signature
     fn core_sign<B: AsRef<[u8]>, C: AsRef<[u8]>>(
         sk: &<Self::PublicKey as Group>::Scalar,
@@ -122,6 +153,16 @@ pub trait BlsSignatureCore:
         sig: Self::Signature,
         msg: B,
         dst: C,
+    ) -> BlsResult<()> {
+        let a = Self::hash_to_point::<B, C>(msg, dst);
+        Self::core_verify_hashed(pk, sig, a)
+    }
+
+    /// Verify a signature against a message that was already hashed to a point
+    fn core_verify_hashed(
+        pk: Self::PublicKey,
+        sig: Self::Signature,
+        a: Self::Signature,
     ) -> BlsResult<()> {
         if sig.is_identity().into() {
             return Err(BlsError::InvalidInputs(
@@ -133,7 +174,6 @@ pub trait BlsSignatureCore:
                 "public key is the identity point".to_string(),
             ));
         }
-        let a = Self::hash_to_point::<B, C>(msg, dst);
         let generator = -Self::PublicKey::generator();
         if Self::pairing(&[(a, pk), (sig, generator)])
             .is_identity()
@@ -145,6 +185,61 @@ pub trait BlsSignatureCore:
         }
     }
 
+    /// Verify a signature using a public key already in affine form
+    fn core_verify_affine<B: AsRef<[u8]>, C: AsRef<[u8]>>(
+        pk: Self::PublicKeyAffine,
+        sig: Self::Signature,
+        msg: B,
+        dst: C,
+    ) -> BlsResult<()> {
+        if sig.is_identity().into() {
+            return Err(BlsError::InvalidInputs(
+                "signature is the identity point".to_string(),
+            ));
+        }
+        let pk_point: Self::PublicKey = pk.into();
+        if pk_point.is_identity().into() {
+            return Err(BlsError::InvalidInputs(
+                "public key is the identity point".to_string(),
+            ));
+        }
+        let a = Self::hash_to_point::<B, C>(msg, dst);
+        let generator = Self::PublicKeyAffine::from(-Self::PublicKey::generator());
+        if Self::pairing_with_affine_public_keys(&[(a, pk), (sig, generator)])
+            .is_identity()
+            .into()
+        {
+            Ok(())
+        } else {
+            Err(BlsError::InvalidSignature)
+        }
+    }
+
+    /// Verify a signature using a prepared public key and the prepared
+    /// negated generator of the public key group
+    fn core_verify_prepared<B: AsRef<[u8]>, C: AsRef<[u8]>>(
+        pk: &Self::PublicKeyPrepared,
+        neg_generator: &Self::PublicKeyPrepared,
+        sig: Self::Signature,
+        msg: B,
+        dst: C,
+    ) -> BlsResult<()> {
+        if sig.is_identity().into() {
+            return Err(BlsError::InvalidInputs(
+                "signature is the identity point".to_string(),
+            ));
+        }
+        let a = Self::hash_to_point::<B, C>(msg, dst);
+        if Self::pairing_with_prepared_public_keys(&[(a, pk), (sig, neg_generator)])
+            .is_identity()
+            .into()
+        {
+            Ok(())
+        } else {
+            Err(BlsError::InvalidSignature)
+        }
+    }
+
     /// Verify an aggregate signature and messages
     fn core_aggregate_verify<P, B, C>(pks: P, sig: Self::Signature, dst: C) -> BlsResult<()>
     where
@@ -176,4 +271,48 @@ pub trait BlsSignatureCore:
             Err(BlsError::InvalidSignature)
         }
     }
+
+    /// Verify multiple signatures where each signature has its own public key,
+    /// message and domain separation tag.
+    ///
+    /// The signatures are combined using a random linear combination so only
+    /// a single multi-pairing is computed. Fails if any signature is invalid.
+    fn core_batch_verify<P, B, D>(items: P, mut rng: impl RngCore + CryptoRng) -> BlsResult<()>
+    where
+        P: Iterator<Item = (Self::PublicKey, Self::Signature, B, D)>,
+        B: AsRef<[u8]>,
+        D: AsRef<[u8]>,
+    {
+        let mut pairs = Vec::new();
+        let mut sig_sum = Self::Signature::identity();
+        for (i, (pk, sig, msg, dst)) in items.enumerate() {
+            if pk.is_identity().into() {
+                return Err(BlsError::InvalidInputs(format!(
+                    "public key at {} is the identity point",
+                    i + 1
+                )));
+            }
+            if sig.is_identity().into() {
+                return Err(BlsError::InvalidInputs(format!(
+                    "signature at {} is the identity point",
+                    i + 1
+                )));
+            }
+            let r = <Self::PublicKey as Group>::Scalar::random(&mut rng);
+            let a = Self::hash_to_point::<_, _>(msg.as_ref(), dst.as_ref());
+            pairs.push((a, pk * r));
+            sig_sum += sig * r;
+        }
+        if pairs.is_empty() {
+            return Err(BlsError::InvalidInputs(
+                "no signatures to verify".to_string(),
+            ));
+        }
+        pairs.push((sig_sum, -<Self::PublicKey as Group>::generator()));
+        if Self::pairing(pairs.as_slice()).is_identity().into() {
+            Ok(())
+        } else {
+            Err(BlsError::InvalidSignature)
+        }
+    }
 }
@@ -68,4 +68,29 @@ pub trait BlsSignaturePop: BlsSignatureCore + BlsMultiSignature + BlsMultiKey {
         let pk_bytes = pk.to_bytes();
         <Self as BlsSignatureCore>::core_verify(pk, sig, pk_bytes, Self::POP_DST)
     }
+
+    /// The proof of possession signing algorithm bound to additional context
+    fn pop_prove_with_context<B: AsRef<[u8]>>(
+        sk: &<Self::PublicKey as Group>::Scalar,
+        context: B,
+    ) -> BlsResult<Self::Signature> {
+        let pk_bytes = Self::public_key(sk).to_bytes();
+        let mut msg = Vec::with_capacity(pk_bytes.as_ref().len() + context.as_ref().len());
+        msg.extend_from_slice(pk_bytes.as_ref());
+        msg.extend_from_slice(context.as_ref());
+        <Self as BlsSignatureCore>::core_sign(sk, msg, Self::POP_DST)
+    }
+
+    /// The proof of possession verification algorithm bound to additional context
+    fn pop_verify_with_context<B: AsRef<[u8]>>(
+        pk: Self::PublicKey,
+        sig: Self::Signature,
+        context: B,
+    ) -> BlsResult<()> {
+        let pk_bytes = pk.to_bytes();
+        let mut msg = Vec::with_capacity(pk_bytes.as_ref().len() + context.as_ref().len());
+        msg.extend_from_slice(pk_bytes.as_ref());
+        msg.extend_from_slice(context.as_ref());
+        <Self as BlsSignatureCore>::core_verify(pk, sig, msg, Self::POP_DST)
+    }
 }
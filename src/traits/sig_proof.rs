@@ -1,8 +1,8 @@
 use crate::impls::inner_types::*;
 use crate::*;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 const SALT: &[u8] = b"BLS_POK__BLS12381_XOF:HKDF-SHA2-256_";
+const NONINTERACTIVE_SALT: &[u8] = b"BLS_POK_NI_BLS12381_XOF:HKDF-SHA2-256_";
 
 /// Methods for creating a signature proof of knowledge as in
 /// <https://miracl.com/assets/pdf-downloads/mpin4.pdf>
@@ -26,11 +26,9 @@ pub trait BlsSignatureProof:
     }
 
     /// Create the timestamp based challenge for `y`
+    #[cfg(feature = "std")]
     fn generate_timestamp_based_y(u: Self::Signature) -> (<Self::Signature as Group>::Scalar, u64) {
-        let t = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
+        let t = now_ms();
         (Self::compute_y(u, t), t)
     }
 
@@ -71,11 +69,22 @@ pub trait BlsSignatureProof:
         Ok((commitment, -(sig * (x + y))))
     }
 
-    /// Create the value `V` using a timestamp
+    /// Create the value `V` using the current system time as the timestamp
+    #[cfg(feature = "std")]
     fn generate_timestamp_proof<B: AsRef<[u8]>, D: AsRef<[u8]>>(
         msg: B,
         dst: D,
         sig: Self::Signature,
+    ) -> BlsResult<(Self::Signature, Self::Signature, u64)> {
+        Self::generate_timestamp_proof_with_time(msg, dst, sig, now_ms())
+    }
+
+    /// Create the value `V` using the timestamp `t` in milliseconds since the unix epoch
+    fn generate_timestamp_proof_with_time<B: AsRef<[u8]>, D: AsRef<[u8]>>(
+        msg: B,
+        dst: D,
+        sig: Self::Signature,
+        t: u64,
     ) -> BlsResult<(Self::Signature, Self::Signature, u64)> {
         if sig.is_identity().into() {
             return Err(BlsError::InvalidInputs(
@@ -91,13 +100,55 @@ pub trait BlsSignatureProof:
         debug_assert_eq!(a.is_identity().unwrap_u8(), 0u8);
         let u = a * x;
         debug_assert_eq!(u.is_identity().unwrap_u8(), 0u8);
-        let (y, t) = Self::generate_timestamp_based_y(u);
+        let y = Self::compute_y(u, t);
         debug_assert_eq!(y.is_zero().unwrap_u8(), 0u8);
         let v = sig * (x + y);
         debug_assert_eq!(v.is_identity().unwrap_u8(), 0u8);
         Ok((u, -v, t))
     }
 
+    /// Compute the fiat-shamir challenge `y` from the commitment,
+    /// public key and message
+    fn compute_noninteractive_y(
+        u: Self::Signature,
+        pk: Self::PublicKey,
+        msg: &[u8],
+        dst: &[u8],
+    ) -> <Self::Signature as Group>::Scalar {
+        let mut transcript = merlin::Transcript::new(b"BlsSignatureProofOfKnowledge");
+        transcript.append_message(b"dst", dst);
+        transcript.append_message(b"u", u.to_bytes().as_ref());
+        transcript.append_message(b"pk", pk.to_bytes().as_ref());
+        transcript.append_message(b"msg", msg);
+        let mut challenge = [0u8; 64];
+        transcript.challenge_bytes(b"challenge", &mut challenge);
+        Self::hash_to_scalar(challenge, NONINTERACTIVE_SALT)
+    }
+
+    /// Create the values `U` and `V` for a non-interactive proof
+    fn generate_noninteractive_proof<B: AsRef<[u8]>, D: AsRef<[u8]>>(
+        msg: B,
+        dst: D,
+        sig: Self::Signature,
+        pk: Self::PublicKey,
+    ) -> BlsResult<(Self::Signature, Self::Signature)> {
+        let (u, x) = Self::generate_commitment(msg.as_ref(), dst.as_ref())?;
+        let y = Self::compute_noninteractive_y(u, pk, msg.as_ref(), dst.as_ref());
+        Self::generate_proof(u, x, y, sig)
+    }
+
+    /// Verify a non-interactive signature proof of knowledge
+    fn verify_noninteractive_proof<B: AsRef<[u8]>, D: AsRef<[u8]>>(
+        commitment: Self::Signature,
+        proof: Self::Signature,
+        pk: Self::PublicKey,
+        msg: B,
+        dst: D,
+    ) -> BlsResult<()> {
+        let y = Self::compute_noninteractive_y(commitment, pk, msg.as_ref(), dst.as_ref());
+        Self::verify(commitment, proof, pk, y, msg, dst)
+    }
+
     /// Verify the signature proof of knowledge
     fn verify<B: AsRef<[u8]>, D: AsRef<[u8]>>(
         commitment: Self::Signature,
@@ -106,6 +157,20 @@ pub trait BlsSignatureProof:
         y: <Self::Signature as Group>::Scalar,
         msg: B,
         dst: D,
+    ) -> BlsResult<()> {
+        let a = Self::hash_to_point(msg, dst);
+        debug_assert_eq!(a.is_identity().unwrap_u8(), 0u8);
+        Self::verify_with_base(commitment, proof, pk, y, a)
+    }
+
+    /// Verify the signature proof of knowledge where `base` is
+    /// the already hashed message point
+    fn verify_with_base(
+        commitment: Self::Signature,
+        proof: Self::Signature,
+        pk: Self::PublicKey,
+        y: <Self::Signature as Group>::Scalar,
+        base: Self::Signature,
     ) -> BlsResult<()> {
         if commitment.is_identity().into() {
             return Err(BlsError::InvalidInputs(
@@ -125,12 +190,15 @@ pub trait BlsSignatureProof:
         if y.is_zero().into() {
             return Err(BlsError::InvalidInputs("y is the zero".to_string()));
         }
+        if base.is_identity().into() {
+            return Err(BlsError::InvalidInputs(
+                "base is the identity point".to_string(),
+            ));
+        }
 
-        let a = Self::hash_to_point(msg, dst);
-        debug_assert_eq!(a.is_identity().unwrap_u8(), 0u8);
         if Self::pairing(&[
             (proof, <Self::PublicKey as Group>::generator()),
-            (commitment + a * y, pk),
+            (commitment + base * y, pk),
         ])
         .is_identity()
         .into()
@@ -141,7 +209,8 @@ pub trait BlsSignatureProof:
         }
     }
 
-    /// Verify a timestamp proof of knowledge
+    /// Verify a timestamp proof of knowledge using the current system time
+    #[cfg(feature = "std")]
     fn verify_timestamp_proof<B: AsRef<[u8]>, D: AsRef<[u8]>>(
         commitment: Self::Signature,
         proof: Self::Signature,
@@ -150,13 +219,29 @@ pub trait BlsSignatureProof:
         timeout_ms: Option<u64>,
         msg: B,
         dst: D,
+    ) -> BlsResult<()> {
+        Self::verify_timestamp_proof_at(commitment, proof, pk, t, now_ms(), timeout_ms, msg, dst)
+    }
+
+    /// Verify a timestamp proof of knowledge where `now` is the current
+    /// time in milliseconds since the unix epoch.
+    ///
+    /// Proofs with a timestamp after `now` are rejected when a timeout is given.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_timestamp_proof_at<B: AsRef<[u8]>, D: AsRef<[u8]>>(
+        commitment: Self::Signature,
+        proof: Self::Signature,
+        pk: Self::PublicKey,
+        t: u64,
+        now: u64,
+        timeout_ms: Option<u64>,
+        msg: B,
+        dst: D,
     ) -> BlsResult<()> {
         if let Some(tt) = timeout_ms {
-            let now = SystemTime::now();
-            let since = UNIX_EPOCH + Duration::from_millis(t);
-            let elapsed = now.duration_since(since).unwrap().as_millis() as u64;
-            if elapsed > tt {
-                return Err(BlsError::InvalidProof);
+            match now.checked_sub(t) {
+                Some(elapsed) if elapsed <= tt => {}
+                _ => return Err(BlsError::InvalidProof),
             }
         }
 
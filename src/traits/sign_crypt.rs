@@ -2,6 +2,7 @@ use super::*;
 use crate::helpers::*;
 use crate::impls::inner_types::*;
 use crate::{BlsError, BlsResult};
+use alloc::{string::ToString, vec, vec::Vec};
 use rand::Rng;
 use sha3::{
     digest::{ExtendableOutput, Update, XofReader},
@@ -36,28 +37,22 @@ pub trait BlsSignCrypt:
         message: B,
         dst: &[u8],
     ) -> (Self::PublicKey, Vec<u8>, Self::Signature) {
-        const SALT: &[u8] = b"SIGNCRYPT_BLS12381_XOF:HKDF-SHA2-256_";
-        let message = message.as_ref();
-
-        // r ← Zq
-        let r = Self::hash_to_scalar(get_crypto_rng().gen::<[u8; 32]>(), SALT);
-        debug_assert_eq!(r.is_zero().unwrap_u8(), 0u8);
-        // U = P^r
-        let u = Self::PublicKey::generator() * r;
-        debug_assert_eq!(u.is_identity().unwrap_u8(), 0u8);
-        // V = HℓX(R) ⊕ M
-        let overhead = uint_zigzag::Uint::from(message.len());
-        let mut overhead_bytes = overhead.to_vec();
-        overhead_bytes.extend_from_slice(message);
-        // Always use at least 32 bytes
-        while overhead_bytes.len() < 32 {
-            overhead_bytes.push(0u8);
-        }
-        let v = Self::compute_v(pk * r, overhead_bytes.as_slice());
-        // W = HG(U′ || V)^r
-        let w = Self::compute_w(u, v.as_slice(), dst) * r;
-        debug_assert_eq!(w.is_identity().unwrap_u8(), 0u8);
-        (u, v, w)
+        seal_inner::<Self>(pk, message.as_ref(), None, dst)
+    }
+
+    /// Create a new ciphertext bound to associated data
+    ///
+    /// The same as [`BlsSignCrypt::seal`] except `W` is computed with
+    /// [`BlsSignCrypt::compute_w_with_aad`] so the ciphertext is only valid
+    /// with the same associated data. Even empty associated data does not
+    /// produce a ciphertext that [`BlsSignCrypt::valid`] accepts.
+    fn seal_with_aad<B: AsRef<[u8]>>(
+        pk: Self::PublicKey,
+        message: B,
+        aad: &[u8],
+        dst: &[u8],
+    ) -> (Self::PublicKey, Vec<u8>, Self::Signature) {
+        seal_inner::<Self>(pk, message.as_ref(), Some(aad), dst)
     }
 
     /// Check if the ciphertext is valid
@@ -67,13 +62,18 @@ pub trait BlsSignCrypt:
     /// 2. Check no inputs are the infinity point
     /// 3. Check if e(W, P) = e(W', U)
     fn valid(u: Self::PublicKey, v: &[u8], w: Self::Signature, dst: &[u8]) -> Choice {
-        let w_tick = Self::compute_w(u, v, dst);
-        debug_assert_eq!(w_tick.is_identity().unwrap_u8(), 0u8);
-
-        let g = -Self::PublicKey::generator();
-        let pair_result = Self::pairing(&[(w, g), (w_tick, u)]);
+        valid_inner::<Self>(u, v, w, None, dst)
+    }
 
-        pair_result.is_identity() & !u.is_identity() & !w.is_identity()
+    /// Check if the ciphertext is valid for the associated data
+    fn valid_with_aad(
+        u: Self::PublicKey,
+        v: &[u8],
+        w: Self::Signature,
+        aad: &[u8],
+        dst: &[u8],
+    ) -> Choice {
+        valid_inner::<Self>(u, v, w, Some(aad), dst)
     }
 
     /// Open a ciphertext if the secret can verify the signature
@@ -89,13 +89,20 @@ pub trait BlsSignCrypt:
         sk: &<Self::PublicKey as Group>::Scalar,
         dst: &[u8],
     ) -> CtOption<Vec<u8>> {
-        let valid = Self::valid(u, v, w, dst);
-        let ua = u * ConditionallySelectable::conditional_select(
-            &<Self::PublicKey as Group>::Scalar::ZERO,
-            sk,
-            valid,
-        );
-        Self::decrypt(v, ua, valid)
+        unseal_inner::<Self>(u, v, w, sk, None, dst)
+    }
+
+    /// Open a ciphertext bound to associated data if the secret can
+    /// verify the signature
+    fn unseal_with_aad(
+        u: Self::PublicKey,
+        v: &[u8],
+        w: Self::Signature,
+        sk: &<Self::PublicKey as Group>::Scalar,
+        aad: &[u8],
+        dst: &[u8],
+    ) -> CtOption<Vec<u8>> {
+        unseal_inner::<Self>(u, v, w, sk, Some(aad), dst)
     }
 
     /// Open the ciphertext given the decryption shares.
@@ -119,6 +126,10 @@ pub trait BlsSignCrypt:
     }
 
     /// Decrypt a ciphertext
+    ///
+    /// The returned `Choice` must be checked. If the ciphertext is invalid
+    /// or the length prefix is malformed the value is empty and never the
+    /// ciphertext.
     fn decrypt(v: &[u8], ua: Self::PublicKey, valid: Choice) -> CtOption<Vec<u8>> {
         let plaintext = Self::compute_v(ua, v);
         if let Some(overhead) = uint_zigzag::Uint::peek(plaintext.as_slice()) {
@@ -132,7 +143,7 @@ pub trait BlsSignCrypt:
                 return CtOption::new(plaintext[overhead..overhead + len].to_vec(), valid);
             }
         }
-        CtOption::new(v.to_vec(), 0u8.into())
+        CtOption::new(Vec::new(), 0u8.into())
     }
 
     /// Compute the `V` value
@@ -159,6 +170,23 @@ pub trait BlsSignCrypt:
         Self::hash_to_point(t.as_slice(), dst)
     }
 
+    /// Compute the `W` value bound to associated data
+    ///
+    /// The length of the associated data is always appended so bytes
+    /// can't be moved between `V` and the associated data, and `dst` is
+    /// extended so the result never matches [`BlsSignCrypt::compute_w`].
+    fn compute_w_with_aad(u: Self::PublicKey, v: &[u8], aad: &[u8], dst: &[u8]) -> Self::Signature {
+        const AAD_DOMAIN: &[u8] = b"associated data";
+        // W = HG2(U′ || V || AAD || len(AAD))^r
+        let u_bytes = u.to_bytes();
+        let mut t = Vec::with_capacity(u_bytes.as_ref().len() + v.len() + aad.len() + 8);
+        t.extend_from_slice(u_bytes.as_ref());
+        t.extend_from_slice(v);
+        t.extend_from_slice(aad);
+        t.extend_from_slice(&(aad.len() as u64).to_be_bytes());
+        Self::hash_to_point(t.as_slice(), domain_dst(dst, AAD_DOMAIN))
+    }
+
     /// Create a sign crypt decryption share
     ///
     /// The math is as follows
@@ -206,3 +234,79 @@ pub trait BlsSignCrypt:
             & Self::pairing(&[(hash, share), (w, pk)]).is_identity()
     }
 }
+
+/// Compute `W` with [`BlsSignCrypt::compute_w_with_aad`] when there is
+/// associated data and [`BlsSignCrypt::compute_w`] otherwise
+fn compute_w_inner<C: BlsSignCrypt + ?Sized>(
+    u: C::PublicKey,
+    v: &[u8],
+    aad: Option<&[u8]>,
+    dst: &[u8],
+) -> C::Signature {
+    match aad {
+        Some(aad) => C::compute_w_with_aad(u, v, aad, dst),
+        None => C::compute_w(u, v, dst),
+    }
+}
+
+fn seal_inner<C: BlsSignCrypt + ?Sized>(
+    pk: C::PublicKey,
+    message: &[u8],
+    aad: Option<&[u8]>,
+    dst: &[u8],
+) -> (C::PublicKey, Vec<u8>, C::Signature) {
+    const SALT: &[u8] = b"SIGNCRYPT_BLS12381_XOF:HKDF-SHA2-256_";
+
+    // r ← Zq
+    let r = C::hash_to_scalar(get_crypto_rng().gen::<[u8; 32]>(), SALT);
+    debug_assert_eq!(r.is_zero().unwrap_u8(), 0u8);
+    // U = P^r
+    let u = C::PublicKey::generator() * r;
+    debug_assert_eq!(u.is_identity().unwrap_u8(), 0u8);
+    // V = HℓX(R) ⊕ M
+    let overhead = uint_zigzag::Uint::from(message.len());
+    let mut overhead_bytes = overhead.to_vec();
+    overhead_bytes.extend_from_slice(message);
+    // Always use at least 32 bytes
+    while overhead_bytes.len() < 32 {
+        overhead_bytes.push(0u8);
+    }
+    let v = C::compute_v(pk * r, overhead_bytes.as_slice());
+    // W = HG(U′ || V)^r or HG(U′ || V || AAD || len(AAD))^r
+    let w = compute_w_inner::<C>(u, v.as_slice(), aad, dst) * r;
+    debug_assert_eq!(w.is_identity().unwrap_u8(), 0u8);
+    (u, v, w)
+}
+
+fn valid_inner<C: BlsSignCrypt + ?Sized>(
+    u: C::PublicKey,
+    v: &[u8],
+    w: C::Signature,
+    aad: Option<&[u8]>,
+    dst: &[u8],
+) -> Choice {
+    let w_tick = compute_w_inner::<C>(u, v, aad, dst);
+    debug_assert_eq!(w_tick.is_identity().unwrap_u8(), 0u8);
+
+    let g = -C::PublicKey::generator();
+    let pair_result = C::pairing(&[(w, g), (w_tick, u)]);
+
+    pair_result.is_identity() & !u.is_identity() & !w.is_identity()
+}
+
+fn unseal_inner<C: BlsSignCrypt + ?Sized>(
+    u: C::PublicKey,
+    v: &[u8],
+    w: C::Signature,
+    sk: &<C::PublicKey as Group>::Scalar,
+    aad: Option<&[u8]>,
+    dst: &[u8],
+) -> CtOption<Vec<u8>> {
+    let valid = valid_inner::<C>(u, v, w, aad, dst);
+    let ua = u * ConditionallySelectable::conditional_select(
+        &<C::PublicKey as Group>::Scalar::ZERO,
+        sk,
+        valid,
+    );
+    C::decrypt(v, ua, valid)
+}
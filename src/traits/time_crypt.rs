@@ -97,7 +97,7 @@ pub trait BlsTimeCrypt:
             if len <= plaintext.len() - overhead {
                 message = plaintext[overhead..overhead + len].to_vec();
             } else {
-                return CtOption::new(w.to_vec(), 0u8.into());
+                return CtOption::new(Vec::new(), 0u8.into());
             }
         }
 
@@ -0,0 +1,44 @@
+use crate::*;
+
+/// A signature that can be checked against its verification context.
+///
+/// Lets code be generic over [`Signature`], [`MultiSignature`] and
+/// [`AggregateSignature`] instead of matching on each kind. The
+/// context is whatever the inherent `verify` method on each type
+/// needs, bundled into a single value.
+pub trait BlsVerifiable<C: BlsSignatureImpl> {
+    /// The public keys and messages needed to verify the signature
+    type Context<'a>
+    where
+        Self: 'a;
+
+    /// Verify the signature against `context`
+    fn verify(&self, context: Self::Context<'_>) -> BlsResult<()>;
+}
+
+impl<C: BlsSignatureImpl> BlsVerifiable<C> for Signature<C> {
+    /// The public key and the signed message
+    type Context<'a> = (&'a PublicKey<C>, &'a [u8]);
+
+    fn verify(&self, (pk, msg): Self::Context<'_>) -> BlsResult<()> {
+        Signature::verify(self, pk, msg)
+    }
+}
+
+impl<C: BlsSignatureImpl> BlsVerifiable<C> for MultiSignature<C> {
+    /// The aggregated public key and the signed message
+    type Context<'a> = (&'a MultiPublicKey<C>, &'a [u8]);
+
+    fn verify(&self, (pk, msg): Self::Context<'_>) -> BlsResult<()> {
+        MultiSignature::verify(self, *pk, msg)
+    }
+}
+
+impl<C: BlsSignatureImpl> BlsVerifiable<C> for AggregateSignature<C> {
+    /// Each signer's public key paired with the message they signed
+    type Context<'a> = &'a [(PublicKey<C>, &'a [u8])];
+
+    fn verify(&self, data: Self::Context<'_>) -> BlsResult<()> {
+        AggregateSignature::verify(self, data)
+    }
+}
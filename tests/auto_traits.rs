@@ -0,0 +1,68 @@
+use blsful::*;
+
+fn assert_auto_traits<T: Send + Sync + 'static>() {}
+
+fn assert_generic_types<C: BlsSignatureImpl + Send + Sync + 'static>() {
+    assert_auto_traits::<AffinePublicKey<C>>();
+    assert_auto_traits::<AggregateSignature<C>>();
+    assert_auto_traits::<AggregatedSignedMessages<C>>();
+    assert_auto_traits::<AnonCredProof<C>>();
+    assert_auto_traits::<AnonCredential<C>>();
+    assert_auto_traits::<DealerTranscript<C>>();
+    assert_auto_traits::<ElGamalCiphertext<C>>();
+    assert_auto_traits::<ElGamalDecryptionKey<C>>();
+    assert_auto_traits::<ElGamalDecryptionProof<C>>();
+    assert_auto_traits::<ElGamalDecryptionShare<C>>();
+    assert_auto_traits::<ElGamalPointProof<C>>();
+    assert_auto_traits::<ElGamalProof<C>>();
+    assert_auto_traits::<HashedMessage<C>>();
+    assert_auto_traits::<MessageHasher<'static, C>>();
+    assert_auto_traits::<MultiPublicKey<C>>();
+    assert_auto_traits::<MultiSignature<C>>();
+    assert_auto_traits::<PreparedPublicKey<C>>();
+    assert_auto_traits::<ProofCommitment<C>>();
+    assert_auto_traits::<ProofCommitmentChallenge<C>>();
+    assert_auto_traits::<ProofCommitmentSecret<C>>();
+    assert_auto_traits::<ProofOfKnowledge<C>>();
+    assert_auto_traits::<ProofOfKnowledgeTimestamp<C>>();
+    assert_auto_traits::<ProofOfPossession<C>>();
+    assert_auto_traits::<PublicKey<C>>();
+    assert_auto_traits::<PublicKeyShare<C>>();
+    assert_auto_traits::<SecretKey<C>>();
+    assert_auto_traits::<SecretKeyShare<C>>();
+    assert_auto_traits::<ShareCorrectnessProof<C>>();
+    assert_auto_traits::<SharingParams<C>>();
+    assert_auto_traits::<SignCryptCiphertext<C>>();
+    assert_auto_traits::<SignCryptDecryptionKey<C>>();
+    assert_auto_traits::<SignCryptReader<'static, C>>();
+    assert_auto_traits::<SignCryptWriter<C>>();
+    assert_auto_traits::<SignDecryptionShare<C>>();
+    assert_auto_traits::<Signature<C>>();
+    assert_auto_traits::<SignatureAggregator<C>>();
+    assert_auto_traits::<SignatureShare<C>>();
+    assert_auto_traits::<SignedMessage<C>>();
+    assert_auto_traits::<SlotSigner<C>>();
+    assert_auto_traits::<TimeCryptAnyCiphertext<C>>();
+    assert_auto_traits::<TimeCryptCiphertext<C>>();
+}
+
+#[test]
+fn public_types_are_send_sync_static() {
+    assert_generic_types::<Bls12381G1Impl>();
+    assert_generic_types::<Bls12381G2Impl>();
+    assert_generic_types::<Bls12381G1XofImpl>();
+    assert_generic_types::<Bls12381G2XofImpl>();
+
+    assert_auto_traits::<Bls12381>();
+    assert_auto_traits::<Bls12381G1Hasher>();
+    assert_auto_traits::<Bls12381G2Hasher>();
+    assert_auto_traits::<BlsError>();
+    assert_auto_traits::<CiphertextEnum>();
+    assert_auto_traits::<InnerPointShareG1>();
+    assert_auto_traits::<InnerPointShareG2>();
+    assert_auto_traits::<PublicKeyEnum>();
+    assert_auto_traits::<SecretKeyEnum>();
+    assert_auto_traits::<SignPolicy>();
+    assert_auto_traits::<SignatureEnum>();
+    assert_auto_traits::<SignatureSchemes>();
+}
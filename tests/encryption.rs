@@ -31,6 +31,196 @@ fn sign_crypt_works<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(#[ca
     }
 }
 
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn sign_crypt_with_aad_works<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let aad = b"recipient: alice";
+
+    let ciphertext = pk.sign_crypt_with_aad(SignatureSchemes::Basic, TEST_MSG, aad);
+    assert_eq!(ciphertext.is_valid_with_aad(aad).unwrap_u8(), 1u8);
+    let plaintext = ciphertext.decrypt_with_aad(&sk, aad);
+    assert_eq!(plaintext.is_some().unwrap_u8(), 1u8);
+    assert_eq!(plaintext.unwrap().as_slice(), TEST_MSG);
+
+    for wrong in [&b"recipient: bob"[..], &b""[..], &aad[..aad.len() - 1]] {
+        assert_eq!(ciphertext.is_valid_with_aad(wrong).unwrap_u8(), 0u8);
+        assert_eq!(
+            ciphertext
+                .decrypt_with_aad(&sk, wrong)
+                .is_none()
+                .unwrap_u8(),
+            1u8
+        );
+    }
+    assert_eq!(ciphertext.is_valid().unwrap_u8(), 0u8);
+    assert_eq!(ciphertext.decrypt(&sk).is_none().unwrap_u8(), 1u8);
+
+    // Bytes can't be moved from the ciphertext into the associated data
+    let mut moved = ciphertext.clone();
+    let last = moved.v.pop().unwrap();
+    let mut moved_aad = vec![last];
+    moved_aad.extend_from_slice(aad);
+    assert_eq!(moved.is_valid_with_aad(&moved_aad).unwrap_u8(), 0u8);
+
+    // The associated data can't be moved into the ciphertext and dropped
+    let mut stripped = ciphertext.clone();
+    stripped.v.extend_from_slice(aad);
+    stripped
+        .v
+        .extend_from_slice(&(aad.len() as u64).to_be_bytes());
+    assert_eq!(stripped.is_valid().unwrap_u8(), 0u8);
+    assert_eq!(stripped.is_valid_with_aad(b"").unwrap_u8(), 0u8);
+
+    // Empty associated data is still bound, unlike plain signcryption
+    let ciphertext = pk.sign_crypt(SignatureSchemes::Basic, TEST_MSG);
+    assert_eq!(ciphertext.is_valid_with_aad(b"").unwrap_u8(), 0u8);
+    assert_eq!(ciphertext.is_valid_with_aad(aad).unwrap_u8(), 0u8);
+    let ciphertext = pk.sign_crypt_with_aad(SignatureSchemes::Basic, TEST_MSG, b"");
+    assert_eq!(ciphertext.is_valid_with_aad(b"").unwrap_u8(), 1u8);
+    assert_eq!(ciphertext.is_valid().unwrap_u8(), 0u8);
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+#[cfg(feature = "std")]
+fn sign_crypt_chunks_match_one_shot<C: BlsSignatureImpl>(#[case] _c: C) {
+    use std::io::Read;
+
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let msg = (0..5 * 1024 * 1024)
+        .map(|i| (i % 251) as u8)
+        .collect::<Vec<_>>();
+
+    let mut writer = SignCryptWriter::new(&pk, SignatureSchemes::Basic, msg.len());
+    for chunk in msg.chunks(64 * 1024 + 7) {
+        writer.update(chunk).unwrap();
+    }
+    let ciphertext = writer.finalize().unwrap();
+    let plaintext = ciphertext.decrypt(&sk);
+    assert_eq!(plaintext.is_some().unwrap_u8(), 1u8);
+    assert!(plaintext.unwrap() == msg);
+
+    let ciphertext = pk.sign_crypt(SignatureSchemes::Basic, &msg);
+    let mut reader = SignCryptReader::new(&ciphertext, &sk).unwrap();
+    assert_eq!(reader.remaining(), msg.len());
+    let mut chunk = vec![0u8; 64 * 1024 + 7];
+    let mut offset = 0;
+    loop {
+        let n = reader.read(&mut chunk).unwrap();
+        if n == 0 {
+            break;
+        }
+        assert!(chunk[..n] == msg[offset..offset + n]);
+        offset += n;
+    }
+    assert_eq!(offset, msg.len());
+
+    // Short messages are padded the same way
+    for msg in [&b""[..], TEST_MSG] {
+        let mut writer = SignCryptWriter::new(&pk, SignatureSchemes::Basic, msg.len());
+        writer.update(msg).unwrap();
+        let ciphertext = writer.finalize().unwrap();
+        assert_eq!(
+            ciphertext.v.len(),
+            pk.sign_crypt(SignatureSchemes::Basic, msg).v.len()
+        );
+        let mut plaintext = Vec::new();
+        SignCryptReader::new(&ciphertext, &sk)
+            .unwrap()
+            .read_to_end(&mut plaintext)
+            .unwrap();
+        assert_eq!(plaintext, msg);
+    }
+
+    let mut writer = SignCryptWriter::new(&pk, SignatureSchemes::Basic, 4);
+    assert!(writer.update(&[0u8; 5]).is_err());
+    writer.update(&[0u8; 3]).unwrap();
+    assert!(writer.finalize().is_err());
+
+    let mut tampered = ciphertext.clone();
+    tampered.v[0] ^= 1;
+    assert!(SignCryptReader::new(&tampered, &sk).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn sign_crypt_bad_length_prefix_returns_no_ciphertext<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+
+    // Build a validly signed ciphertext whose plaintext has a length
+    // prefix far larger than the message
+    let r = SecretKey::<C>::new();
+    let u = r.public_key().0;
+    let mut plaintext = vec![0xffu8; 9];
+    plaintext.push(0x01);
+    plaintext.resize(32, 0u8);
+    let keystream = <C as BlsSignCrypt>::compute_v(pk.0 * r.0, &[0u8; 32]);
+    let v = plaintext
+        .iter()
+        .zip(keystream.iter())
+        .map(|(p, k)| p ^ k)
+        .collect::<Vec<_>>();
+    let w = <C as BlsSignCrypt>::compute_w(u, &v, <C as BlsSignatureBasic>::DST) * r.0;
+    let ciphertext = SignCryptCiphertext::<C> {
+        u,
+        v,
+        w,
+        scheme: SignatureSchemes::Basic,
+    };
+    assert_eq!(ciphertext.is_valid().unwrap_u8(), 1u8);
+
+    let res = ciphertext.decrypt(&sk);
+    assert_eq!(res.is_none().unwrap_u8(), 1u8);
+    assert!(format!("{:?}", res).contains("value: []"));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn sign_crypt_ciphertext_tagged_bytes<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(
+    #[case] _c: C,
+) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let ciphertext = pk.sign_crypt(SignatureSchemes::MessageAugmentation, TEST_MSG);
+    let bytes = ciphertext.to_bytes();
+    assert_eq!(bytes[0], u8::from(SignCryptCiphertext::<C>::curve()));
+    assert_eq!(bytes[1], SignatureSchemes::MessageAugmentation as u8);
+    assert_eq!(
+        bytes.len(),
+        10 + <C as Pairing>::PUBLIC_KEY_BYTES
+            + ciphertext.v.len()
+            + <C as Pairing>::SIGNATURE_BYTES
+    );
+
+    let decoded = SignCryptCiphertext::<C>::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, ciphertext);
+    assert_eq!(decoded.decrypt(&sk).unwrap(), TEST_MSG);
+
+    for len in [0, 1, bytes.len() / 2, bytes.len() - 1] {
+        assert!(matches!(
+            SignCryptCiphertext::<C>::from_bytes(&bytes[..len]),
+            Err(BlsError::DeserializationError(_))
+        ));
+    }
+    let mut longer = bytes.clone();
+    longer.push(0);
+    assert!(SignCryptCiphertext::<C>::from_bytes(&longer).is_err());
+    let mut bad_scheme = bytes.clone();
+    bad_scheme[1] = 3;
+    assert!(SignCryptCiphertext::<C>::from_bytes(&bad_scheme).is_err());
+    let mut other_curve = bytes.clone();
+    other_curve[0] = 3 - other_curve[0];
+    assert!(SignCryptCiphertext::<C>::from_bytes(&other_curve).is_err());
+}
+
 #[rstest]
 #[case::g1(Bls12381G1Impl)]
 #[case::g2(Bls12381G2Impl)]
@@ -86,6 +276,58 @@ fn time_lock_works<C: BlsSignatureImpl>(#[case] _c: C) {
     assert_eq!(plaintext.is_some().unwrap_u8(), 0u8);
 }
 
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn time_lock_seal_ephemeral_works<C: BlsSignatureImpl>(#[case] _c: C) {
+    let time_sk = SecretKey::<C>::new();
+    let time_pk = time_sk.public_key();
+    let recipient_sk = SecretKey::<C>::new();
+    let recipient_pk = recipient_sk.public_key();
+    let ciphertext = time_pk
+        .seal_ephemeral(&recipient_pk, SignatureSchemes::Basic, TEST_MSG, TEST_ID)
+        .unwrap();
+
+    // Before the time lock opens nobody can read it, not even the recipient
+    let bad_sig = time_sk.sign(SignatureSchemes::Basic, BAD_MSG).unwrap();
+    let res = ciphertext.decrypt_ephemeral(&bad_sig, &recipient_sk);
+    assert_eq!(res.is_some().unwrap_u8(), 0u8);
+
+    // After it opens only the recipient can read it
+    let sig = time_sk.sign(SignatureSchemes::Basic, TEST_ID).unwrap();
+    let res = ciphertext.decrypt_ephemeral(&sig, &SecretKey::<C>::new());
+    assert_eq!(res.is_some().unwrap_u8(), 0u8);
+    let res = ciphertext.decrypt_ephemeral(&sig, &time_sk);
+    assert_eq!(res.is_some().unwrap_u8(), 0u8);
+    let res = ciphertext.decrypt_ephemeral(&sig, &recipient_sk);
+    assert_eq!(res.is_some().unwrap_u8(), 1u8);
+    assert_eq!(res.unwrap().as_slice(), TEST_MSG);
+
+    // The opened time lock layer is still sign crypted
+    let inner = ciphertext.decrypt(&sig).unwrap();
+    assert_ne!(inner.as_slice(), TEST_MSG);
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn time_lock_boundary_lengths<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let sig = sk.sign(SignatureSchemes::Basic, TEST_ID).unwrap();
+    // 31 bytes plus the one byte length exactly fill the 32 byte minimum
+    // and 127/128 are where the length prefix grows to two bytes
+    for len in [0usize, 1, 30, 31, 32, 127, 128] {
+        let msg = vec![0xa5u8; len];
+        let ciphertext = pk
+            .encrypt_time_lock(SignatureSchemes::Basic, &msg, TEST_ID)
+            .unwrap();
+        let plaintext = ciphertext.decrypt(&sig);
+        assert_eq!(plaintext.is_some().unwrap_u8(), 1u8, "length {}", len);
+        assert_eq!(plaintext.unwrap(), msg);
+    }
+}
+
 #[test]
 fn time_lock_works_g1() {
     let sk = SecretKey::<Bls12381G1Impl>::new();
@@ -126,6 +368,156 @@ fn time_lock_all_schemes(#[case] scheme: SignatureSchemes) {
     assert_eq!(res.is_some().unwrap_u8(), 1u8);
 }
 
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn time_lock_decrypt_checked_reports_reason<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let ciphertext = pk
+        .encrypt_time_lock(SignatureSchemes::Basic, TEST_MSG, TEST_ID)
+        .unwrap();
+
+    let sig = sk.sign(SignatureSchemes::Basic, TEST_ID).unwrap();
+    assert_eq!(
+        ciphertext.decrypt_checked(&sig).unwrap().as_slice(),
+        TEST_MSG
+    );
+
+    let bad_scheme = sk
+        .sign(SignatureSchemes::MessageAugmentation, TEST_ID)
+        .unwrap();
+    assert!(matches!(
+        ciphertext.decrypt_checked(&bad_scheme),
+        Err(BlsError::InvalidSignatureScheme)
+    ));
+
+    let bad_id = sk.sign(SignatureSchemes::Basic, BAD_MSG).unwrap();
+    assert!(matches!(
+        ciphertext.decrypt_checked(&bad_id),
+        Err(BlsError::DecryptionFailed)
+    ));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn time_lock_any_recipient<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sks = [0, 1, 2].map(|_| SecretKey::<C>::new());
+    let pks = sks.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+    let ciphertext =
+        PublicKey::encrypt_time_lock_any(&pks, SignatureSchemes::Basic, TEST_MSG, TEST_ID).unwrap();
+    assert_eq!(ciphertext.branches.len(), 3);
+
+    let sig = sks[1].sign(SignatureSchemes::Basic, TEST_ID).unwrap();
+    let plaintext = ciphertext.decrypt(&sig);
+    assert_eq!(plaintext.is_some().unwrap_u8(), 1u8);
+    assert_eq!(plaintext.unwrap().as_slice(), TEST_MSG);
+
+    let bytes = Vec::from(&ciphertext);
+    let ciphertext2 = TimeCryptAnyCiphertext::<C>::try_from(bytes).unwrap();
+    assert_eq!(ciphertext2, ciphertext);
+
+    let unrelated = SecretKey::<C>::new()
+        .sign(SignatureSchemes::Basic, TEST_ID)
+        .unwrap();
+    assert_eq!(ciphertext.decrypt(&unrelated).is_none().unwrap_u8(), 1u8);
+    let wrong_id = sks[1].sign(SignatureSchemes::Basic, BAD_MSG).unwrap();
+    assert_eq!(ciphertext.decrypt(&wrong_id).is_none().unwrap_u8(), 1u8);
+
+    let mut tampered = ciphertext.clone();
+    tampered.payload[0] ^= 1;
+    assert_eq!(tampered.decrypt(&sig).is_none().unwrap_u8(), 1u8);
+
+    assert!(
+        PublicKey::<C>::encrypt_time_lock_any(&[], SignatureSchemes::Basic, TEST_MSG, TEST_ID)
+            .is_err()
+    );
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn time_lock_unlocks_with_threshold_signature_shares<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let sig_shares = sk
+        .split(2, 3)
+        .unwrap()
+        .iter()
+        .map(|s| s.sign(SignatureSchemes::Basic, TEST_ID).unwrap())
+        .collect::<Vec<_>>();
+    let ciphertext = pk
+        .encrypt_time_lock(SignatureSchemes::Basic, TEST_MSG, TEST_ID)
+        .unwrap();
+
+    for pair in [[0, 1], [0, 2], [1, 2]] {
+        let subset = pair.map(|i| sig_shares[i]);
+        let res = ciphertext.decrypt_with_shares(&subset, 2, &pk, TEST_ID);
+        assert_eq!(res.unwrap().as_slice(), TEST_MSG);
+    }
+    assert!(matches!(
+        ciphertext.decrypt_with_shares(&sig_shares[..1], 2, &pk, TEST_ID),
+        Err(BlsError::InsufficientShares(2, 1))
+    ));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn time_lock_decrypt_with_shares_errors<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let shares = sk.split(3, 5).unwrap();
+    let sig_shares = shares
+        .iter()
+        .map(|s| s.sign(SignatureSchemes::Basic, TEST_ID).unwrap())
+        .collect::<Vec<_>>();
+    let ciphertext = pk
+        .encrypt_time_lock(SignatureSchemes::Basic, TEST_MSG, TEST_ID)
+        .unwrap();
+
+    let res = ciphertext.decrypt_with_shares(&sig_shares[..3], 3, &pk, TEST_ID);
+    assert!(res.is_ok());
+    assert_eq!(res.unwrap().as_slice(), TEST_MSG);
+
+    let res = ciphertext.decrypt_with_shares(&sig_shares[..2], 3, &pk, TEST_ID);
+    assert!(matches!(res, Err(BlsError::InsufficientShares(3, 2))));
+
+    let res = ciphertext.decrypt_with_shares(
+        &[sig_shares[0], sig_shares[1], sig_shares[0]],
+        3,
+        &pk,
+        TEST_ID,
+    );
+    assert!(matches!(res, Err(BlsError::DuplicateShareIdentifier)));
+
+    let bad_shares = shares
+        .iter()
+        .map(|s| s.sign(SignatureSchemes::Basic, BAD_MSG).unwrap())
+        .collect::<Vec<_>>();
+    let res = ciphertext.decrypt_with_shares(&bad_shares[..3], 3, &pk, TEST_ID);
+    assert!(matches!(res, Err(BlsError::InvalidSignature)));
+    let mixed = [sig_shares[0], sig_shares[1], bad_shares[2]];
+    let res = ciphertext.decrypt_with_shares(&mixed, 3, &pk, TEST_ID);
+    assert!(matches!(res, Err(BlsError::InvalidSignature)));
+
+    let mut corrupt = ciphertext.clone();
+    corrupt.v[0] ^= 1;
+    let res = corrupt.decrypt_with_shares(&sig_shares[..3], 3, &pk, TEST_ID);
+    assert!(matches!(res, Err(BlsError::DecryptionFailed)));
+
+    let pop_shares = shares
+        .iter()
+        .map(|s| {
+            s.sign(SignatureSchemes::ProofOfPossession, TEST_ID)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let res = ciphertext.decrypt_with_shares(&pop_shares[..3], 3, &pk, TEST_ID);
+    assert!(matches!(res, Err(BlsError::InvalidSignatureScheme)));
+}
+
 #[rstest]
 #[case::g1_basic(Bls12381G1Impl, SignatureSchemes::Basic)]
 #[case::g1_pop(Bls12381G1Impl, SignatureSchemes::ProofOfPossession)]
@@ -184,6 +576,217 @@ fn elgamal_ciphertext_works<C: BlsSignatureImpl>(#[case] _c: C) {
     );
 }
 
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn elgamal_ciphertext_scale_and_sub<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let generator = <C as BlsElGamal>::message_generator();
+
+    for _ in 0..10 {
+        let a = SecretKey::<C>::new();
+        let b = SecretKey::<C>::new();
+        let k = SecretKey::<C>::new();
+        let ct_a = pk.encrypt_key_el_gamal(&a).unwrap();
+        let ct_b = pk.encrypt_key_el_gamal(&b).unwrap();
+
+        assert_eq!(ct_a.scale(k.0).decrypt(&sk), ct_a.decrypt(&sk) * k.0);
+        assert_eq!((ct_a * k.0).decrypt(&sk), generator * (a.0 * k.0));
+        assert_eq!((&ct_a * k.0), ct_a.scale(k.0));
+        let mut scaled = ct_a;
+        scaled *= k.0;
+        assert_eq!(scaled, ct_a.scale(k.0));
+
+        assert_eq!((ct_a - ct_b).decrypt(&sk), generator * (a.0 - b.0));
+        assert_eq!(&ct_a - &ct_b, ct_a - ct_b);
+        let mut diff = ct_a;
+        diff -= ct_b;
+        assert_eq!(diff, ct_a - ct_b);
+        diff += &ct_b;
+        assert_eq!(diff.decrypt(&sk), ct_a.decrypt(&sk));
+    }
+
+    // Cancelling a vote leaves the other votes
+    let one = SecretKey::<C>(From::from(1u64));
+    let votes = [0, 1, 2].map(|_| pk.encrypt_key_el_gamal(&one).unwrap());
+    let tally = votes[0] + votes[1] + votes[2] - votes[1];
+    assert_eq!(tally.decrypt_scalar(&sk, 10).unwrap(), Some(2));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn elgamal_ciphertext_rerandomize<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let secret = SecretKey::<C>::new();
+    let ciphertext = pk.encrypt_key_el_gamal(&secret).unwrap();
+
+    let rerandomized = ciphertext.rerandomize(&pk, MockRng::default());
+    assert_ne!(rerandomized.to_bytes(), ciphertext.to_bytes());
+    assert_ne!(rerandomized.c1, ciphertext.c1);
+    assert_ne!(rerandomized.c2, ciphertext.c2);
+    assert_eq!(rerandomized.decrypt(&sk), ciphertext.decrypt(&sk));
+
+    let blinder = SecretKey::<C>::new();
+    let res1 = ciphertext.rerandomize_with_blinder(&pk, &blinder).unwrap();
+    let res2 = ciphertext.rerandomize_with_blinder(&pk, &blinder).unwrap();
+    assert_eq!(res1, res2);
+    assert_ne!(res1, ciphertext);
+    assert_eq!(res1.decrypt(&sk), ciphertext.decrypt(&sk));
+
+    let zero = SecretKey::<C>(Default::default());
+    assert!(ciphertext.rerandomize_with_blinder(&pk, &zero).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn elgamal_decrypt_scalar_recovers_small_messages<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+
+    for m in 0..1000u64 {
+        let message = SecretKey::<C>(From::from(m));
+        let ciphertext = pk.encrypt_key_el_gamal(&message).unwrap();
+        assert_eq!(ciphertext.decrypt_scalar(&sk, 999).unwrap(), Some(m));
+    }
+
+    let message = SecretKey::<C>(From::from(1000u64));
+    let ciphertext = pk.encrypt_key_el_gamal(&message).unwrap();
+    assert_eq!(ciphertext.decrypt_scalar(&sk, 999).unwrap(), None);
+    assert_eq!(ciphertext.decrypt_scalar(&sk, 1000).unwrap(), Some(1000));
+    assert_eq!(
+        ciphertext
+            .decrypt_scalar(&SecretKey::<C>::new(), 1000)
+            .unwrap(),
+        None
+    );
+
+    // Bounds that would take too long are rejected up front
+    for max in [MAX_DISCRETE_LOG + 1, u64::MAX - 1, u64::MAX] {
+        assert!(matches!(
+            ciphertext.decrypt_scalar(&sk, max),
+            Err(BlsError::InvalidInputs(_))
+        ));
+    }
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn elgamal_with_shares_works<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let secret = SecretKey::<C>::new();
+    let ciphertext = pk.encrypt_key_el_gamal(&secret).unwrap();
+    let expected = <C as BlsElGamal>::message_generator() * secret.0;
+
+    let key_shares = sk.split(2, 3).unwrap();
+    let shares = key_shares
+        .iter()
+        .map(|s| ciphertext.create_decryption_share(s).unwrap())
+        .collect::<Vec<_>>();
+
+    for pair in [[0, 1], [0, 2], [1, 2]] {
+        let subset = [shares[pair[0]].clone(), shares[pair[1]].clone()];
+        let res = ciphertext.decrypt_with_shares(&subset);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), expected);
+
+        let key = ElGamalDecryptionKey::from_shares(&subset).unwrap();
+        assert_eq!(key.decrypt(&ciphertext), expected);
+    }
+
+    assert!(ciphertext.decrypt_with_shares(&shares[..1]).is_err());
+
+    // Shares of another key don't decrypt
+    let other_shares = SecretKey::<C>::new()
+        .split(2, 3)
+        .unwrap()
+        .iter()
+        .map(|s| ciphertext.create_decryption_share(s).unwrap())
+        .collect::<Vec<_>>();
+    let res = ciphertext.decrypt_with_shares(&other_shares[..2]).unwrap();
+    assert_ne!(res, expected);
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn elgamal_decryption_shares_are_verifiable<C: BlsSignatureImpl>(#[case] _c: C) {
+    use blsful::inner_types::Group;
+    use blsful::vsss_rs::{Share, ValueGroup};
+
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let secret = SecretKey::<C>::new();
+    let ciphertext = pk.encrypt_key_el_gamal(&secret).unwrap();
+    let expected = <C as BlsElGamal>::message_generator() * secret.0;
+
+    let key_shares = sk.split(2, 3).unwrap();
+    let public_shares = key_shares
+        .iter()
+        .map(|s| s.public_key().unwrap())
+        .collect::<Vec<_>>();
+    let shares = key_shares
+        .iter()
+        .map(|s| s.elgamal_decryption_share(&ciphertext).unwrap())
+        .collect::<Vec<_>>();
+    for (share, pks) in shares.iter().zip(public_shares.iter()) {
+        assert!(share.verify(pks, &ciphertext).is_ok());
+    }
+    assert!(shares[0].verify(&public_shares[1], &ciphertext).is_err());
+    assert_eq!(
+        ciphertext.decrypt_with_shares(&shares[..2]).unwrap(),
+        expected
+    );
+
+    // A share with a replaced point no longer matches its proof
+    let mut forged = shares[0].clone();
+    *forged.0.value_mut() = ValueGroup(forged.0.value().0.double());
+    assert!(forged.verify(&public_shares[0], &ciphertext).is_err());
+
+    // Shares from another ciphertext don't verify
+    let other = pk.encrypt_key_el_gamal(&secret).unwrap();
+    assert!(shares[0].verify(&public_shares[0], &other).is_err());
+
+    // Unproven shares can't be verified
+    let unproven = ciphertext.create_decryption_share(&key_shares[0]).unwrap();
+    assert!(matches!(
+        unproven.verify(&public_shares[0], &ciphertext),
+        Err(BlsError::InvalidProof)
+    ));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn elgamal_with_blinder_is_deterministic<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let message = SecretKey::<C>::new();
+    let blinder = SecretKey::<C>::new();
+
+    let res = pk.encrypt_key_el_gamal_with_blinder(&message, &blinder);
+    assert!(res.is_ok());
+    let ciphertext1 = res.unwrap();
+    let res = pk.encrypt_key_el_gamal_with_blinder(&message, &blinder);
+    assert!(res.is_ok());
+    let ciphertext2 = res.unwrap();
+    assert_eq!(ciphertext1, ciphertext2);
+    assert_eq!(
+        ciphertext1.decrypt(&sk),
+        <C as BlsElGamal>::message_generator() * message.0
+    );
+
+    let zero = SecretKey::<C>(Default::default());
+    assert!(pk
+        .encrypt_key_el_gamal_with_blinder(&message, &zero)
+        .is_err());
+}
+
 #[rstest]
 #[case::g1(Bls12381G1Impl)]
 #[case::g2(Bls12381G2Impl)]
@@ -203,3 +806,106 @@ fn elgamal_proofs_work<C: BlsSignatureImpl>(#[case] _c: C) {
         <C as BlsElGamal>::message_generator() * secret.0
     );
 }
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn elgamal_proof_verify_with_decryption_key<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let secret = SecretKey::<C>::new();
+    let proof = pk.encrypt_key_el_gamal_with_proof(&secret).unwrap();
+
+    let shares = sk
+        .split(2, 3)
+        .unwrap()
+        .iter()
+        .map(|s| proof.ciphertext.create_decryption_share(s).unwrap())
+        .collect::<Vec<_>>();
+    let dk = ElGamalDecryptionKey::from_shares(&shares[..2]).unwrap();
+
+    let res = proof.verify_with_decryption_key(&pk, &dk);
+    assert_eq!(
+        res.unwrap(),
+        <C as BlsElGamal>::message_generator() * secret.0
+    );
+
+    let wrong_pk = SecretKey::<C>::new().public_key();
+    assert!(proof.verify_with_decryption_key(&wrong_pk, &dk).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn elgamal_point_encryption_works<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let point = SecretKey::<C>::new().public_key().0;
+
+    let ciphertext = pk.encrypt_point_el_gamal(point).unwrap();
+    assert_eq!(ciphertext.decrypt(&sk), point);
+
+    let proof = pk.encrypt_point_el_gamal_with_proof(point).unwrap();
+    assert!(proof.verify(pk).is_ok());
+    assert_eq!(proof.verify_and_decrypt(&sk).unwrap(), point);
+    assert!(proof.verify(SecretKey::<C>::new().public_key()).is_err());
+
+    // Tampering with the ciphertext breaks the proof
+    let mut bad = proof;
+    bad.ciphertext.c2 += point;
+    assert!(bad.verify(pk).is_err());
+    let mut bad = proof;
+    bad.ciphertext.c1 += pk.0;
+    assert!(bad.verify(pk).is_err());
+    assert!(bad.verify_and_decrypt(&sk).is_err());
+}
+
+#[test]
+fn ciphertext_enum_dispatches_on_tags() {
+    let sk_g1 = SecretKey::<Bls12381G1Impl>::new();
+    let sk_g2 = SecretKey::<Bls12381G2Impl>::new();
+    let sign_crypt = CiphertextEnum::from(
+        sk_g1
+            .public_key()
+            .sign_crypt(SignatureSchemes::Basic, TEST_MSG),
+    );
+    let time_crypt = CiphertextEnum::from(
+        sk_g2
+            .public_key()
+            .encrypt_time_lock(SignatureSchemes::Basic, TEST_MSG, TEST_ID)
+            .unwrap(),
+    );
+    assert_eq!(sign_crypt.curve(), Bls12381::G1);
+    assert!(!sign_crypt.is_time_lock());
+    assert_eq!(time_crypt.curve(), Bls12381::G2);
+    assert!(time_crypt.is_time_lock());
+
+    for ciphertext in [&sign_crypt, &time_crypt] {
+        let bytes = Vec::<u8>::from(ciphertext);
+        assert_eq!(CiphertextEnum::try_from(bytes).unwrap(), *ciphertext);
+        let json = serde_json::to_string(ciphertext).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CiphertextEnum>(&json).unwrap(),
+            *ciphertext
+        );
+    }
+    assert!(CiphertextEnum::try_from(&[1u8][..]).is_err());
+    assert!(CiphertextEnum::try_from(&[1u8, 3u8][..]).is_err());
+
+    let plaintext = sign_crypt
+        .decrypt_with_secret_key(&SecretKeyEnum::G1(sk_g1.clone()))
+        .unwrap();
+    assert_eq!(plaintext.as_slice(), TEST_MSG);
+    assert!(sign_crypt
+        .decrypt_with_secret_key(&SecretKeyEnum::G2(sk_g2.clone()))
+        .is_err());
+
+    let sig = sk_g2.sign(SignatureSchemes::Basic, TEST_ID).unwrap();
+    let plaintext = time_crypt
+        .decrypt_with_signature(&SignatureEnum::G2(sig))
+        .unwrap();
+    assert_eq!(plaintext.as_slice(), TEST_MSG);
+    assert!(time_crypt
+        .decrypt_with_secret_key(&SecretKeyEnum::G2(sk_g2))
+        .is_err());
+}
@@ -25,6 +25,108 @@ fn proof_of_knowledge_works<C: BlsSignatureImpl + Copy>(#[case] _c: C) {
 #[rstest]
 #[case::g1(Bls12381G1Impl)]
 #[case::g2(Bls12381G2Impl)]
+fn proof_of_knowledge_noninteractive_works<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    for scheme in [SignatureSchemes::Basic, SignatureSchemes::ProofOfPossession] {
+        let sig = sk.sign(scheme, TEST_MSG).unwrap();
+        let proof = ProofOfKnowledge::create_noninteractive(TEST_MSG, sig, pk).unwrap();
+        assert_eq!(proof.scheme(), scheme);
+        assert!(proof.verify_noninteractive(pk, TEST_MSG).is_ok());
+        assert!(proof.verify_noninteractive(pk, BAD_MSG).is_err());
+        let other_pk = SecretKey::<C>::new().public_key();
+        assert!(proof.verify_noninteractive(other_pk, TEST_MSG).is_err());
+
+        // A proof for another message doesn't verify for this one
+        let bad_sig = sk.sign(scheme, BAD_MSG).unwrap();
+        let bad_proof = ProofOfKnowledge::create_noninteractive(BAD_MSG, bad_sig, pk).unwrap();
+        assert!(bad_proof.verify_noninteractive(pk, BAD_MSG).is_ok());
+        assert!(bad_proof.verify_noninteractive(pk, TEST_MSG).is_err());
+    }
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn proof_of_knowledge_verify_batch<C: BlsSignatureImpl>(#[case] _c: C) {
+    let mut items = Vec::new();
+    for _ in 0..5 {
+        let sk = SecretKey::<C>::new();
+        let pk = sk.public_key();
+        let sig = sk
+            .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+            .unwrap();
+        let (comm, x) = ProofCommitment::generate(TEST_MSG, sig).unwrap();
+        let y = ProofCommitmentChallenge::<C>::new();
+        let proof = comm.finalize(x, y, sig).unwrap();
+        items.push((pk, TEST_MSG, y, proof));
+    }
+    assert!(ProofOfKnowledge::verify_batch(&items, MockRng::default()).is_ok());
+
+    // Wrong challenge and wrong message
+    items[1].2 = ProofCommitmentChallenge::<C>::new();
+    items[3].1 = BAD_MSG;
+    assert_eq!(
+        ProofOfKnowledge::verify_batch(&items, MockRng::default()),
+        Err(vec![1, 3])
+    );
+
+    // Scheme mismatches are reported without checking the pairing
+    let sk = SecretKey::<C>::new();
+    let sig = sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+    let (comm, x) = ProofCommitment::generate(TEST_MSG, sig).unwrap();
+    let y = ProofCommitmentChallenge::<C>::new();
+    items.push((
+        sk.public_key(),
+        TEST_MSG,
+        y,
+        comm.finalize(x, y, sig).unwrap(),
+    ));
+    assert_eq!(
+        ProofOfKnowledge::verify_batch(&items, MockRng::default()),
+        Err(vec![5])
+    );
+
+    assert_eq!(
+        ProofOfKnowledge::<C>::verify_batch(&[], MockRng::default()),
+        Err(vec![])
+    );
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn proof_of_knowledge_fixed_size_bytes<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    for scheme in [
+        SignatureSchemes::Basic,
+        SignatureSchemes::MessageAugmentation,
+        SignatureSchemes::ProofOfPossession,
+    ] {
+        let sig = sk.sign(scheme, TEST_MSG).unwrap();
+        let proof = ProofOfKnowledge::create_noninteractive(TEST_MSG, sig, pk).unwrap();
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), 1 + 2 * <C as Pairing>::SIGNATURE_BYTES);
+        assert_eq!(bytes.len(), ProofOfKnowledge::<C>::BYTES);
+        assert_eq!(bytes[0], scheme as u8);
+
+        let decoded = ProofOfKnowledge::<C>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, proof);
+        assert_eq!(decoded.to_bytes(), bytes);
+        assert!(decoded.verify_noninteractive(pk, TEST_MSG).is_ok());
+
+        assert!(ProofOfKnowledge::<C>::from_bytes(&bytes[1..]).is_err());
+        let mut bad_scheme = bytes.clone();
+        bad_scheme[0] = 3;
+        assert!(ProofOfKnowledge::<C>::from_bytes(&bad_scheme).is_err());
+    }
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+#[cfg(feature = "std")]
 fn proof_of_knowledge_timestamp_works<C: BlsSignatureImpl>(#[case] _c: C) {
     let sk = SecretKey::<C>::new();
     let pk = sk.public_key();
@@ -36,3 +138,152 @@ fn proof_of_knowledge_timestamp_works<C: BlsSignatureImpl>(#[case] _c: C) {
     proof.timestamp -= 10;
     assert!(proof.verify(pk, TEST_MSG, Some(3)).is_err());
 }
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn proof_of_knowledge_timestamp_fixed_clock<C: BlsSignatureImpl>(#[case] _c: C) {
+    const NOW: u64 = 1_700_000_000_000;
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let sig = sk
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    let proof = ProofOfKnowledgeTimestamp::generate_with_time(TEST_MSG, sig, NOW).unwrap();
+    assert_eq!(proof.timestamp, NOW);
+    assert!(proof.verify_at(pk, TEST_MSG, NOW, Some(0)).is_ok());
+    assert!(proof.verify_at(pk, TEST_MSG, NOW + 5, Some(5)).is_ok());
+    assert!(proof.verify_at(pk, TEST_MSG, NOW + 6, Some(5)).is_err());
+    // A proof from the future is rejected rather than panicking
+    assert!(proof.verify_at(pk, TEST_MSG, NOW - 1, Some(5)).is_err());
+    assert!(proof.verify_at(pk, TEST_MSG, NOW + 1_000_000, None).is_ok());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn proof_of_knowledge_timestamp_extreme_clock<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let sig = sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+
+    let proof = ProofOfKnowledgeTimestamp::generate_with_time(TEST_MSG, sig, 0).unwrap();
+    assert!(proof
+        .verify_at(pk, TEST_MSG, u64::MAX, Some(u64::MAX))
+        .is_ok());
+    assert!(proof
+        .verify_at(pk, TEST_MSG, u64::MAX, Some(u64::MAX - 1))
+        .is_err());
+
+    let proof = ProofOfKnowledgeTimestamp::generate_with_time(TEST_MSG, sig, u64::MAX).unwrap();
+    assert!(proof.verify_at(pk, TEST_MSG, u64::MAX, Some(0)).is_ok());
+    assert!(proof.verify_at(pk, TEST_MSG, 0, Some(u64::MAX)).is_err());
+    assert!(proof.verify_at(pk, TEST_MSG, 0, None).is_ok());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn anon_cred_proof_works<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let issuer = SecretKey::<C>::new();
+    let issuer_pk = issuer.public_key();
+    let attributes: [&[u8]; 3] = [b"name=alice", b"age=42", b"country=ca"];
+    let credential = AnonCredential::issue(&issuer, &attributes).unwrap();
+    assert!(credential.verify().is_ok());
+
+    let proof = credential.prove(&[2], b"nonce").unwrap();
+    assert!(proof
+        .verify(issuer_pk, &[(2, attributes[2])], b"nonce")
+        .is_ok());
+    // Proofs don't reveal the credential and can't be linked
+    let proof2 = credential.prove(&[2], b"nonce").unwrap();
+    assert_ne!(proof, proof2);
+    assert_ne!(proof.randomized_signature, credential.signature);
+    assert_ne!(proof.randomized_signature, proof2.randomized_signature);
+    assert_ne!(proof.randomized_commitment, proof2.randomized_commitment);
+    assert!(!format!("{:?}", credential).contains(&format!("{:?}", credential.blinding)));
+
+    // Wrong context, attribute, position, or issuer
+    assert!(proof
+        .verify(issuer_pk, &[(2, attributes[2])], b"other")
+        .is_err());
+    assert!(proof
+        .verify(issuer_pk, &[(2, b"country=us".as_slice())], b"nonce")
+        .is_err());
+    assert!(proof
+        .verify(issuer_pk, &[(1, attributes[2])], b"nonce")
+        .is_err());
+    let other_pk = SecretKey::<C>::new().public_key();
+    assert!(proof
+        .verify(other_pk, &[(2, attributes[2])], b"nonce")
+        .is_err());
+
+    // Claiming an extra undisclosed attribute fails
+    assert!(proof
+        .verify(
+            issuer_pk,
+            &[(2, attributes[2]), (1, attributes[1])],
+            b"nonce"
+        )
+        .is_err());
+
+    let proof = credential.prove(&[0, 2], b"nonce").unwrap();
+    assert!(proof
+        .verify(
+            issuer_pk,
+            &[(0, attributes[0]), (2, attributes[2])],
+            b"nonce"
+        )
+        .is_ok());
+    let bytes = Vec::<u8>::from(&proof);
+    let proof2 = AnonCredProof::<C>::try_from(bytes.as_slice()).unwrap();
+    assert_eq!(proof, proof2);
+
+    // Every attribute can stay hidden
+    let proof = credential.prove(&[], b"nonce").unwrap();
+    assert_eq!(proof.hidden_responses.len(), 3);
+    let none: [(usize, &[u8]); 0] = [];
+    assert!(proof.verify(issuer_pk, &none, b"nonce").is_ok());
+    let mut tampered = proof.clone();
+    tampered.hidden_responses[1] += tampered.hidden_responses[0];
+    assert!(tampered.verify(issuer_pk, &none, b"nonce").is_err());
+
+    assert!(credential.prove(&[3], b"nonce").is_err());
+    assert!(credential.prove(&[1, 1], b"nonce").is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn anon_cred_proof_cannot_mix_credentials<C: BlsSignatureImpl>(#[case] _c: C) {
+    let issuer = SecretKey::<C>::new();
+    let issuer_pk = issuer.public_key();
+    let alice: [&[u8]; 2] = [b"name=alice", b"age=42"];
+    let bob: [&[u8]; 2] = [b"name=bob", b"age=17"];
+    let alice_credential = AnonCredential::issue(&issuer, &alice).unwrap();
+    let bob_credential = AnonCredential::issue(&issuer, &bob).unwrap();
+
+    // Swap bob's age into alice's credential
+    for credential in [&alice_credential, &bob_credential] {
+        let mut mixed = credential.clone();
+        mixed.attributes = vec![alice[0].to_vec(), bob[1].to_vec()];
+        assert!(mixed.verify().is_err());
+        let proof = mixed.prove(&[0, 1], b"nonce").unwrap();
+        assert!(proof
+            .verify(issuer_pk, &[(0, alice[0]), (1, bob[1])], b"nonce")
+            .is_err());
+    }
+
+    // Presenting one credential's disclosed attribute with the other's
+    // randomized signature fails as well
+    let proof = alice_credential.prove(&[0], b"nonce").unwrap();
+    assert!(proof.verify(issuer_pk, &[(0, alice[0])], b"nonce").is_ok());
+    let mut mixed = alice_credential.prove(&[0], b"nonce").unwrap();
+    let bob_proof = bob_credential.prove(&[0], b"nonce").unwrap();
+    mixed.randomized_signature = bob_proof.randomized_signature;
+    mixed.randomized_commitment = bob_proof.randomized_commitment;
+    assert!(mixed.verify(issuer_pk, &[(0, alice[0])], b"nonce").is_err());
+    let proof = bob_credential.prove(&[1], b"nonce").unwrap();
+    assert!(proof.verify(issuer_pk, &[(1, bob[1])], b"nonce").is_ok());
+    assert!(proof.verify(issuer_pk, &[(1, alice[1])], b"nonce").is_err());
+}
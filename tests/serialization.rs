@@ -1,6 +1,6 @@
 mod utils;
 
-use blsful::inner_types::{G1Projective, G2Projective};
+use blsful::inner_types::{G1Affine, G1Projective, G2Affine, G2Projective, Group, GroupEncoding};
 use blsful::*;
 use rstest::*;
 use utils::*;
@@ -286,3 +286,487 @@ fn legacy_shares_test() {
         assert_eq!(t, share2.0.value.0);
     }
 }
+
+#[test]
+fn legacy_shares_with_wide_identifiers() {
+    use blsful::vsss_rs::IdentifierPrimeField;
+
+    let sk = SecretKey::<Bls12381G1Impl>::from_hash("legacy_shares_with_wide_identifiers");
+    let ids = [300u64, 301].map(|i| IdentifierPrimeField(From::from(i)));
+    let sk_shares = sk
+        .split_with_identifiers(2, &ids, MockRng::default())
+        .unwrap();
+    let share = &sk_shares[0];
+
+    let mut v1 = [0u8; 34];
+    v1[..2].copy_from_slice(&300u16.to_le_bytes());
+    v1[2..].copy_from_slice(&share.0.value.to_le_bytes());
+    let share2 =
+        SecretKeyShare::<Bls12381G1Impl>::from_v1_bytes_with_identifier_width(&v1, 2).unwrap();
+    assert_eq!(share, &share2);
+    assert!(SecretKeyShare::<Bls12381G1Impl>::from_v1_bytes_with_identifier_width(&v1, 1).is_err());
+    assert!(
+        SecretKeyShare::<Bls12381G1Impl>::from_v1_bytes_with_identifier_width(&v1[..33], 2)
+            .is_err()
+    );
+
+    let mut v1 = [0u8; 50];
+    v1[..2].copy_from_slice(&300u16.to_le_bytes());
+    let t = G1Projective::GENERATOR * share.0.value.0;
+    v1[2..].copy_from_slice(&t.to_compressed());
+    let share2 = InnerPointShareG1::from_v1_bytes_with_identifier_width(&v1, 2).unwrap();
+    assert_eq!(share.0.identifier, share2.0.identifier);
+    assert_eq!(t, share2.0.value.0);
+
+    let mut v1 = [0u8; 98];
+    v1[..2].copy_from_slice(&300u16.to_le_bytes());
+    let t = G2Projective::GENERATOR * share.0.value.0;
+    v1[2..].copy_from_slice(&t.to_compressed());
+    let share2 = InnerPointShareG2::from_v1_bytes_with_identifier_width(&v1, 2).unwrap();
+    assert_eq!(share.0.identifier, share2.0.identifier);
+    assert_eq!(t, share2.0.value.0);
+
+    // A width that overflows the expected length is rejected, not wrapped
+    assert!(matches!(
+        SecretKeyShare::<Bls12381G1Impl>::from_v1_bytes_with_identifier_width(&v1, usize::MAX),
+        Err(BlsError::InvalidInputs(_))
+    ));
+    assert!(matches!(
+        InnerPointShareG1::from_v1_bytes_with_identifier_width(&v1, usize::MAX),
+        Err(BlsError::InvalidInputs(_))
+    ));
+    assert!(matches!(
+        InnerPointShareG2::from_v1_bytes_with_identifier_width(&v1, usize::MAX),
+        Err(BlsError::InvalidInputs(_))
+    ));
+}
+
+#[test]
+fn legacy_point_shares_check_length() {
+    let sk = SecretKey::<Bls12381G1Impl>::from_hash("legacy_point_shares_check_length");
+
+    let mut v1 = [0u8; 49];
+    v1[0] = 1;
+    v1[1..].copy_from_slice(&(G1Projective::GENERATOR * sk.0).to_compressed());
+    assert!(InnerPointShareG1::from_v1_bytes(&v1).is_ok());
+    assert!(InnerPointShareG1::from_v1_bytes(&v1[..48]).is_err());
+    assert!(InnerPointShareG1::from_v1_bytes(&[]).is_err());
+
+    let mut v1 = [0u8; 97];
+    v1[0] = 1;
+    v1[1..].copy_from_slice(&(G2Projective::GENERATOR * sk.0).to_compressed());
+    assert!(InnerPointShareG2::from_v1_bytes(&v1).is_ok());
+    assert!(InnerPointShareG2::from_v1_bytes(&v1[..96]).is_err());
+    assert!(InnerPointShareG2::from_v1_bytes(&v1[..49]).is_err());
+    assert!(InnerPointShareG2::from_v1_bytes(&[]).is_err());
+}
+
+#[test]
+fn aggregate_signature_rejects_non_subgroup_g1() {
+    let mut bytes = [0u8; 48];
+    bytes[0] = 0x80;
+    let mut point = None;
+    for x in 1..=u8::MAX {
+        bytes[47] = x;
+        let unchecked = G1Affine::from_compressed_unchecked(&bytes);
+        let checked = G1Affine::from_compressed(&bytes);
+        if bool::from(unchecked.is_some()) && bool::from(checked.is_none()) {
+            point = Some(unchecked.unwrap());
+            break;
+        }
+    }
+    let point = point.expect("a non-subgroup point to exist");
+
+    let mut encoded = vec![2u8];
+    encoded.extend_from_slice(&bytes);
+    let res = AggregateSignature::<Bls12381G1Impl>::try_from(encoded.as_slice());
+    assert!(res.is_err());
+
+    let asig = AggregateSignature::<Bls12381G1Impl>::ProofOfPossession(G1Projective::from(point));
+    assert!(asig.validate().is_err());
+
+    let asig = AggregateSignature::<Bls12381G1Impl>::ProofOfPossession(G1Projective::default());
+    assert!(asig.validate().is_err());
+}
+
+#[test]
+fn aggregate_signature_rejects_non_subgroup_g2() {
+    let mut bytes = [0u8; 96];
+    bytes[0] = 0x80;
+    let mut point = None;
+    for x in 1..=u8::MAX {
+        bytes[95] = x;
+        let unchecked = G2Affine::from_compressed_unchecked(&bytes);
+        let checked = G2Affine::from_compressed(&bytes);
+        if bool::from(unchecked.is_some()) && bool::from(checked.is_none()) {
+            point = Some(unchecked.unwrap());
+            break;
+        }
+    }
+    let point = point.expect("a non-subgroup point to exist");
+
+    let mut encoded = vec![2u8];
+    encoded.extend_from_slice(&bytes);
+    let res = AggregateSignature::<Bls12381G2Impl>::try_from(encoded.as_slice());
+    assert!(res.is_err());
+
+    let asig = AggregateSignature::<Bls12381G2Impl>::ProofOfPossession(G2Projective::from(point));
+    assert!(asig.validate().is_err());
+
+    let asig = AggregateSignature::<Bls12381G2Impl>::ProofOfPossession(G2Projective::default());
+    assert!(asig.validate().is_err());
+}
+
+#[test]
+fn tagged_enums_reject_cross_group_bytes() {
+    let sk = SecretKey::<Bls12381G1Impl>::new();
+    let pk = PublicKeyEnum::from(sk.public_key());
+    let sig = SignatureEnum::from(sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap());
+    assert_eq!(pk.curve(), Bls12381::G1);
+    assert_eq!(sig.curve(), Bls12381::G1);
+
+    let pk_bytes = Vec::<u8>::from(&pk);
+    assert_eq!(PublicKeyEnum::try_from(pk_bytes.as_slice()).unwrap(), pk);
+    let sig_bytes = Vec::<u8>::from(&sig);
+    assert_eq!(SignatureEnum::try_from(sig_bytes.as_slice()).unwrap(), sig);
+
+    let mut bad_pk_bytes = pk_bytes.clone();
+    bad_pk_bytes[0] = u8::from(Bls12381::G2);
+    let res = PublicKeyEnum::try_from(bad_pk_bytes.as_slice());
+    assert!(res.is_err());
+    assert!(res.unwrap_err().to_string().contains("BLS12381G2"));
+
+    let mut bad_sig_bytes = sig_bytes.clone();
+    bad_sig_bytes[0] = u8::from(Bls12381::G2);
+    assert!(SignatureEnum::try_from(bad_sig_bytes.as_slice()).is_err());
+    assert!(PublicKeyEnum::try_from(&[][..]).is_err());
+
+    let text = serde_json::to_string(&pk).unwrap();
+    assert_eq!(serde_json::from_str::<PublicKeyEnum>(&text).unwrap(), pk);
+    let text = serde_json::to_string(&sig).unwrap();
+    assert_eq!(serde_json::from_str::<SignatureEnum>(&text).unwrap(), sig);
+    let bytes = serde_bare::to_vec(&sig).unwrap();
+    assert_eq!(
+        serde_bare::from_slice::<SignatureEnum>(&bytes).unwrap(),
+        sig
+    );
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn elgamal_compact_encoding_round_trip<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(
+    #[case] _c: C,
+) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let secret = SecretKey::<C>::new();
+    let proof = pk.encrypt_key_el_gamal_with_proof(&secret).unwrap();
+
+    let bytes = proof.ciphertext.to_bytes();
+    assert_eq!(bytes.len(), ElGamalCiphertext::<C>::BYTES);
+    let ciphertext = ElGamalCiphertext::<C>::from_bytes(&bytes).unwrap();
+    assert_eq!(ciphertext, proof.ciphertext);
+    assert!(ElGamalCiphertext::<C>::from_bytes(&bytes[1..]).is_err());
+
+    let bytes = proof.to_bytes();
+    assert_eq!(bytes.len(), ElGamalProof::<C>::BYTES);
+    let proof2 = ElGamalProof::<C>::from_bytes(&bytes).unwrap();
+    assert_eq!(proof2, proof);
+    assert!(proof2.verify(pk).is_ok());
+    assert!(ElGamalProof::<C>::from_bytes(&bytes[1..]).is_err());
+
+    let bytes = serde_bare::to_vec(&proof).unwrap();
+    assert_eq!(
+        serde_bare::from_slice::<ElGamalProof<C>>(&bytes).unwrap(),
+        proof
+    );
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn identity_public_key_is_rejected<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(
+    #[case] _c: C,
+) {
+    let identity = Vec::<u8>::from(&PublicKey::<C>::default());
+    let res = PublicKey::<C>::try_from(identity.as_slice());
+    assert!(matches!(res, Err(BlsError::InvalidInputs(e)) if e == "public key is identity"));
+    let pk = PublicKey::<C>::try_from_unchecked(identity.as_slice()).unwrap();
+    assert_eq!(pk, PublicKey::<C>::default());
+
+    assert!(MultiPublicKey::<C>::try_from(identity.as_slice()).is_err());
+    let mpk = MultiPublicKey::<C>::try_from_unchecked(identity.as_slice()).unwrap();
+    assert_eq!(mpk, MultiPublicKey::<C>::default());
+
+    let bare = serde_bare::to_vec(&PublicKey::<C>::default()).unwrap();
+    assert!(serde_bare::from_slice::<PublicKey<C>>(&bare).is_err());
+    assert!(serde_bare::from_slice::<MultiPublicKey<C>>(&bare).is_err());
+    let json = serde_json::to_string(&PublicKey::<C>::default()).unwrap();
+    assert!(serde_json::from_str::<PublicKey<C>>(&json).is_err());
+
+    let pk = SecretKey::<C>::new().public_key();
+    let bytes = Vec::<u8>::from(&pk);
+    assert_eq!(PublicKey::<C>::try_from(bytes.as_slice()).unwrap(), pk);
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn validated_signature_rejects_non_subgroup_point<
+    C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug,
+>(
+    #[case] _c: C,
+) {
+    // Find a point on the curve that is outside the prime order subgroup
+    let mut repr = <C as Pairing>::Signature::default().to_bytes();
+    let mut point = None;
+    for x in 1..=u8::MAX {
+        let bytes = repr.as_mut();
+        bytes.iter_mut().for_each(|b| *b = 0);
+        bytes[0] = 0x80;
+        let last = bytes.len() - 1;
+        bytes[last] = x;
+        let candidate = Option::<<C as Pairing>::Signature>::from(
+            <C as Pairing>::Signature::from_bytes_unchecked(&repr),
+        );
+        if let Some(p) = candidate {
+            if (!<C as Pairing>::signature_is_torsion_free(&p)).into() {
+                point = Some(p);
+                break;
+            }
+        }
+    }
+    let bad = Signature::<C>::ProofOfPossession(point.expect("a non subgroup point"));
+    let bytes = Vec::<u8>::from(&bad);
+    assert!(matches!(
+        Signature::<C>::try_from_validated(&bytes),
+        Err(BlsError::InvalidInputs(_))
+    ));
+    assert!(matches!(
+        Signature::<C>::try_from(bytes.as_slice()),
+        Err(BlsError::InvalidInputs(_))
+    ));
+    assert!(matches!(
+        Signature::<C>::try_from_validated(&bytes[1..]),
+        Err(BlsError::InvalidInputs(_))
+    ));
+
+    let identity = Signature::<C>::ProofOfPossession(<C as Pairing>::Signature::identity());
+    let bytes = Vec::<u8>::from(&identity);
+    assert!(matches!(
+        Signature::<C>::try_from_validated(&bytes),
+        Err(BlsError::InvalidSignature)
+    ));
+
+    let sk = SecretKey::<C>::new();
+    let sig = sk
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    let bytes = Vec::<u8>::from(&sig);
+    assert_eq!(Signature::<C>::try_from_validated(&bytes).unwrap(), sig);
+    assert_eq!(Signature::<C>::try_from(bytes.as_slice()).unwrap(), sig);
+}
+
+#[test]
+fn public_key_enum_matches_generic_public_key() {
+    for curve in [Bls12381::G1, Bls12381::G2] {
+        let sk = SecretKeyEnum::new(curve);
+        let pk = sk.public_key();
+        assert_eq!(pk.curve(), curve);
+
+        let bytes = pk.to_be_bytes();
+        assert_eq!(bytes[0], u8::from(curve));
+        assert_eq!(PublicKeyEnum::from_be_bytes(&bytes).unwrap(), pk);
+        let bare = serde_bare::to_vec(&pk).unwrap();
+        assert_eq!(serde_bare::from_slice::<PublicKeyEnum>(&bare).unwrap(), pk);
+
+        let sig_bytes = match &sk {
+            SecretKeyEnum::G1(sk) => {
+                let sig = sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+                assert_eq!(pk, PublicKeyEnum::G1(sk.public_key()));
+                assert_eq!(&bytes[1..], Vec::<u8>::from(&sk.public_key()).as_slice());
+                sig.as_raw_value().to_bytes().as_ref().to_vec()
+            }
+            SecretKeyEnum::G2(sk) => {
+                let sig = sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+                assert_eq!(pk, PublicKeyEnum::G2(sk.public_key()));
+                assert_eq!(&bytes[1..], Vec::<u8>::from(&sk.public_key()).as_slice());
+                sig.as_raw_value().to_bytes().as_ref().to_vec()
+            }
+        };
+        assert!(pk
+            .verify(SignatureSchemes::Basic, &sig_bytes, TEST_MSG)
+            .is_ok());
+        assert!(pk
+            .verify(SignatureSchemes::ProofOfPossession, &sig_bytes, TEST_MSG)
+            .is_err());
+        assert!(pk
+            .verify(SignatureSchemes::Basic, &sig_bytes, b"other")
+            .is_err());
+        assert!(pk
+            .verify(SignatureSchemes::Basic, &sig_bytes[1..], TEST_MSG)
+            .is_err());
+    }
+}
+
+#[test]
+fn signature_enum_verifies_against_matching_curve() {
+    let sk_g1 = SecretKeyEnum::new(Bls12381::G1);
+    let sk_g2 = SecretKeyEnum::new(Bls12381::G2);
+    let sig = match &sk_g1 {
+        SecretKeyEnum::G1(sk) => SignatureEnum::from(
+            sk.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+                .unwrap(),
+        ),
+        SecretKeyEnum::G2(_) => unreachable!(),
+    };
+
+    let bytes: Vec<u8> = sig.into();
+    let sig2 = SignatureEnum::try_from(bytes.as_slice()).unwrap();
+    assert_eq!(sig2, sig);
+    let bare = serde_bare::to_vec(&sig).unwrap();
+    assert_eq!(serde_bare::from_slice::<SignatureEnum>(&bare).unwrap(), sig);
+
+    assert!(sig.verify(&sk_g1.public_key(), TEST_MSG).is_ok());
+    assert!(sig.verify(&sk_g1.public_key(), b"other").is_err());
+    let res = sig.verify(&sk_g2.public_key(), TEST_MSG);
+    assert!(matches!(res, Err(BlsError::InvalidInputs(_))));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn hex_round_trip<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(#[case] _c: C) {
+    fn to_hex(bytes: Vec<u8>) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let sig = sk
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    let pop = sk.proof_of_possession().unwrap();
+
+    assert_eq!(sk.to_hex(), to_hex(Vec::from(&sk)));
+    assert_eq!(pk.to_hex(), to_hex(Vec::from(&pk)));
+    assert_eq!(sig.to_hex(), to_hex(Vec::from(&sig)));
+    assert_eq!(pop.to_hex(), to_hex(Vec::from(&pop)));
+
+    assert_eq!(SecretKey::<C>::from_hex(&sk.to_hex()).unwrap(), sk);
+    assert_eq!(PublicKey::<C>::from_hex(&pk.to_hex()).unwrap(), pk);
+    assert_eq!(Signature::<C>::from_hex(&sig.to_hex()).unwrap(), sig);
+    assert_eq!(
+        ProofOfPossession::<C>::from_hex(&pop.to_hex()).unwrap(),
+        pop
+    );
+    assert_eq!(pk.to_hex().parse::<PublicKey<C>>().unwrap(), pk);
+    assert_eq!(sig.to_hex().parse::<Signature<C>>().unwrap(), sig);
+
+    let odd = &pk.to_hex()[1..];
+    assert!(matches!(
+        PublicKey::<C>::from_hex(odd),
+        Err(BlsError::DeserializationError(_))
+    ));
+    let not_hex = format!("zz{}", &pk.to_hex()[2..]);
+    assert!(matches!(
+        not_hex.parse::<PublicKey<C>>(),
+        Err(BlsError::DeserializationError(_))
+    ));
+    assert!(SecretKey::<C>::from_hex("0x01").is_err());
+    assert!(Signature::<C>::from_hex("").is_err());
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Validator<C: BlsSignatureImpl> {
+    #[serde(with = "blsful::serde_0x")]
+    pubkey: PublicKey<C>,
+    #[serde(with = "blsful::serde_0x")]
+    signature: Signature<C>,
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn serde_0x_prefixes_human_readable_hex<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(
+    #[case] _c: C,
+) {
+    let sk = SecretKey::<C>::new();
+    let validator = Validator {
+        pubkey: sk.public_key(),
+        signature: sk
+            .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+            .unwrap(),
+    };
+
+    let json = serde_json::to_value(&validator).unwrap();
+    let pubkey = json["pubkey"].as_str().unwrap();
+    let signature = json["signature"].as_str().unwrap();
+    assert!(pubkey.starts_with("0x"));
+    assert_eq!(pubkey.len(), 2 + 2 * <C as Pairing>::PUBLIC_KEY_BYTES);
+    assert!(signature.starts_with("0x"));
+    assert_eq!(signature.len(), 2 + 2 * <C as Pairing>::SIGNATURE_BYTES);
+
+    let decoded: Validator<C> = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(decoded.pubkey, validator.pubkey);
+    assert_eq!(decoded.signature, validator.signature);
+
+    // Binary formats keep the compact encoding
+    let bytes = serde_bare::to_vec(&validator).unwrap();
+    let decoded: Validator<C> = serde_bare::from_slice(&bytes).unwrap();
+    assert_eq!(decoded.pubkey, validator.pubkey);
+    assert_eq!(decoded.signature, validator.signature);
+
+    let mut missing_prefix = json.clone();
+    missing_prefix["pubkey"] = serde_json::Value::String(pubkey[2..].to_string());
+    assert!(serde_json::from_value::<Validator<C>>(missing_prefix).is_err());
+
+    let basic = Validator {
+        pubkey: sk.public_key(),
+        signature: sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap(),
+    };
+    assert!(serde_json::to_value(&basic).is_err());
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct StoredShare<C: BlsSignatureImpl> {
+    #[serde(with = "blsful::serde_byte_string")]
+    share: SecretKeyShare<C>,
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn serde_byte_string_is_compact_in_cbor<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(
+    #[case] _c: C,
+) {
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split_with_rng(2, 3, rand_core::OsRng).unwrap();
+    let stored = StoredShare {
+        share: shares[0].clone(),
+    };
+
+    let mut cbor = Vec::new();
+    ciborium::into_writer(&stored, &mut cbor).unwrap();
+    // A map with the single text key "share" followed by a byte string
+    assert_eq!(&cbor[..7], b"\xa1\x65share");
+    assert_eq!(cbor[7] & 0xe0, 0x40);
+    let bytes = Vec::from(&stored.share);
+    assert!(cbor.ends_with(&bytes));
+
+    let decoded: StoredShare<C> = ciborium::from_reader(cbor.as_slice()).unwrap();
+    assert_eq!(decoded.share, stored.share);
+
+    // The other formats are unchanged
+    assert_eq!(
+        serde_json::to_value(&stored).unwrap()["share"],
+        serde_json::to_value(&stored.share).unwrap()
+    );
+    let decoded: StoredShare<C> =
+        serde_json::from_str(&serde_json::to_string(&stored).unwrap()).unwrap();
+    assert_eq!(decoded.share, stored.share);
+    let decoded: StoredShare<C> =
+        serde_bare::from_slice(&serde_bare::to_vec(&stored).unwrap()).unwrap();
+    assert_eq!(decoded.share, stored.share);
+}
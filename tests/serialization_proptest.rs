@@ -0,0 +1,215 @@
+mod utils;
+
+use blsful::*;
+use proptest::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::Debug;
+use utils::*;
+
+/// Check a value survives the raw byte, JSON and serde-bare encodings
+fn assert_round_trips<T>(value: &T)
+where
+    T: PartialEq
+        + Debug
+        + Serialize
+        + DeserializeOwned
+        + for<'a> TryFrom<&'a [u8], Error = BlsError>,
+    for<'a> Vec<u8>: From<&'a T>,
+{
+    let bytes = Vec::<u8>::from(value);
+    assert_eq!(T::try_from(bytes.as_slice()).unwrap(), *value);
+    let text = serde_json::to_string(value).unwrap();
+    assert_eq!(serde_json::from_str::<T>(&text).unwrap(), *value);
+    let bytes = serde_bare::to_vec(value).unwrap();
+    assert_eq!(serde_bare::from_slice::<T>(&bytes).unwrap(), *value);
+}
+
+/// Decode arbitrary input with every decoder for `T`, only errors are allowed
+fn decode_any<T>(bytes: &[u8])
+where
+    T: DeserializeOwned + for<'a> TryFrom<&'a [u8], Error = BlsError>,
+{
+    let _ = T::try_from(bytes);
+    let _ = serde_json::from_slice::<T>(bytes);
+    let _ = serde_bare::from_slice::<T>(bytes);
+}
+
+fn all_types_round_trip<C>(seed: [u8; 32], msg: &[u8])
+where
+    C: BlsSignatureImpl + PartialEq + Eq + Debug + Serialize + DeserializeOwned,
+{
+    let sk = SecretKey::<C>::from_hash(seed);
+    let pk = sk.public_key();
+    assert_round_trips(&sk);
+    assert_round_trips(&pk);
+
+    let sigs = [
+        SignatureSchemes::Basic,
+        SignatureSchemes::MessageAugmentation,
+        SignatureSchemes::ProofOfPossession,
+    ]
+    .map(|scheme| sk.sign(scheme, msg).unwrap());
+    for sig in &sigs {
+        assert_round_trips(sig);
+    }
+    assert_round_trips(&sk.proof_of_possession().unwrap());
+
+    let sk2 = SecretKey::<C>::from_hash(msg);
+    let pop_sigs = [
+        sigs[2],
+        sk2.sign(SignatureSchemes::ProofOfPossession, msg).unwrap(),
+    ];
+    assert_round_trips(&AggregateSignature::from_signatures(pop_sigs).unwrap());
+    assert_round_trips(&MultiSignature::from_signatures(pop_sigs).unwrap());
+    assert_round_trips(&MultiPublicKey::from_public_keys([pk, sk2.public_key()]));
+    assert_round_trips(&SignedMessage::sign(&sk, SignatureSchemes::Basic, msg).unwrap());
+
+    let (shares, params) = sk.split_with_params(2, 3).unwrap();
+    assert_round_trips(&params);
+    for share in &shares {
+        assert_round_trips(share);
+        assert_round_trips(&share.public_key().unwrap());
+        assert_round_trips(&share.sign(SignatureSchemes::Basic, msg).unwrap());
+    }
+
+    assert_round_trips(&pk.sign_crypt(SignatureSchemes::Basic, msg));
+    assert_round_trips(
+        &pk.encrypt_time_lock(SignatureSchemes::Basic, msg, TEST_ID)
+            .unwrap(),
+    );
+    assert_round_trips(&pk.encrypt_key_el_gamal(&sk2).unwrap());
+    assert_round_trips(&pk.encrypt_key_el_gamal_with_proof(&sk2).unwrap());
+
+    // AffinePublicKey has no encoding of its own, it converts losslessly
+    let affine = pk.to_affine();
+    assert_eq!(PublicKey::from(affine), pk);
+    assert_round_trips(&PublicKey::from(affine));
+
+    let sign_crypt = pk.sign_crypt(SignatureSchemes::Basic, msg);
+    let decryption_shares = shares
+        .iter()
+        .map(|s| sign_crypt.create_decryption_share(s).unwrap())
+        .collect::<Vec<_>>();
+    for share in &decryption_shares {
+        assert_round_trips(share);
+    }
+    assert_round_trips(&SignCryptDecryptionKey::from_shares(&decryption_shares).unwrap());
+
+    let el_gamal = pk.encrypt_key_el_gamal(&sk2).unwrap();
+    let decryption_shares = shares
+        .iter()
+        .map(|s| s.elgamal_decryption_share(&el_gamal).unwrap())
+        .chain(Some(el_gamal.create_decryption_share(&shares[0]).unwrap()))
+        .collect::<Vec<_>>();
+    for share in &decryption_shares {
+        assert_round_trips(share);
+    }
+    assert_round_trips(&ElGamalDecryptionKey::from_shares(&decryption_shares[..2]).unwrap());
+
+    let (_, transcript) = sk.split_with_transcript(2, 3, rand_core::OsRng).unwrap();
+    assert_round_trips(&transcript);
+
+    let (commitment, x) = ProofCommitment::generate(msg, sigs[0]).unwrap();
+    assert_round_trips(&commitment);
+    assert_round_trips(&x);
+    let y = ProofCommitmentChallenge::<C>::from_hash(seed);
+    assert_round_trips(&y);
+    assert_round_trips(&commitment.finalize(x, y, sigs[0]).unwrap());
+    assert_round_trips(&ProofOfKnowledgeTimestamp::generate_with_time(msg, sigs[2], 0).unwrap());
+
+    let credential = AnonCredential::issue(&sk, &[msg, b"attribute".as_slice()]).unwrap();
+    assert_round_trips(&credential.prove(&[1], msg).unwrap());
+}
+
+fn all_types_decode_any<C>(bytes: &[u8])
+where
+    C: BlsSignatureImpl + Serialize + DeserializeOwned,
+{
+    decode_any::<SecretKey<C>>(bytes);
+    decode_any::<PublicKey<C>>(bytes);
+    decode_any::<Signature<C>>(bytes);
+    decode_any::<ProofOfPossession<C>>(bytes);
+    decode_any::<AggregateSignature<C>>(bytes);
+    decode_any::<MultiSignature<C>>(bytes);
+    decode_any::<MultiPublicKey<C>>(bytes);
+    decode_any::<SignedMessage<C>>(bytes);
+    decode_any::<SharingParams<C>>(bytes);
+    decode_any::<SecretKeyShare<C>>(bytes);
+    decode_any::<PublicKeyShare<C>>(bytes);
+    decode_any::<SignatureShare<C>>(bytes);
+    decode_any::<SignCryptCiphertext<C>>(bytes);
+    decode_any::<TimeCryptCiphertext<C>>(bytes);
+    decode_any::<ElGamalCiphertext<C>>(bytes);
+    decode_any::<ElGamalProof<C>>(bytes);
+    decode_any::<ElGamalDecryptionShare<C>>(bytes);
+    decode_any::<ElGamalDecryptionKey<C>>(bytes);
+    decode_any::<SignDecryptionShare<C>>(bytes);
+    decode_any::<SignCryptDecryptionKey<C>>(bytes);
+    decode_any::<DealerTranscript<C>>(bytes);
+    decode_any::<ProofCommitment<C>>(bytes);
+    decode_any::<ProofCommitmentSecret<C>>(bytes);
+    decode_any::<ProofCommitmentChallenge<C>>(bytes);
+    decode_any::<ProofOfKnowledge<C>>(bytes);
+    decode_any::<ProofOfKnowledgeTimestamp<C>>(bytes);
+    decode_any::<AnonCredProof<C>>(bytes);
+    let _ = SecretKeyShare::<C>::from_v1_bytes(bytes);
+}
+
+fn tagged_enums_decode_any(bytes: &[u8]) {
+    decode_any::<SecretKeyEnum>(bytes);
+    decode_any::<PublicKeyEnum>(bytes);
+    decode_any::<SignatureEnum>(bytes);
+    decode_any::<CiphertextEnum>(bytes);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn values_round_trip(seed in any::<[u8; 32]>(), msg in prop::collection::vec(any::<u8>(), 0..64)) {
+        all_types_round_trip::<Bls12381G1Impl>(seed, &msg);
+        all_types_round_trip::<Bls12381G2Impl>(seed, &msg);
+    }
+
+    #[test]
+    fn tagged_enums_round_trip(seed in any::<[u8; 32]>(), msg in prop::collection::vec(any::<u8>(), 0..64)) {
+        let sk = SecretKey::<Bls12381G1Impl>::from_hash(seed);
+        let pk = sk.public_key();
+        assert_round_trips(&SecretKeyEnum::G1(sk.clone()));
+        assert_round_trips(&PublicKeyEnum::G1(pk));
+        assert_round_trips(&SignatureEnum::G1(sk.sign(SignatureSchemes::Basic, &msg).unwrap()));
+        assert_round_trips(&CiphertextEnum::from(pk.sign_crypt(SignatureSchemes::Basic, &msg)));
+
+        let sk = SecretKey::<Bls12381G2Impl>::from_hash(seed);
+        let pk = sk.public_key();
+        assert_round_trips(&SecretKeyEnum::G2(sk.clone()));
+        assert_round_trips(&PublicKeyEnum::G2(pk));
+        assert_round_trips(&SignatureEnum::G2(sk.sign(SignatureSchemes::Basic, &msg).unwrap()));
+        assert_round_trips(&CiphertextEnum::from(
+            pk.encrypt_time_lock(SignatureSchemes::Basic, &msg, TEST_ID).unwrap(),
+        ));
+    }
+}
+
+proptest! {
+    #[test]
+    fn arbitrary_bytes_never_panic(bytes in prop::collection::vec(any::<u8>(), 0..512)) {
+        all_types_decode_any::<Bls12381G1Impl>(&bytes);
+        all_types_decode_any::<Bls12381G2Impl>(&bytes);
+        tagged_enums_decode_any(&bytes);
+    }
+
+    #[test]
+    fn tagged_bytes_never_panic(
+        curve in 0u8..4,
+        kind in 0u8..4,
+        bytes in prop::collection::vec(any::<u8>(), 0..512),
+    ) {
+        let mut input = vec![curve, kind];
+        input.extend_from_slice(&bytes);
+        tagged_enums_decode_any(&input);
+        tagged_enums_decode_any(&input[..1]);
+        input.remove(1);
+        tagged_enums_decode_any(&input);
+    }
+}
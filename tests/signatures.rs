@@ -1,7 +1,11 @@
 mod utils;
 use blsful::{
-    AggregateSignature, Bls12381G1, Bls12381G1Impl, Bls12381G2, Bls12381G2Impl, BlsSignatureImpl,
-    MultiPublicKey, MultiSignature, PublicKey, SecretKey, Signature, SignatureSchemes,
+    eth, AggregateSignature, Bls12381G1, Bls12381G1Impl, Bls12381G1XofImpl, Bls12381G2,
+    Bls12381G2Impl, Bls12381G2XofImpl, BlsError, BlsSignatureBasic, BlsSignatureCore,
+    BlsSignatureImpl, BlsVerifiable, DealerTranscript, FeldmanCommitment, MessageHasher,
+    MultiPublicKey, MultiSignature, ProofOfPossession, PublicKey, SecretKey, SecretKeyShare,
+    ShareCorrectnessProof, SharingParams, SignPolicy, Signature, SignatureAggregator,
+    SignatureSchemes, SignatureShare, SignedMessage, SlotSigner,
 };
 use rstest::*;
 use utils::*;
@@ -35,6 +39,37 @@ fn signatures_work() {
     }
 }
 
+#[rstest]
+#[case::g1(Bls12381G1XofImpl, Bls12381G1Impl)]
+#[case::g2(Bls12381G2XofImpl, Bls12381G2Impl)]
+fn xof_suite_works<X: BlsSignatureImpl, S: BlsSignatureImpl>(#[case] _x: X, #[case] _s: S) {
+    let sk = SecretKey::<X>::new();
+    let pk = sk.public_key();
+    for scheme in [
+        SignatureSchemes::Basic,
+        SignatureSchemes::MessageAugmentation,
+        SignatureSchemes::ProofOfPossession,
+    ] {
+        let sig = sk.sign(scheme, TEST_MSG).unwrap();
+        assert!(sig.verify(&pk, TEST_MSG).is_ok());
+        assert!(sig.verify(&pk, BAD_MSG).is_err());
+
+        // The same key signing the same message under the SHA-256 suite
+        // gives a different signature and neither verifies under the other
+        let sha_sk = SecretKey::<S>::try_from(Vec::from(&sk)).unwrap();
+        let sha_pk = PublicKey::<S>::try_from(Vec::from(&pk)).unwrap();
+        let sha_sig = sha_sk.sign(scheme, TEST_MSG).unwrap();
+        assert!(sha_sig.verify(&sha_pk, TEST_MSG).is_ok());
+        assert_ne!(Vec::from(&sig), Vec::from(&sha_sig));
+        let cross_sig = Signature::<S>::try_from(Vec::from(&sig)).unwrap();
+        assert!(cross_sig.verify(&sha_pk, TEST_MSG).is_err());
+        let cross_sig = Signature::<X>::try_from(Vec::from(&sha_sig)).unwrap();
+        assert!(cross_sig.verify(&pk, TEST_MSG).is_err());
+    }
+    let pop = sk.proof_of_possession().unwrap();
+    assert!(pop.verify(pk).is_ok());
+}
+
 #[test]
 fn proof_of_possession_works() {
     let sk = Bls12381G1::new_secret_key();
@@ -52,6 +87,21 @@ fn proof_of_possession_works() {
     assert!(pop.verify(pk2).is_err());
 }
 
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn proof_of_possession_with_context_works<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let pop = sk.proof_of_possession_with_context(b"chain-1").unwrap();
+    assert!(pop.verify_with_context(pk, b"chain-1").is_ok());
+    assert!(pop.verify_with_context(pk, b"chain-2").is_err());
+    assert!(pop.verify(pk).is_err());
+
+    let pop = sk.proof_of_possession_with_context(&[]).unwrap();
+    assert!(pop.verify(pk).is_ok());
+}
+
 #[rstest]
 #[case::g1(Bls12381G1Impl)]
 #[case::g2(Bls12381G2Impl)]
@@ -85,6 +135,134 @@ fn shares_work<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
     assert!(sig.verify(&pk, TEST_MSG).is_ok());
 }
 
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn shares_combine_rejects_bad_identifiers<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split_with_rng(2, 3, rand_core::OsRng).unwrap();
+    let sig0 = shares[0].sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+    let pks0 = shares[0].public_key().unwrap();
+
+    assert!(matches!(
+        Signature::from_shares(&[sig0, sig0]),
+        Err(BlsError::DuplicateShareIdentifier)
+    ));
+    assert!(matches!(
+        PublicKey::from_shares(&[pks0, pks0]),
+        Err(BlsError::DuplicateShareIdentifier)
+    ));
+    assert!(matches!(
+        Signature::from_shares(&[sig0]),
+        Err(BlsError::InsufficientShares(2, 1))
+    ));
+    assert!(matches!(
+        Signature::<C>::from_shares(&[]),
+        Err(BlsError::InsufficientShares(2, 0))
+    ));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn combine_shares_msm_matches_interpolation<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split(50, 100).unwrap();
+    let subset = shares.iter().skip(1).step_by(2).collect::<Vec<_>>();
+    assert_eq!(subset.len(), 50);
+
+    let sig_shares = subset
+        .iter()
+        .map(|s| {
+            *s.sign(SignatureSchemes::Basic, TEST_MSG)
+                .unwrap()
+                .as_raw_value()
+        })
+        .collect::<Vec<_>>();
+    let expected = <C as BlsSignatureCore>::core_combine_signature_shares(&sig_shares).unwrap();
+    let actual = <C as BlsSignatureCore>::core_combine_signature_shares_msm(&sig_shares).unwrap();
+    assert_eq!(actual, expected);
+    let sig = sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+    assert_eq!(&actual, sig.as_raw_value());
+
+    let pk_shares = subset
+        .iter()
+        .map(|s| s.public_key().unwrap().0)
+        .collect::<Vec<_>>();
+    let expected = <C as BlsSignatureCore>::core_combine_public_key_shares(&pk_shares).unwrap();
+    let actual = <C as BlsSignatureCore>::core_combine_public_key_shares_msm(&pk_shares).unwrap();
+    assert_eq!(actual, expected);
+    assert_eq!(actual, sk.public_key().0);
+
+    assert!(<C as BlsSignatureCore>::core_combine_signature_shares_msm(&sig_shares[..1]).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn shares_work_beyond_byte_identifiers<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split_with_rng(150, 300, rand_core::OsRng).unwrap();
+    assert_eq!(shares.len(), 300);
+
+    let mut sorted = shares.clone();
+    sorted.sort();
+    assert!(sorted == shares);
+
+    // Identifiers above 255 survive serialization
+    let last = &shares[299];
+    let bytes = Vec::<u8>::from(last);
+    let res = SecretKeyShare::<C>::try_from(bytes.as_slice());
+    assert!(res.is_ok());
+    assert!(&res.unwrap() == last);
+
+    // Every other share leaves gaps between identifiers
+    let subset = shares.iter().step_by(2).cloned().collect::<Vec<_>>();
+    assert_eq!(subset.len(), 150);
+    let res = SecretKey::combine(&subset);
+    assert!(res.is_ok());
+    assert!(res.unwrap() == sk);
+
+    // Shares don't carry the threshold so one share short still
+    // interpolates, just to the wrong key
+    let res = SecretKey::combine(&subset[..149]);
+    assert!(res.is_ok());
+    assert!(res.unwrap() != sk);
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn shares_sort_by_identifier<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split_with_rng(3, 5, rand_core::OsRng).unwrap();
+    let sig_shares = shares
+        .iter()
+        .map(|s| s.sign(SignatureSchemes::Basic, TEST_MSG).unwrap())
+        .collect::<Vec<_>>();
+    let pk_shares = shares
+        .iter()
+        .map(|s| s.public_key().unwrap())
+        .collect::<Vec<_>>();
+
+    let mut sorted = shares.iter().rev().cloned().collect::<Vec<_>>();
+    sorted.sort();
+    assert!(sorted == shares);
+
+    let mut sorted = sig_shares.iter().rev().copied().collect::<Vec<_>>();
+    sorted.sort();
+    assert!(sorted == sig_shares);
+
+    let mut sorted = pk_shares.iter().rev().copied().collect::<Vec<_>>();
+    sorted.sort();
+    assert!(sorted == pk_shares);
+
+    let mut dups = vec![sig_shares[1], sig_shares[0], sig_shares[1]];
+    dups.sort();
+    dups.dedup();
+    assert!(dups == sig_shares[..2]);
+}
+
 #[rstest]
 #[case::g1(Bls12381G1Impl)]
 #[case::g2(Bls12381G2Impl)]
@@ -171,3 +349,1710 @@ fn aggegratesigs_work<C: BlsSignatureImpl>(#[case] _c: C) {
         .verify(&[(pk1, TEST_MSG), (pk2, TEST_MSG), (pk3, TEST_MSG)])
         .is_ok());
 }
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn slot_signer_rejects_double_sign<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::random(MockRng::default());
+    let pk = sk.public_key();
+    let mut signer = SlotSigner::new(sk);
+
+    let sig = signer
+        .sign(SignatureSchemes::ProofOfPossession, 5, TEST_MSG)
+        .unwrap();
+    assert!(sig.verify_with_slot(&pk, 5, TEST_MSG).is_ok());
+    assert!(sig.verify_with_slot(&pk, 6, TEST_MSG).is_err());
+    assert!(sig.verify(&pk, TEST_MSG).is_err());
+    assert_eq!(signer.last_slot(), Some(5));
+
+    // Regular signatures over the same bytes are not slot signatures
+    let slot_msg = [&5u64.to_be_bytes()[..], TEST_MSG].concat();
+    assert!(sig.verify(&pk, &slot_msg).is_err());
+    // Same seed as the signer's key
+    let plain = SecretKey::<C>::random(MockRng::default())
+        .sign(SignatureSchemes::ProofOfPossession, &slot_msg)
+        .unwrap();
+    assert!(plain.verify(&pk, &slot_msg).is_ok());
+    assert!(plain.verify_with_slot(&pk, 5, TEST_MSG).is_err());
+
+    let res = signer.sign(SignatureSchemes::ProofOfPossession, 5, BAD_MSG);
+    assert!(matches!(res, Err(BlsError::DoubleSign)));
+    let res = signer.sign(SignatureSchemes::ProofOfPossession, 4, TEST_MSG);
+    assert!(matches!(res, Err(BlsError::DoubleSign)));
+    assert!(signer
+        .sign(SignatureSchemes::ProofOfPossession, 6, TEST_MSG)
+        .is_ok());
+    assert_eq!(signer.last_slot(), Some(6));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn fast_aggregate_verify_works<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sks = (0..3).map(|_| SecretKey::<C>::new()).collect::<Vec<_>>();
+    let pks = sks.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+    let sigs = sks
+        .iter()
+        .map(|sk| {
+            sk.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let msig = MultiSignature::from_signatures(&sigs).unwrap();
+    let sig = Signature::ProofOfPossession(*msig.as_raw_value());
+
+    assert!(sig.fast_aggregate_verify(&pks, TEST_MSG).is_ok());
+    assert!(sig.fast_aggregate_verify(&pks, BAD_MSG).is_err());
+    assert!(sig.fast_aggregate_verify(&pks[..2], TEST_MSG).is_err());
+    assert!(sig.fast_aggregate_verify(&[], TEST_MSG).is_err());
+
+    let sig = sks[0].sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+    assert!(sig.fast_aggregate_verify(&pks[..1], TEST_MSG).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn multi_public_key_as_public_key_verifies_multi_signature<C: BlsSignatureImpl + PartialEq + Eq>(
+    #[case] _c: C,
+) {
+    let sks = (0..3).map(|_| SecretKey::<C>::new()).collect::<Vec<_>>();
+    let keys = sks
+        .iter()
+        .map(|sk| (sk.public_key(), sk.proof_of_possession().unwrap()))
+        .collect::<Vec<_>>();
+    let apk = MultiPublicKey::from_public_keys_checked(&keys).unwrap();
+    let pk = apk.as_public_key();
+    assert_eq!(pk, PublicKey::from(apk));
+    assert_eq!(pk, PublicKey::from(&apk));
+    assert_eq!(pk.0, apk.0);
+
+    let sigs = sks
+        .iter()
+        .map(|sk| {
+            sk.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let msig = MultiSignature::from_signatures(&sigs).unwrap();
+    let sig = Signature::ProofOfPossession(*msig.as_raw_value());
+    assert!(sig.verify(&pk, TEST_MSG).is_ok());
+    assert!(sig.verify(&pk, BAD_MSG).is_err());
+    assert!(sig.verify(&keys[0].0, TEST_MSG).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn multi_public_key_incremental_add_and_remove<C: BlsSignatureImpl + PartialEq + Eq>(
+    #[case] _c: C,
+) {
+    let pks = (0..5)
+        .map(|_| SecretKey::<C>::new().public_key())
+        .collect::<Vec<_>>();
+    let mut apk = MultiPublicKey::from_public_keys(&pks[..1]);
+    for pk in &pks[1..] {
+        apk.add(pk);
+    }
+    assert_eq!(apk, MultiPublicKey::from_public_keys(&pks));
+
+    apk.remove(&pks[2]).unwrap();
+    let remaining = [pks[0], pks[1], pks[3], pks[4]];
+    assert_eq!(apk, MultiPublicKey::from_public_keys(remaining));
+    assert_eq!(
+        Some(apk),
+        MultiPublicKey::from_public_keys(&pks).checked_sub(&pks[2])
+    );
+
+    let mut single = MultiPublicKey::from_public_keys(&pks[..1]);
+    assert!(single.checked_sub(&pks[0]).is_none());
+    assert!(single.remove(&pks[0]).is_err());
+    assert_eq!(single, MultiPublicKey::from_public_keys(&pks[..1]));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn multi_signature_verify_fast_matches_verify<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sks = (0..10).map(|_| SecretKey::<C>::new()).collect::<Vec<_>>();
+    let pks = sks.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+    let apk = MultiPublicKey::from_public_keys(&pks);
+    for scheme in [SignatureSchemes::Basic, SignatureSchemes::ProofOfPossession] {
+        let sigs = sks
+            .iter()
+            .map(|sk| sk.sign(scheme, TEST_MSG).unwrap())
+            .collect::<Vec<_>>();
+        let msig = MultiSignature::from_signatures(&sigs).unwrap();
+        assert!(msig.verify(apk, TEST_MSG).is_ok());
+        assert!(msig.verify_fast(&pks, TEST_MSG).is_ok());
+        assert!(msig.verify(apk, BAD_MSG).is_err());
+        assert!(msig.verify_fast(&pks, BAD_MSG).is_err());
+        assert!(msig.verify_fast(&pks[1..], TEST_MSG).is_err());
+        assert!(msig.verify_fast(&[], TEST_MSG).is_err());
+    }
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn affine_public_key_verify_works<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let apk = pk.to_affine();
+    assert!(PublicKey::from_affine(&apk) == pk);
+
+    for scheme in [
+        SignatureSchemes::Basic,
+        SignatureSchemes::MessageAugmentation,
+        SignatureSchemes::ProofOfPossession,
+    ] {
+        let sig = sk.sign(scheme, TEST_MSG).unwrap();
+        assert!(apk.verify(&sig, TEST_MSG).is_ok());
+        assert!(apk.verify(&sig, BAD_MSG).is_err());
+    }
+
+    let apk2 = SecretKey::<C>::new().public_key().to_affine();
+    let sig = sk
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    assert!(apk2.verify(&sig, TEST_MSG).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn prepared_public_key_agrees_with_verify<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let ppk = pk.prepare();
+    assert!(ppk.public_key() == pk);
+    let other = SecretKey::<C>::new().public_key().prepare();
+
+    for scheme in [
+        SignatureSchemes::Basic,
+        SignatureSchemes::MessageAugmentation,
+        SignatureSchemes::ProofOfPossession,
+    ] {
+        let sig = sk.sign(scheme, TEST_MSG).unwrap();
+        for msg in [TEST_MSG, BAD_MSG] {
+            assert_eq!(ppk.verify(&sig, msg).is_ok(), sig.verify(&pk, msg).is_ok());
+        }
+        assert!(ppk.verify(&sig, TEST_MSG).is_ok());
+        assert!(other.verify(&sig, TEST_MSG).is_err());
+    }
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn batch_verify_mixed_works<C: BlsSignatureImpl>(#[case] _c: C) {
+    let schemes = [
+        SignatureSchemes::Basic,
+        SignatureSchemes::MessageAugmentation,
+        SignatureSchemes::ProofOfPossession,
+        SignatureSchemes::ProofOfPossession,
+    ];
+    let mut items = Vec::with_capacity(schemes.len());
+    for scheme in schemes {
+        let sk = SecretKey::<C>::new();
+        let sig = sk.sign(scheme, TEST_MSG).unwrap();
+        items.push((sk.public_key(), TEST_MSG, sig));
+    }
+    assert!(Signature::batch_verify_mixed(&items).is_ok());
+    assert!(Signature::<C>::batch_verify_mixed(&[]).is_err());
+
+    items[1].1 = BAD_MSG;
+    assert!(Signature::batch_verify_mixed(&items).is_err());
+    items[1].1 = TEST_MSG;
+
+    items.swap(0, 3);
+    assert!(Signature::batch_verify_mixed(&items).is_ok());
+
+    let pk = items[1].0;
+    items[1].0 = items[2].0;
+    items[2].0 = pk;
+    assert!(Signature::batch_verify_mixed(&items).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn secret_key_from_okm_matches_keygen<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let mut ikm = TEST_MSG.to_vec();
+    ikm.push(0u8);
+    let hk = hkdf::Hkdf::<sha2::Sha256>::new(Some(b"BLS-SIG-KEYGEN-SALT-"), &ikm);
+    let mut okm = [0u8; 48];
+    hk.expand(&[0u8, 48u8], &mut okm).unwrap();
+
+    let sk = SecretKey::<C>::from_okm(&okm).unwrap();
+    assert!(sk == SecretKey::<C>::from_hash(TEST_MSG));
+
+    // The curve order reduces to zero
+    let mut okm = [0u8; 48];
+    okm[16..].copy_from_slice(
+        &hex::decode("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001").unwrap(),
+    );
+    assert_eq!(SecretKey::<C>::from_okm(&okm).is_none().unwrap_u8(), 1u8);
+    assert_eq!(
+        SecretKey::<C>::from_okm(&[0u8; 48]).is_none().unwrap_u8(),
+        1u8
+    );
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn derive_subkey_is_deterministic<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let sk = SecretKey::<C>::from_hash(TEST_MSG);
+    let signing = sk.derive_subkey(b"signing");
+    let encryption = sk.derive_subkey(b"encryption");
+    assert!(signing == sk.derive_subkey(b"signing"));
+    assert!(signing != encryption);
+    assert!(signing != sk);
+    assert!(signing != SecretKey::<C>::new().derive_subkey(b"signing"));
+
+    let sig = signing.sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+    assert!(sig.verify(&signing.public_key(), TEST_MSG).is_ok());
+    assert!(sig.verify(&encryption.public_key(), TEST_MSG).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn scheme_accessors_work<C: BlsSignatureImpl>(#[case] _c: C) {
+    for scheme in [
+        SignatureSchemes::Basic,
+        SignatureSchemes::MessageAugmentation,
+        SignatureSchemes::ProofOfPossession,
+    ] {
+        let sig1 = SecretKey::<C>::new().sign(scheme, TEST_MSG).unwrap();
+        let sig2 = SecretKey::<C>::new().sign(scheme, BAD_MSG).unwrap();
+        assert_eq!(sig1.scheme(), scheme);
+        let asig = AggregateSignature::from_signatures(&[sig1, sig2]).unwrap();
+        assert_eq!(asig.scheme(), scheme);
+        if scheme != SignatureSchemes::MessageAugmentation {
+            let msig = MultiSignature::from_signatures(&[sig1, sig2]).unwrap();
+            assert_eq!(msig.scheme(), scheme);
+        }
+    }
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn dealer_transcript_works<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let (shares, transcript) = sk.split_with_transcript(3, 5, rand_core::OsRng).unwrap();
+    assert!(transcript.public_key() == Some(sk.public_key()));
+    assert!(SecretKey::combine(&shares[..3]).unwrap() == sk);
+
+    let pk_shares = shares
+        .iter()
+        .map(|s| s.public_key().unwrap())
+        .collect::<Vec<_>>();
+    assert!(transcript.verify(&pk_shares).is_ok());
+    assert!(transcript.verify(&pk_shares[..2]).is_err());
+
+    let (other_shares, _) = sk.split_with_transcript(3, 5, rand_core::OsRng).unwrap();
+    let mut bad_pk_shares = pk_shares.clone();
+    bad_pk_shares[1] = other_shares[1].public_key().unwrap();
+    assert!(transcript.verify(&bad_pk_shares).is_err());
+
+    let bytes = Vec::<u8>::from(&transcript);
+    let transcript2 = DealerTranscript::<C>::try_from(bytes.as_slice()).unwrap();
+    assert!(transcript == transcript2);
+
+    assert!(sk.split_with_transcript(1, 5, rand_core::OsRng).is_err());
+    assert!(sk.split_with_transcript(6, 5, rand_core::OsRng).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn sign_with_policy_works<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let scheme = SignatureSchemes::ProofOfPossession;
+
+    assert!(sk
+        .sign_with_policy(scheme, &[], SignPolicy::default())
+        .is_ok());
+    assert!(sk
+        .sign_with_policy(scheme, &[], SignPolicy::NON_EMPTY)
+        .is_err());
+
+    let policy = SignPolicy {
+        min_len: 1,
+        max_len: TEST_MSG.len(),
+    };
+    let sig = sk.sign_with_policy(scheme, TEST_MSG, policy).unwrap();
+    assert!(sig.verify(&pk, TEST_MSG).is_ok());
+    let mut big_msg = TEST_MSG.to_vec();
+    big_msg.push(0u8);
+    assert!(sk.sign_with_policy(scheme, &big_msg, policy).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn committee_subtraction_works<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let sks = (0..3).map(|_| SecretKey::<C>::new()).collect::<Vec<_>>();
+    let pks = sks.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+
+    let mpk = MultiPublicKey::from_public_keys(&pks);
+    assert!(mpk.checked_sub(&pks[2]).unwrap() == MultiPublicKey::from_public_keys(&pks[..2]));
+
+    let msgs: [&[u8]; 3] = [b"one", b"two", b"three"];
+    let sigs = sks
+        .iter()
+        .zip(msgs.iter())
+        .map(|(sk, m)| sk.sign(SignatureSchemes::ProofOfPossession, m).unwrap())
+        .collect::<Vec<_>>();
+    let asig = AggregateSignature::from_signatures(&sigs).unwrap();
+    let asig = asig.checked_sub(&sigs[2]).unwrap();
+    let data = pks[..2]
+        .iter()
+        .copied()
+        .zip(msgs[..2].iter().copied())
+        .collect::<Vec<_>>();
+    assert!(asig.verify(&data).is_ok());
+
+    let basic = sks[2].sign(SignatureSchemes::Basic, msgs[2]).unwrap();
+    assert!(asig.checked_sub(&basic).is_err());
+}
+
+fn verify_any<C: BlsSignatureImpl, V: BlsVerifiable<C>>(sig: &V, context: V::Context<'_>) -> bool {
+    sig.verify(context).is_ok()
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn verifiable_is_generic_over_signature_kinds<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk1 = SecretKey::<C>::new();
+    let sk2 = SecretKey::<C>::new();
+    let pk1 = sk1.public_key();
+    let pk2 = sk2.public_key();
+
+    let sig1 = sk1
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    let sig2 = sk2
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    assert!(verify_any(&sig1, (&pk1, TEST_MSG)));
+    assert!(!verify_any(&sig1, (&pk2, TEST_MSG)));
+
+    let msig = MultiSignature::from_signatures(&[sig1, sig2]).unwrap();
+    let mpk = MultiPublicKey::from_public_keys(&[pk1, pk2]);
+    assert!(verify_any(&msig, (&mpk, TEST_MSG)));
+    assert!(!verify_any(&msig, (&mpk, b"other message".as_slice())));
+
+    let sig1 = sk1.sign(SignatureSchemes::Basic, b"sig1").unwrap();
+    let sig2 = sk2.sign(SignatureSchemes::Basic, b"sig2").unwrap();
+    let asig = AggregateSignature::from_signatures(&[sig1, sig2]).unwrap();
+    let data = [(pk1, b"sig1".as_slice()), (pk2, b"sig2".as_slice())];
+    assert!(verify_any(&asig, &data));
+    assert!(!verify_any(&asig, &data[..1]));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn constant_time_token_comparison<C: BlsSignatureImpl>(#[case] _c: C) {
+    use subtle::ConstantTimeEq;
+
+    let sk = SecretKey::<C>::new();
+    let token = sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+    let same = sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+    let other = sk.sign(SignatureSchemes::Basic, b"other").unwrap();
+    let other_scheme = sk
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+
+    assert_eq!(token.ct_eq(&same).unwrap_u8(), 1);
+    assert_eq!(token.ct_eq(&other).unwrap_u8(), 0);
+    assert_eq!(token.ct_eq(&other_scheme).unwrap_u8(), 0);
+
+    assert!(same.verify_token(&token).is_ok());
+    assert!(matches!(
+        other.verify_token(&token),
+        Err(BlsError::InvalidSignature)
+    ));
+    assert!(matches!(
+        other_scheme.verify_token(&token),
+        Err(BlsError::InvalidSignatureScheme)
+    ));
+
+    let pk = sk.public_key();
+    assert_eq!(pk.ct_eq(&sk.public_key()).unwrap_u8(), 1);
+    assert_eq!(pk.ct_eq(&SecretKey::<C>::new().public_key()).unwrap_u8(), 0);
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn sign_verified_works<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    for scheme in [
+        SignatureSchemes::Basic,
+        SignatureSchemes::MessageAugmentation,
+        SignatureSchemes::ProofOfPossession,
+    ] {
+        let sig = sk.sign_verified(scheme, TEST_MSG).unwrap();
+        assert_eq!(sig.scheme(), scheme);
+        assert!(sig.verify(&pk, TEST_MSG).is_ok());
+    }
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn signature_share_from_parts<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    use blsful::vsss_rs::{IdentifierPrimeField, Share};
+
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split_with_rng(2, 3, rand_core::OsRng).unwrap();
+    let share = shares[0]
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    let id = *share.as_raw_value().identifier();
+    let point = share.as_raw_value().value().0;
+
+    let res = SignatureShare::<C>::from_parts(SignatureSchemes::ProofOfPossession, id, point);
+    assert!(res.is_ok());
+    assert!(res.unwrap() == share);
+
+    let res = SignatureShare::<C>::from_parts(SignatureSchemes::Basic, id, point);
+    assert!(res.is_ok());
+    assert!(res.unwrap() != share);
+
+    let zero = IdentifierPrimeField(id.0 - id.0);
+    assert!(
+        SignatureShare::<C>::from_parts(SignatureSchemes::ProofOfPossession, zero, point).is_err()
+    );
+    assert!(SignatureShare::<C>::from_parts(
+        SignatureSchemes::ProofOfPossession,
+        id,
+        point - point
+    )
+    .is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn proof_of_possession_verify_batch<C: BlsSignatureImpl>(#[case] _c: C) {
+    let mut items = (0..103)
+        .map(|_| {
+            let sk = SecretKey::<C>::new();
+            (sk.public_key(), sk.proof_of_possession().unwrap())
+        })
+        .collect::<Vec<_>>();
+    assert!(ProofOfPossession::verify_batch(&items, rand_core::OsRng).is_ok());
+
+    // A proof for another key, a signature used as a proof and a proof
+    // bound to a context
+    let other = SecretKey::<C>::new();
+    items[7].1 = other.proof_of_possession().unwrap();
+    let sk = SecretKey::<C>::new();
+    items[42] = (
+        sk.public_key(),
+        ProofOfPossession(
+            *sk.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+                .unwrap()
+                .as_raw_value(),
+        ),
+    );
+    let sk = SecretKey::<C>::new();
+    items[99] = (
+        sk.public_key(),
+        sk.proof_of_possession_with_context(TEST_ID).unwrap(),
+    );
+    assert_eq!(
+        ProofOfPossession::verify_batch(&items, rand_core::OsRng),
+        Err(vec![7, 42, 99])
+    );
+    assert_eq!(
+        ProofOfPossession::<C>::verify_batch(&[], rand_core::OsRng),
+        Err(vec![])
+    );
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn multi_public_key_from_checked_proofs_of_possession<C: BlsSignatureImpl + PartialEq>(
+    #[case] _c: C,
+) {
+    let sks = (0..4).map(|_| SecretKey::<C>::new()).collect::<Vec<_>>();
+    let mut keys = sks
+        .iter()
+        .map(|sk| (sk.public_key(), sk.proof_of_possession().unwrap()))
+        .collect::<Vec<_>>();
+    let pks = keys.iter().map(|(pk, _)| *pk).collect::<Vec<_>>();
+    let apk = MultiPublicKey::from_public_keys_checked(&keys).unwrap();
+    assert!(apk == MultiPublicKey::from_public_keys(&pks));
+
+    // A rogue key without a matching proof is refused
+    keys[2].1 = sks[0].proof_of_possession().unwrap();
+    assert!(matches!(
+        MultiPublicKey::from_public_keys_checked(&keys),
+        Err(BlsError::InvalidInputs(e)) if e == "proof of possession at 3 is invalid"
+    ));
+    assert!(MultiPublicKey::<C>::from_public_keys_checked(&[]).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn aggregate_verifies_against_key_commitment<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sks = (0..4).map(|_| SecretKey::<C>::new()).collect::<Vec<_>>();
+    let pks = sks.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+    for (sk, pk) in sks.iter().zip(pks.iter()) {
+        let pop = sk.proof_of_possession().unwrap();
+        assert!(pop.verify(*pk).is_ok());
+    }
+    // The verifier only keeps the commitment to the key set
+    let commitment = MultiPublicKey::from_public_keys(&pks);
+    let stored = Vec::<u8>::from(&commitment);
+    let commitment = MultiPublicKey::<C>::try_from(stored.as_slice()).unwrap();
+
+    let sigs = sks
+        .iter()
+        .map(|sk| {
+            sk.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let asig = AggregateSignature::from_signatures(&sigs).unwrap();
+    assert!(asig
+        .verify_against_key_commitment(&commitment, TEST_MSG)
+        .is_ok());
+    assert!(asig
+        .verify_against_key_commitment(&commitment, BAD_MSG)
+        .is_err());
+
+    // Missing a signer or committing to a different set fails
+    let partial = AggregateSignature::from_signatures(&sigs[..3]).unwrap();
+    assert!(partial
+        .verify_against_key_commitment(&commitment, TEST_MSG)
+        .is_err());
+    let other = MultiPublicKey::from_public_keys(&pks[..3]);
+    assert!(asig
+        .verify_against_key_commitment(&other, TEST_MSG)
+        .is_err());
+
+    let basic = sks
+        .iter()
+        .map(|sk| sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap())
+        .collect::<Vec<_>>();
+    let asig = AggregateSignature::from_signatures(&basic).unwrap();
+    assert!(matches!(
+        asig.verify_against_key_commitment(&commitment, TEST_MSG),
+        Err(BlsError::InvalidSignatureScheme)
+    ));
+}
+
+#[cfg(feature = "rayon")]
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn parallel_aggregation_matches_sequential<C: BlsSignatureImpl>(#[case] _c: C) {
+    let base = SecretKey::<C>::new()
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    let mut point = *base.as_raw_value();
+    let mut expected = point;
+    let mut sigs = vec![base];
+    for _ in 1..4096 {
+        point += base.as_raw_value();
+        expected += point;
+        sigs.push(Signature::ProofOfPossession(point));
+    }
+    let asig = AggregateSignature::from_signatures(&sigs).unwrap();
+    assert_eq!(asig.as_raw_value(), &expected);
+    let msig = MultiSignature::from_signatures(&sigs).unwrap();
+    assert_eq!(msig.as_raw_value(), &expected);
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn verify_batch_of_independent_signatures<C: BlsSignatureImpl>(#[case] _c: C) {
+    const N: usize = 64;
+    let msgs = (0..N)
+        .map(|i| [TEST_MSG, &i.to_be_bytes()].concat())
+        .collect::<Vec<_>>();
+    let mut items = msgs
+        .iter()
+        .map(|msg| {
+            let sk = SecretKey::<C>::new();
+            let sig = sk.sign(SignatureSchemes::Basic, msg).unwrap();
+            (sk.public_key(), sig, msg.as_slice())
+        })
+        .collect::<Vec<_>>();
+    assert!(Signature::verify_batch(&items, MockRng::default()).is_ok());
+
+    // One signature over the wrong message fails the whole batch
+    let saved = items[N / 2].2;
+    items[N / 2].2 = BAD_MSG;
+    assert!(Signature::verify_batch(&items, MockRng::default()).is_err());
+    items[N / 2].2 = saved;
+
+    let sk = SecretKey::<C>::new();
+    items.push((
+        sk.public_key(),
+        sk.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+            .unwrap(),
+        TEST_MSG,
+    ));
+    assert!(matches!(
+        Signature::verify_batch(&items, MockRng::default()),
+        Err(BlsError::InvalidSignatureScheme)
+    ));
+    assert!(Signature::<C>::verify_batch(&[], MockRng::default()).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn aggregate_verify_randomized_rejects_cancelling_signatures<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk1 = SecretKey::<C>::new();
+    let sk2 = SecretKey::<C>::new();
+    let pk1 = sk1.public_key();
+    let pk2 = sk2.public_key();
+    let sig1 = sk1
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    let sig2 = sk2
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    let asig = AggregateSignature::from_signatures([sig1, sig2]).unwrap();
+    let data = [(pk1, TEST_MSG, sig1), (pk2, TEST_MSG, sig2)];
+    assert!(asig.verify_randomized(&data, MockRng::default()).is_ok());
+
+    // Shift both signatures by the same point in opposite directions
+    let delta = *SecretKey::<C>::new()
+        .sign(SignatureSchemes::ProofOfPossession, BAD_MSG)
+        .unwrap()
+        .as_raw_value();
+    let forged1 = Signature::<C>::ProofOfPossession(*sig1.as_raw_value() + delta);
+    let forged2 = Signature::<C>::ProofOfPossession(*sig2.as_raw_value() - delta);
+    let forged = AggregateSignature::from_signatures([forged1, forged2]).unwrap();
+    assert!(forged.verify(&[(pk1, TEST_MSG), (pk2, TEST_MSG)]).is_ok());
+    let data = [(pk1, TEST_MSG, forged1), (pk2, TEST_MSG, forged2)];
+    assert!(forged.verify_randomized(&data, MockRng::default()).is_err());
+
+    // The signatures must add up to the aggregate
+    let data = [(pk1, TEST_MSG, sig1), (pk2, TEST_MSG, sig1)];
+    assert!(asig.verify_randomized(&data, MockRng::default()).is_err());
+    let empty: [(PublicKey<C>, &[u8], Signature<C>); 0] = [];
+    assert!(asig.verify_randomized(&empty, MockRng::default()).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn aggregate_verify_reports_duplicate_message_indices<C: BlsSignatureImpl>(#[case] _c: C) {
+    struct Msg(Vec<u8>);
+
+    impl AsRef<[u8]> for Msg {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let sig = sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+    let msgs = || {
+        (0..1000u32).map(|i| {
+            let i = if i == 877 { 123 } else { i };
+            (pk.0, Msg(i.to_be_bytes().to_vec()))
+        })
+    };
+    match <C as BlsSignatureBasic>::aggregate_verify(msgs(), *sig.as_raw_value()) {
+        Err(BlsError::InvalidInputs(e)) => {
+            assert_eq!(e, "duplicate messages detected at 123 and 877")
+        }
+        r => panic!("expected duplicate messages error, got {:?}", r),
+    }
+
+    // The earliest repeat is reported when there are several
+    let msgs = msgs().map(|(pk, m)| {
+        if m.0 == 999u32.to_be_bytes() {
+            (pk, Msg(500u32.to_be_bytes().to_vec()))
+        } else {
+            (pk, m)
+        }
+    });
+    match <C as BlsSignatureBasic>::aggregate_verify(msgs, *sig.as_raw_value()) {
+        Err(BlsError::InvalidInputs(e)) => {
+            assert_eq!(e, "duplicate messages detected at 123 and 877")
+        }
+        r => panic!("expected duplicate messages error, got {:?}", r),
+    }
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn incremental_aggregation_matches_from_signatures<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sigs = (0..3)
+        .map(|_| {
+            SecretKey::<C>::new()
+                .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let aggregator = SignatureAggregator::<C>::new(SignatureSchemes::ProofOfPossession);
+    assert!(aggregator.is_empty());
+    assert!(matches!(
+        aggregator.finalize(),
+        Err(BlsError::InvalidInputs(_))
+    ));
+
+    let mut aggregator = SignatureAggregator::new(SignatureSchemes::ProofOfPossession);
+    for sig in &sigs {
+        aggregator.push(*sig).unwrap();
+    }
+    assert_eq!(aggregator.len(), 3);
+
+    let basic = SecretKey::<C>::new()
+        .sign(SignatureSchemes::Basic, TEST_MSG)
+        .unwrap();
+    assert!(matches!(
+        aggregator.push(basic),
+        Err(BlsError::InvalidSignatureScheme)
+    ));
+    assert_eq!(aggregator.len(), 3);
+
+    let expected = AggregateSignature::from_signatures(&sigs).unwrap();
+    let asig = aggregator.finalize().unwrap();
+    assert_eq!(asig.scheme(), expected.scheme());
+    assert_eq!(asig.as_raw_value(), expected.as_raw_value());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn aggregate_verify_identifies_bad_signatures<C: BlsSignatureImpl>(#[case] _c: C) {
+    let msgs = (0..5u8)
+        .map(|i| [TEST_MSG, &[i]].concat())
+        .collect::<Vec<_>>();
+    let mut data = msgs
+        .iter()
+        .map(|msg| {
+            let sk = SecretKey::<C>::new();
+            let sig = sk.sign(SignatureSchemes::Basic, msg).unwrap();
+            (sk.public_key(), msg.as_slice(), sig)
+        })
+        .collect::<Vec<_>>();
+    let sigs = data.iter().map(|(_, _, sig)| *sig).collect::<Vec<_>>();
+    let asig = AggregateSignature::from_signatures(&sigs).unwrap();
+    assert_eq!(asig.verify_identify(&data), Ok(()));
+
+    // Swapping two signatures keeps the sum but breaks both entries
+    let sig1 = data[1].2;
+    data[1].2 = data[3].2;
+    data[3].2 = sig1;
+    assert_eq!(asig.verify_identify(&data), Err(vec![1, 3]));
+
+    // Valid signatures that don't match the aggregate
+    let other = AggregateSignature::from_signatures(&sigs[..4]).unwrap();
+    data[3].2 = data[1].2;
+    data[1].2 = sig1;
+    assert_eq!(other.verify_identify(&data), Err(vec![]));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn verify_same_message_returns_aggregate_key<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sks = (0..3).map(|_| SecretKey::<C>::new()).collect::<Vec<_>>();
+    let pks = sks.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+    let sigs = sks
+        .iter()
+        .map(|sk| {
+            sk.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let asig = AggregateSignature::from_signatures(&sigs).unwrap();
+    assert!(asig.verify_same_message(&pks, TEST_MSG).is_ok());
+    assert!(asig.verify_same_message(&pks, BAD_MSG).is_err());
+    assert!(asig.verify_same_message(&[], TEST_MSG).is_err());
+
+    let (apk, res) = asig.verify_same_message_returning_key(&pks, TEST_MSG);
+    assert!(res.is_ok());
+    assert_eq!(apk, MultiPublicKey::from_public_keys(&pks));
+    let (apk, res) = asig.verify_same_message_returning_key(&pks[..2], TEST_MSG);
+    assert!(res.is_err());
+    assert_eq!(apk, MultiPublicKey::from_public_keys(&pks[..2]));
+    let (_, res) = asig.verify_same_message_returning_key(&[], TEST_MSG);
+    assert!(res.is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn verify_same_message_matches_per_key_verification<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sks = (0..5).map(|_| SecretKey::<C>::new()).collect::<Vec<_>>();
+    let pks = sks.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+    let mut sigs = sks
+        .iter()
+        .map(|sk| {
+            sk.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert!(pks
+        .iter()
+        .zip(&sigs)
+        .all(|(pk, sig)| sig.verify(pk, TEST_MSG).is_ok()));
+    let asig = AggregateSignature::from_signatures(&sigs).unwrap();
+    assert!(asig.verify_same_message(&pks, TEST_MSG).is_ok());
+    let data = pks.iter().map(|pk| (*pk, TEST_MSG)).collect::<Vec<_>>();
+    assert!(asig.verify(&data).is_ok());
+
+    sigs[3] = sks[3]
+        .sign(SignatureSchemes::ProofOfPossession, BAD_MSG)
+        .unwrap();
+    assert!(sigs[3].verify(&pks[3], TEST_MSG).is_err());
+    let asig = AggregateSignature::from_signatures(&sigs).unwrap();
+    assert!(asig.verify_same_message(&pks, TEST_MSG).is_err());
+    assert!(asig.verify(&data).is_err());
+
+    // The basic scheme rejects duplicate messages either way
+    let sigs = sks
+        .iter()
+        .map(|sk| sk.sign(SignatureSchemes::Basic, TEST_MSG).unwrap())
+        .collect::<Vec<_>>();
+    assert!(pks
+        .iter()
+        .zip(&sigs)
+        .all(|(pk, sig)| sig.verify(pk, TEST_MSG).is_ok()));
+    let asig = AggregateSignature::from_signatures(&sigs).unwrap();
+    assert!(matches!(
+        asig.verify_same_message(&pks, TEST_MSG),
+        Err(BlsError::InvalidSignatureScheme)
+    ));
+    assert!(asig.verify(&data).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn hash_to_point_times_secret_key_is_signature<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    for scheme in [SignatureSchemes::Basic, SignatureSchemes::ProofOfPossession] {
+        let point = Signature::<C>::hash_to_point(scheme, TEST_MSG);
+        let sig = sk.sign(scheme, TEST_MSG).unwrap();
+        assert_eq!(sig.scheme(), scheme);
+        assert_eq!(point * sk.0, *sig.as_raw_value());
+        assert_ne!(
+            Signature::<C>::hash_to_point(scheme, BAD_MSG) * sk.0,
+            *sig.as_raw_value()
+        );
+    }
+    assert_ne!(
+        Signature::<C>::hash_to_point(SignatureSchemes::Basic, TEST_MSG),
+        Signature::<C>::hash_to_point(SignatureSchemes::ProofOfPossession, TEST_MSG)
+    );
+
+    // Message augmentation signs the public key followed by the message
+    let sig = sk
+        .sign(SignatureSchemes::MessageAugmentation, TEST_MSG)
+        .unwrap();
+    let point = Signature::<C>::hash_to_point_augmented(&pk, TEST_MSG);
+    assert_eq!(point * sk.0, *sig.as_raw_value());
+    assert_ne!(
+        Signature::<C>::hash_to_point(SignatureSchemes::MessageAugmentation, TEST_MSG),
+        point
+    );
+    let other_pk = SecretKey::<C>::new().public_key();
+    assert_ne!(
+        Signature::<C>::hash_to_point_augmented(&other_pk, TEST_MSG),
+        point
+    );
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn verify_prehashed_matches_verify<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let shares = sk.split(5, 10).unwrap();
+    let hashed = pk.hash_message(SignatureSchemes::Basic, TEST_MSG);
+    assert_eq!(hashed.scheme(), SignatureSchemes::Basic);
+
+    for share in &shares {
+        let pks = share.public_key().unwrap();
+        let sig = share.sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+        assert!(sig.verify(&pks, TEST_MSG).is_ok());
+        assert!(sig.verify_prehashed(&pks, &hashed).is_ok());
+        let bad = share.sign(SignatureSchemes::Basic, BAD_MSG).unwrap();
+        assert!(bad.verify(&pks, TEST_MSG).is_err());
+        assert!(bad.verify_prehashed(&pks, &hashed).is_err());
+    }
+
+    for scheme in [
+        SignatureSchemes::Basic,
+        SignatureSchemes::MessageAugmentation,
+        SignatureSchemes::ProofOfPossession,
+    ] {
+        let sig = sk.sign(scheme, TEST_MSG).unwrap();
+        let hashed = pk.hash_message(scheme, TEST_MSG);
+        assert!(sig.verify_prehashed(&pk, &hashed).is_ok());
+        assert!(sig
+            .verify_prehashed(&pk, &pk.hash_message(scheme, BAD_MSG))
+            .is_err());
+        let other_pk = SecretKey::<C>::new().public_key();
+        assert!(sig.verify_prehashed(&other_pk, &hashed).is_err());
+    }
+
+    let sig = sk
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    assert!(matches!(
+        sig.verify_prehashed(&pk, &hashed),
+        Err(BlsError::InvalidSignatureScheme)
+    ));
+    let share_sig = shares[0]
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    assert!(matches!(
+        share_sig.verify_prehashed(&shares[0].public_key().unwrap(), &hashed),
+        Err(BlsError::InvalidSignatureScheme)
+    ));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn verify_with_message_hasher<C: BlsSignatureImpl>(#[case] _c: C) {
+    let mut hasher = MessageHasher::<C>::new(TEST_MSG);
+    assert_eq!(hasher.message(), TEST_MSG);
+
+    for scheme in [
+        SignatureSchemes::Basic,
+        SignatureSchemes::MessageAugmentation,
+        SignatureSchemes::ProofOfPossession,
+    ] {
+        for _ in 0..3 {
+            let sk = SecretKey::<C>::new();
+            let pk = sk.public_key();
+            let sig = sk.sign(scheme, TEST_MSG).unwrap();
+            assert!(sig.verify_with_hasher(&pk, &mut hasher).is_ok());
+            let other_pk = SecretKey::<C>::new().public_key();
+            assert!(sig.verify_with_hasher(&other_pk, &mut hasher).is_err());
+
+            let bad_sig = sk.sign(scheme, BAD_MSG).unwrap();
+            assert!(bad_sig.verify_with_hasher(&pk, &mut hasher).is_err());
+        }
+    }
+
+    let point = hasher.to_point(b"some dst");
+    assert_eq!(hasher.to_point(b"some dst"), point);
+    assert_ne!(hasher.to_point(b"other dst"), point);
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn combine_with_sharing_params<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let (shares, params) = sk.split_with_params(3, 5).unwrap();
+    assert_eq!(shares.len(), 5);
+    assert_eq!(params.threshold, 3);
+    assert_eq!(params.limit, 5);
+    assert!(params.group_public_key == sk.public_key());
+
+    let bytes = Vec::<u8>::from(&params);
+    let params = SharingParams::<C>::try_from(bytes.as_slice()).unwrap();
+
+    let res = SecretKey::combine_with_params(&shares[1..4], &params);
+    assert!(res.is_ok());
+    assert!(res.unwrap() == sk);
+
+    assert!(matches!(
+        SecretKey::combine_with_params(&shares[..2], &params),
+        Err(BlsError::InsufficientShares(3, 2))
+    ));
+    let dups = [shares[0].clone(), shares[1].clone(), shares[0].clone()];
+    assert!(matches!(
+        SecretKey::combine_with_params(&dups, &params),
+        Err(BlsError::DuplicateShareIdentifier)
+    ));
+
+    let (other_shares, _) = SecretKey::<C>::new().split_with_params(3, 5).unwrap();
+    assert!(SecretKey::combine_with_params(&other_shares[..3], &params).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+#[cfg(feature = "std")]
+fn combine_from_shares_map<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    use std::collections::HashMap;
+
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let shares = sk.split(2, 3).unwrap();
+
+    let mut sk_shares = HashMap::new();
+    let mut pk_shares = HashMap::new();
+    let mut sig_shares = HashMap::new();
+    for (participant, share) in shares.iter().enumerate().skip(1) {
+        sk_shares.insert(participant, share.clone());
+        pk_shares.insert(participant, share.public_key().unwrap());
+        sig_shares.insert(
+            participant,
+            share.sign(SignatureSchemes::Basic, TEST_MSG).unwrap(),
+        );
+    }
+
+    assert!(SecretKey::combine_map(&sk_shares).unwrap() == sk);
+    assert!(PublicKey::from_shares_map(&pk_shares).unwrap() == pk);
+    let sig = Signature::from_shares_map(&sig_shares).unwrap();
+    assert!(sig.verify(&pk, TEST_MSG).is_ok());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn batch_public_keys_match<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    for count in [0, 2, 10] {
+        let sks = (0..count)
+            .map(|_| SecretKey::<C>::new())
+            .collect::<Vec<_>>();
+        let pks = PublicKey::batch_from_secret_keys(&sks);
+        assert_eq!(pks.len(), count);
+        for (sk, pk) in sks.iter().zip(pks.iter()) {
+            assert!(*pk == sk.public_key());
+        }
+    }
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn domain_signatures_are_isolated<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    for scheme in [
+        SignatureSchemes::Basic,
+        SignatureSchemes::MessageAugmentation,
+        SignatureSchemes::ProofOfPossession,
+    ] {
+        let sig = sk.sign_in_domain(scheme, b"chain-a", TEST_MSG).unwrap();
+        assert_eq!(sig.scheme(), scheme);
+        assert!(sig.verify_in_domain(&pk, b"chain-a", TEST_MSG).is_ok());
+        assert!(sig.verify_in_domain(&pk, b"chain-b", TEST_MSG).is_err());
+        assert!(sig.verify_in_domain(&pk, b"chain-a", BAD_MSG).is_err());
+        assert!(sig.verify(&pk, TEST_MSG).is_err());
+
+        let plain = sk.sign(scheme, TEST_MSG).unwrap();
+        assert!(plain.verify_in_domain(&pk, b"chain-a", TEST_MSG).is_err());
+    }
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn aggregate_from_signed_messages<C: BlsSignatureImpl>(#[case] _c: C) {
+    let messages = (0..3u8)
+        .map(|i| {
+            let sk = SecretKey::<C>::new();
+            SignedMessage::sign(&sk, SignatureSchemes::Basic, [TEST_MSG, &[i]].concat()).unwrap()
+        })
+        .collect::<Vec<_>>();
+    for m in &messages {
+        assert!(m.verify().is_ok());
+    }
+    let bytes = Vec::<u8>::from(&messages[0]);
+    let restored = SignedMessage::<C>::try_from(bytes.as_slice()).unwrap();
+    assert!(restored.verify().is_ok());
+
+    let aggregated = AggregateSignature::from_signed_messages(&messages).unwrap();
+    assert_eq!(aggregated.data.len(), messages.len());
+    assert!(aggregated.signature.verify(&aggregated.data).is_ok());
+    assert!(aggregated.verify().is_ok());
+
+    // A signature bundled with the wrong key still aggregates but the
+    // aggregate fails to verify
+    let mut swapped = messages.clone();
+    swapped[0].pk = messages[1].pk;
+    assert!(swapped[0].verify().is_err());
+    let aggregated = AggregateSignature::from_signed_messages(&swapped).unwrap();
+    assert!(aggregated.verify().is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn eip2333_test_vectors<C: BlsSignatureImpl>(#[case] _c: C) {
+    // seed, master secret key, child index, child secret key
+    const VECTORS: [(&str, &str, u32, &str); 4] = [
+        (
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            "0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070",
+            0,
+            "2d18bd6c14e6d15bf8b5085c9b74f3daae3b03cc2014770a599d8c1539e50f8e",
+        ),
+        (
+            "3141592653589793238462643383279502884197169399375105820974944592",
+            "41c9e07822b092a93fd6797396338c3ada4170cc81829fdfce6b5d34bd5e7ec7",
+            3141592653,
+            "384843fad5f3d777ea39de3e47a8f999ae91f89e42bffa993d91d9782d152a0f",
+        ),
+        (
+            "0099ff991111002299dd7744ee3355bbdd8844115566cc55663355668888cc00",
+            "3cfa341ab3910a7d00d933d8f7c4fe87c91798a0397421d6b19fd5b815132e80",
+            4294967295,
+            "40e86285582f35b28821340f6a53b448588efa575bc4d88c32ef8567b8d9479b",
+        ),
+        (
+            "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+            "2a0e28ffa5fbbe2f8e7aad4ed94f745d6bf755c51182e119bb1694fe61d3afca",
+            42,
+            "455c0dc9fccb3395825d92a60d2672d69416be1c2578a87a7a3d3ced11ebb88d",
+        ),
+    ];
+
+    for (seed, master, index, child) in VECTORS {
+        let seed = hex::decode(seed).unwrap();
+        let master_sk = SecretKey::<C>::derive_master(&seed).unwrap();
+        assert_eq!(hex::encode(master_sk.to_be_bytes()), master);
+        let child_sk = master_sk.derive_child(index);
+        assert_eq!(hex::encode(child_sk.to_be_bytes()), child);
+    }
+
+    assert!(SecretKey::<C>::derive_master(&[0u8; 31]).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn eip2334_derive_path<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(#[case] _c: C) {
+    let seed = [7u8; 32];
+    let master = SecretKey::<C>::derive_master(&seed).unwrap();
+    let expected = [12381, 3600, 0, 0, 0]
+        .iter()
+        .fold(master.clone(), |sk, i| sk.derive_child(*i));
+    assert_eq!(
+        SecretKey::<C>::derive_path(&seed, "m/12381/3600/0/0/0").unwrap(),
+        expected
+    );
+    assert_eq!(SecretKey::<C>::derive_path(&seed, "m").unwrap(), master);
+
+    for (path, token) in [
+        ("m/abc", "'abc'"),
+        ("12381/3600", "'12381'"),
+        ("/12381", "''"),
+        ("m/12381/", "''"),
+        ("m//0", "''"),
+        ("m/-1", "'-1'"),
+        ("m/+1", "'+1'"),
+        ("m/4294967296", "'4294967296'"),
+        ("", "''"),
+    ] {
+        match SecretKey::<C>::derive_path(&seed, path) {
+            Err(BlsError::InvalidInputs(e)) => assert!(e.contains(token), "{}: {}", path, e),
+            r => panic!("{} should be rejected, got {:?}", path, r),
+        }
+    }
+    assert!(SecretKey::<C>::derive_path(&[0u8; 16], "m/0").is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn split_with_identifiers_combines<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(
+    #[case] _c: C,
+) {
+    use blsful::vsss_rs::{IdentifierPrimeField, Share};
+
+    let sk = SecretKey::<C>::new();
+    let ids = [5u64, 9, 14].map(|i| IdentifierPrimeField(From::from(i)));
+    let shares = sk
+        .split_with_identifiers(2, &ids, rand_core::OsRng)
+        .unwrap();
+    assert_eq!(shares.len(), 3);
+    for (share, id) in shares.iter().zip(ids.iter()) {
+        assert_eq!(share.0.identifier(), id);
+    }
+    for pair in [[0, 1], [0, 2], [1, 2]] {
+        let subset = pair.map(|i| shares[i].clone());
+        assert_eq!(SecretKey::<C>::combine(&subset).unwrap(), sk);
+    }
+
+    assert!(sk
+        .split_with_identifiers(4, &ids, rand_core::OsRng)
+        .is_err());
+    let duplicate = [ids[0], ids[1], ids[0]];
+    assert!(matches!(
+        sk.split_with_identifiers(2, &duplicate, rand_core::OsRng),
+        Err(BlsError::DuplicateShareIdentifier)
+    ));
+    let zero = [ids[0], IdentifierPrimeField(From::from(0u64))];
+    assert!(sk
+        .split_with_identifiers(2, &zero, rand_core::OsRng)
+        .is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn secret_key_share_verify_feldman<C: BlsSignatureImpl + PartialEq + Eq>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let (shares, commitment) = sk.split_with_transcript(3, 5, rand_core::OsRng).unwrap();
+    for share in &shares {
+        assert!(share.verify_feldman(&commitment).is_ok());
+    }
+
+    let (other_shares, other_commitment) =
+        sk.split_with_transcript(3, 5, rand_core::OsRng).unwrap();
+    assert!(other_shares[1].verify_feldman(&commitment).is_err());
+    assert!(shares[1].verify_feldman(&other_commitment).is_err());
+
+    let text = serde_json::to_string(&commitment).unwrap();
+    let commitment2: FeldmanCommitment<C> = serde_json::from_str(&text).unwrap();
+    assert!(shares[0].verify_feldman(&commitment2).is_ok());
+
+    let mut truncated = commitment.clone();
+    truncated.commitments.pop();
+    assert!(shares[0].verify_feldman(&truncated).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn refreshed_shares_sign_for_the_same_key<
+    C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug,
+>(
+    #[case] _c: C,
+) {
+    use blsful::vsss_rs::Share;
+
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split_with_rng(3, 5, rand_core::OsRng).unwrap();
+    let refreshed = SecretKeyShare::refresh(&shares, 3, rand_core::OsRng).unwrap();
+    assert_eq!(refreshed.len(), shares.len());
+    for (old, new) in shares.iter().zip(refreshed.iter()) {
+        assert_eq!(old.0.identifier(), new.0.identifier());
+        assert_ne!(old.0.value(), new.0.value());
+    }
+
+    let sign = |shares: &[SecretKeyShare<C>]| {
+        let sig_shares = shares
+            .iter()
+            .map(|s| {
+                s.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        Signature::from_shares(&sig_shares).unwrap()
+    };
+    let before = sign(&shares[..3]);
+    let after = sign(&refreshed[2..]);
+    assert_eq!(before, after);
+    assert!(after.verify(&sk.public_key(), TEST_MSG).is_ok());
+    assert_eq!(SecretKey::combine(&refreshed[1..4]).unwrap(), sk);
+
+    // Mixing shares from before and after the refresh doesn't recover the key
+    let mixed = [
+        shares[0].clone(),
+        refreshed[1].clone(),
+        refreshed[2].clone(),
+    ];
+    assert_ne!(SecretKey::combine(&mixed).unwrap(), sk);
+
+    assert!(matches!(
+        SecretKeyShare::refresh(&shares[..2], 3, rand_core::OsRng),
+        Err(BlsError::InsufficientShares(3, 2))
+    ));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn dedup_shares_by_identifier<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(
+    #[case] _c: C,
+) {
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split_with_rng(2, 3, rand_core::OsRng).unwrap();
+    let other = sk.split_with_rng(2, 3, rand_core::OsRng).unwrap();
+
+    let repeated = [shares[0].clone(), shares[1].clone(), shares[0].clone()];
+    let deduped = SecretKeyShare::dedup_by_identifier(&repeated).unwrap();
+    assert_eq!(deduped, shares[..2].to_vec());
+    assert_eq!(SecretKey::combine(&deduped).unwrap(), sk);
+    let conflicting = [shares[0].clone(), shares[1].clone(), other[0].clone()];
+    assert!(matches!(
+        SecretKeyShare::dedup_by_identifier(&conflicting),
+        Err(BlsError::DuplicateShareIdentifier)
+    ));
+
+    let sign = |s: &SecretKeyShare<C>| {
+        s.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+            .unwrap()
+    };
+    let sig_shares = [sign(&shares[0]), sign(&shares[1]), sign(&shares[1])];
+    let deduped = SignatureShare::dedup_by_identifier(&sig_shares).unwrap();
+    assert_eq!(deduped.len(), 2);
+    let sig = Signature::from_shares(&deduped).unwrap();
+    assert!(sig.verify(&sk.public_key(), TEST_MSG).is_ok());
+
+    let conflicting = [sign(&shares[0]), sign(&shares[1]), sign(&other[1])];
+    assert!(matches!(
+        SignatureShare::dedup_by_identifier(&conflicting),
+        Err(BlsError::DuplicateShareIdentifier)
+    ));
+    let basic = shares[0].sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+    assert!(SignatureShare::dedup_by_identifier(&[sign(&shares[0]), basic]).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn signature_share_verify_batch_reports_forged_shares<C: BlsSignatureImpl>(#[case] _c: C) {
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split_with_rng(3, 4, rand_core::OsRng).unwrap();
+    let forger = SecretKey::<C>::new()
+        .split_with_rng(3, 4, rand_core::OsRng)
+        .unwrap();
+    let mut items = shares
+        .iter()
+        .map(|s| {
+            (
+                s.public_key().unwrap(),
+                s.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+                    .unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert!(SignatureShare::verify_batch(&items, TEST_MSG, rand_core::OsRng).is_ok());
+    assert_eq!(
+        SignatureShare::verify_batch(&items, b"other", rand_core::OsRng),
+        Err(vec![0, 1, 2, 3])
+    );
+
+    items[2].1 = forger[2]
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    assert_eq!(
+        SignatureShare::verify_batch(&items, TEST_MSG, rand_core::OsRng),
+        Err(vec![2])
+    );
+
+    items[2].1 = shares[2].sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+    assert_eq!(
+        SignatureShare::verify_batch(&items, TEST_MSG, rand_core::OsRng),
+        Err(vec![2])
+    );
+    assert_eq!(
+        SignatureShare::<C>::verify_batch(&[], TEST_MSG, rand_core::OsRng),
+        Err(vec![])
+    );
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn signature_share_correctness_proof<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(
+    #[case] _c: C,
+) {
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split_with_rng(2, 3, rand_core::OsRng).unwrap();
+    let pks = shares[0].public_key().unwrap();
+    let (sig, proof) = shares[0]
+        .sign_with_proof(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    assert!(sig.verify_proof(&pks, TEST_MSG, &proof).is_ok());
+    assert!(sig.verify_proof(&pks, b"other", &proof).is_err());
+    assert!(sig
+        .verify_proof(&shares[1].public_key().unwrap(), TEST_MSG, &proof)
+        .is_err());
+
+    let bytes = Vec::<u8>::from(&proof);
+    let proof2 = ShareCorrectnessProof::<C>::try_from(bytes.as_slice()).unwrap();
+    assert_eq!(proof, proof2);
+
+    // A share with the same identifier but a different secret can't
+    // reuse the honest proof or prove itself against the public key share
+    let other = SecretKey::<C>::new()
+        .split_with_rng(2, 3, rand_core::OsRng)
+        .unwrap();
+    let (forged, forged_proof) = other[0]
+        .sign_with_proof(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    assert!(matches!(
+        forged.verify_proof(&pks, TEST_MSG, &proof),
+        Err(BlsError::InvalidProof)
+    ));
+    assert!(matches!(
+        forged.verify_proof(&pks, TEST_MSG, &forged_proof),
+        Err(BlsError::InvalidProof)
+    ));
+
+    let (sig, proof) = shares[1]
+        .sign_with_proof(SignatureSchemes::Basic, TEST_MSG)
+        .unwrap();
+    assert!(sig
+        .verify_proof(&shares[1].public_key().unwrap(), TEST_MSG, &proof)
+        .is_ok());
+
+    assert!(matches!(
+        shares[1].sign_with_proof(SignatureSchemes::MessageAugmentation, TEST_MSG),
+        Err(BlsError::InvalidSignatureScheme)
+    ));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn signature_from_shares_checked<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(
+    #[case] _c: C,
+) {
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split_with_rng(2, 3, rand_core::OsRng).unwrap();
+    let sig_shares = shares
+        .iter()
+        .map(|s| {
+            s.sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    assert!(matches!(
+        Signature::from_shares_checked(&sig_shares[..1], 2),
+        Err(BlsError::InvalidInputs(_))
+    ));
+    let repeated = [sig_shares[0], sig_shares[0]];
+    assert!(matches!(
+        Signature::from_shares_checked(&repeated, 2),
+        Err(BlsError::InvalidInputs(_))
+    ));
+
+    let sig = Signature::from_shares_checked(&sig_shares[1..], 2).unwrap();
+    assert!(sig.verify(&sk.public_key(), TEST_MSG).is_ok());
+    assert_eq!(sig, Signature::from_shares(&sig_shares[1..]).unwrap());
+    assert!(Signature::from_shares_checked(&sig_shares[..2], 3).is_err());
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn secret_key_arithmetic_and_tweaks<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(
+    #[case] _c: C,
+) {
+    let a = SecretKey::<C>::new();
+    let b = SecretKey::<C>::new();
+    let sum = a.checked_add(&b).unwrap();
+    assert_eq!(sum.public_key().0, a.public_key().0 + b.public_key().0);
+    assert_eq!(sum.checked_sub(&b).unwrap(), a);
+    assert_eq!(sum.checked_sub(&a).unwrap(), b);
+
+    let tweaked = a.add_tweak(b"stealth").unwrap();
+    assert_ne!(tweaked, a);
+    assert_eq!(
+        a.public_key().add_tweak(b"stealth").unwrap(),
+        tweaked.public_key()
+    );
+    assert_ne!(a.add_tweak(b"other").unwrap(), tweaked);
+
+    let sig = tweaked
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+    let pk = a.public_key().add_tweak(b"stealth").unwrap();
+    assert!(sig.verify(&pk, TEST_MSG).is_ok());
+
+    // Results that would be a zero secret key are rejected
+    assert!(matches!(a.checked_sub(&a), Err(BlsError::InvalidInputs(_))));
+    let neg_a = SecretKey::<C>(-a.0);
+    assert!(matches!(
+        a.checked_add(&neg_a),
+        Err(BlsError::InvalidInputs(_))
+    ));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn secret_types_compare_in_constant_time_and_zeroize<C: BlsSignatureImpl>(#[case] _c: C) {
+    use blsful::inner_types::Field;
+    use blsful::vsss_rs::Share;
+    use blsful::{ProofCommitment, ProofCommitmentSecret};
+    use subtle::ConstantTimeEq;
+    use zeroize::Zeroize;
+
+    let a = SecretKey::<C>::new();
+    let b = SecretKey::<C>::new();
+    for (x, y) in [(&a, &a), (&a, &b)] {
+        assert_eq!(bool::from(x.ct_eq(y)), x.to_be_bytes() == y.to_be_bytes());
+        assert_eq!(bool::from(x.ct_eq(y)), x == y);
+    }
+    let mut c = a.clone();
+    c.zeroize();
+    assert_eq!(c.to_be_bytes(), [0u8; 32]);
+    assert!(c != a);
+
+    let shares = a.split_with_rng(2, 3, rand_core::OsRng).unwrap();
+    assert!(bool::from(shares[0].ct_eq(&shares[0].clone())));
+    assert!(!bool::from(shares[0].ct_eq(&shares[1])));
+    let mut share = shares[0].clone();
+    share.zeroize();
+    assert_eq!(share.0.identifier(), shares[0].0.identifier());
+    assert!(bool::from(share.0.value().0.is_zero()));
+    assert!(share != shares[0]);
+
+    let sig = a.sign(SignatureSchemes::Basic, TEST_MSG).unwrap();
+    let (_, mut x) = ProofCommitment::generate(TEST_MSG, sig).unwrap();
+    let copy = ProofCommitmentSecret::<C>(x.0);
+    assert!(x == copy);
+    x.zeroize();
+    assert_eq!(x.to_be_bytes(), [0u8; 32]);
+    assert!(x != copy);
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn single_signature_aggregates<C: BlsSignatureImpl + PartialEq + Eq + std::fmt::Debug>(
+    #[case] _c: C,
+) {
+    let sk = SecretKey::<C>::new();
+    let pk = sk.public_key();
+    let sig = sk
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap();
+
+    let asig = AggregateSignature::from_signatures(&[sig]).unwrap();
+    assert_eq!(asig, AggregateSignature::from_single(sig));
+    assert_eq!(asig.as_raw_value(), sig.as_raw_value());
+    assert!(asig.verify(&[(pk, TEST_MSG)]).is_ok());
+
+    let msig = MultiSignature::from_signatures(&[sig]).unwrap();
+    assert_eq!(msig, MultiSignature::from_single(sig).unwrap());
+    assert!(msig
+        .verify(MultiPublicKey::from(&[pk][..]), TEST_MSG)
+        .is_ok());
+
+    let ma = sk
+        .sign(SignatureSchemes::MessageAugmentation, TEST_MSG)
+        .unwrap();
+    assert!(MultiSignature::from_single(ma).is_err());
+    assert!(AggregateSignature::from_single(ma)
+        .verify(&[(pk, TEST_MSG)])
+        .is_ok());
+
+    assert!(matches!(
+        AggregateSignature::<C>::from_signatures(&[]),
+        Err(BlsError::InvalidInputs(_))
+    ));
+    assert!(matches!(
+        MultiSignature::<C>::from_signatures(&[]),
+        Err(BlsError::InvalidInputs(_))
+    ));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn aggregating_no_signatures_is_an_error<C: BlsSignatureImpl>(#[case] _c: C) {
+    let none: &[Signature<C>] = &[];
+    let res = AggregateSignature::<C>::try_from(none);
+    assert!(matches!(res, Err(BlsError::InvalidInputs(e)) if e == "no signatures provided"));
+    let res = MultiSignature::<C>::try_from(none);
+    assert!(matches!(res, Err(BlsError::InvalidInputs(e)) if e == "no signatures provided"));
+}
+
+#[rstest]
+#[case::g1(Bls12381G1Impl)]
+#[case::g2(Bls12381G2Impl)]
+fn signature_share_conditional_select_picks_operand<C: BlsSignatureImpl>(#[case] _c: C) {
+    use blsful::vsss_rs::Share;
+    use blsful::Pairing;
+    use subtle::{Choice, ConditionallySelectable};
+
+    let sk = SecretKey::<C>::new();
+    let shares = sk.split_with_rng(2, 3, rand_core::OsRng).unwrap();
+    let a = *shares[0]
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap()
+        .as_raw_value();
+    let b = *shares[1]
+        .sign(SignatureSchemes::ProofOfPossession, TEST_MSG)
+        .unwrap()
+        .as_raw_value();
+    assert!(a.value().0 != b.value().0);
+
+    for (choice, expected) in [(0u8, &a), (1u8, &b)] {
+        let selected =
+            <C as Pairing>::SignatureShare::conditional_select(&a, &b, Choice::from(choice));
+        assert!(selected.identifier() == expected.identifier());
+        assert!(selected.value().0 == expected.value().0);
+    }
+}
+
+#[test]
+fn eth_sign_vectors() {
+    // From the Ethereum consensus spec BLS `sign` tests
+    const SK: &str = "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3";
+    const PK: &str = "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a";
+    const VECTORS: [(u8, &str); 3] = [
+        (0x00, "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55"),
+        (0x56, "882730e5d03f6b42c3abc26d3372625034e1d871b65a8a6b900a56dae22da98abbe1b68f85e49fe7652a55ec3d0591c20767677e33e5cbb1207315c41a9ac03be39c2e7668edc043d6cb1d9fd93033caa8a1c5b0e84bedaeb6c64972503a43eb"),
+        (0xab, "91347bccf740d859038fcdcaf233eeceb2a436bcaaee9b2aa3bfb70efe29dfb2677562ccbea1c8e061fb9971b0753c240622fab78489ce96768259fc01360346da5b9f579e5da0d941e4c6ba18a0e64906082375394f337fa1af2b7127b0d121"),
+    ];
+
+    let sk = SecretKey::<eth::Eth2G2>::try_from(hex::decode(SK).unwrap()).unwrap();
+    let pk = eth::public_key_from_bytes(&hex::decode(PK).unwrap()).unwrap();
+    assert_eq!(sk.public_key(), pk);
+    for (byte, expected) in VECTORS {
+        let msg = [byte; 32];
+        let expected = eth::signature_from_bytes(&hex::decode(expected).unwrap()).unwrap();
+        let sig = sk.sign(SignatureSchemes::ProofOfPossession, msg).unwrap();
+        assert_eq!(sig, expected);
+        assert!(eth::eth_fast_aggregate_verify(&[pk], msg, &sig).is_ok());
+    }
+}
+
+#[test]
+fn eth_fast_aggregate_verify_infinity_edge_case() {
+    let msg = [0u8; 32];
+    let mut infinity = [0u8; 96];
+    infinity[0] = 0xc0;
+    let infinity = eth::signature_from_bytes(&infinity).unwrap();
+
+    // From the Ethereum consensus spec `eth_fast_aggregate_verify` tests
+    assert!(eth::eth_fast_aggregate_verify(&[], msg, &infinity).is_ok());
+    assert!(eth::signature_from_bytes(&[0u8; 96]).is_err());
+
+    let sks = (0..3)
+        .map(|_| SecretKey::<eth::Eth2G2>::new())
+        .collect::<Vec<_>>();
+    let pks = sks.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+    let sigs = sks
+        .iter()
+        .map(|sk| sk.sign(SignatureSchemes::ProofOfPossession, msg).unwrap())
+        .collect::<Vec<_>>();
+    let asig = AggregateSignature::from_signatures(&sigs).unwrap();
+    let asig = Signature::ProofOfPossession(*asig.as_raw_value());
+    assert!(eth::eth_fast_aggregate_verify(&pks, msg, &asig).is_ok());
+    assert!(eth::eth_fast_aggregate_verify(&pks[..2], msg, &asig).is_err());
+    assert!(eth::eth_fast_aggregate_verify(&[], msg, &asig).is_err());
+    assert!(eth::eth_fast_aggregate_verify(&pks, msg, &infinity).is_err());
+
+    let basic = sks[0].sign(SignatureSchemes::Basic, msg).unwrap();
+    assert!(matches!(
+        eth::eth_fast_aggregate_verify(&pks[..1], msg, &basic),
+        Err(BlsError::InvalidSignatureScheme)
+    ));
+}